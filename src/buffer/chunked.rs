@@ -0,0 +1,278 @@
+//! Segmented byte buffer made of fixed-size chunks.
+//!
+//! This module provides a byte buffer that grows by appending heap-allocated chunks of
+//! `CHUNK` bytes instead of reallocating a contiguous region. Bytes never move once written,
+//! so slices handed out by [`ChunkedBuffer::chunks`] or [`ChunkedBuffer::contiguous_at`] point
+//! to stable addresses until the chunk holding them is released.
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+//!
+//! # Overview
+//!
+//! The buffer is a queue of chunks. Data is appended at the end of the last chunk, and the
+//! readable region starts at `head` bytes into the first chunk. Offsets used by `get`,
+//! `read_at` and `contiguous_at` are relative to the start of the readable region. Calling
+//! `consume` advances that start and frees every chunk that becomes fully consumed, which
+//! suits protocol engines receiving framed data piecemeal.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::buffer::ChunkedBuffer;
+//!
+//! let mut buffer: ChunkedBuffer<4> = ChunkedBuffer::new();
+//! buffer.append(b"\x00\x05hello");
+//!
+//! let mut header = [0u8; 2];
+//! buffer.read_at(0, &mut header);
+//! buffer.consume(2);
+//!
+//! assert_eq!(buffer.len(), 5);
+//! assert_eq!(buffer.get(0), Some(b'h'));
+//! ```
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+
+/// A byte buffer growing by chunks of `CHUNK` bytes.
+///
+/// # Type Parameters
+///
+/// - `CHUNK`: Size of each chunk in bytes (must be non-zero)
+///
+/// # Fields
+///
+/// - `chunks`: Queue of allocated chunks
+/// - `head`: Number of consumed bytes in the first chunk
+/// - `len`: Number of readable bytes
+pub struct ChunkedBuffer<const CHUNK: usize> {
+    chunks: VecDeque<Box<[u8; CHUNK]>>,
+    head: usize,
+    len: usize,
+}
+
+impl<const CHUNK: usize> Default for ChunkedBuffer<CHUNK> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CHUNK: usize> ChunkedBuffer<CHUNK> {
+    /// Creates an empty buffer without allocating.
+    pub fn new() -> Self {
+        const { assert!(CHUNK > 0, "chunks must not be empty") };
+
+        Self {
+            chunks: VecDeque::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of readable bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no readable bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of allocated chunks.
+    #[inline]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns the number of bytes that fit before a new chunk is allocated.
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        self.chunks.len() * CHUNK - self.head - self.len
+    }
+
+    /// Maps a readable offset to a chunk index and a position inside that chunk.
+    #[inline]
+    fn locate(&self, offset: usize) -> (usize, usize) {
+        let absolute = self.head + offset;
+
+        (absolute / CHUNK, absolute % CHUNK)
+    }
+
+    /// Appends bytes at the end of the buffer, allocating chunks as needed.
+    ///
+    /// Existing bytes are never moved.
+    pub fn append(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            if self.remaining_capacity() == 0 {
+                self.chunks.push_back(Box::new([0; CHUNK]));
+            }
+
+            let (chunk, pos) = self.locate(self.len);
+            let count = data.len().min(CHUNK - pos);
+
+            self.chunks[chunk][pos..pos + count].copy_from_slice(&data[..count]);
+
+            self.len += count;
+            data = &data[count..];
+        }
+    }
+
+    /// Returns the byte at `offset`, or `None` if `offset >= len()`.
+    pub fn get(&self, offset: usize) -> Option<u8> {
+        if offset >= self.len {
+            return None;
+        }
+
+        let (chunk, pos) = self.locate(offset);
+
+        Some(self.chunks[chunk][pos])
+    }
+
+    /// Returns the longest contiguous slice starting at `offset`.
+    ///
+    /// The slice ends at the end of the chunk holding `offset` or at the end of the readable
+    /// region, whichever comes first. Returns an empty slice if `offset >= len()`.
+    pub fn contiguous_at(&self, offset: usize) -> &[u8] {
+        if offset >= self.len {
+            return &[];
+        }
+
+        let (chunk, pos) = self.locate(offset);
+        let end = (pos + self.len - offset).min(CHUNK);
+
+        &self.chunks[chunk][pos..end]
+    }
+
+    /// Copies bytes starting at `offset` into `out`, crossing chunk boundaries as needed.
+    ///
+    /// Returns the number of bytes copied, which is smaller than `out.len()` when the readable
+    /// region ends first.
+    pub fn read_at(&self, offset: usize, out: &mut [u8]) -> usize {
+        let mut copied = 0;
+
+        while copied < out.len() {
+            let slice = self.contiguous_at(offset + copied);
+            if slice.is_empty() {
+                break;
+            }
+
+            let count = slice.len().min(out.len() - copied);
+            out[copied..copied + count].copy_from_slice(&slice[..count]);
+            copied += count;
+        }
+
+        copied
+    }
+
+    /// Marks up to `count` bytes at the front as consumed and releases emptied chunks.
+    ///
+    /// Returns the number of bytes actually consumed.
+    pub fn consume(&mut self, count: usize) -> usize {
+        let count = count.min(self.len);
+
+        self.head += count;
+        self.len -= count;
+
+        while self.head >= CHUNK {
+            self.chunks.pop_front();
+            self.head -= CHUNK;
+        }
+
+        if self.len == 0 && self.chunks.len() == 1 {
+            // The last chunk is fully read: rewind it instead of keeping a hole at the front.
+            self.head = 0;
+        }
+
+        count
+    }
+
+    /// Removes every byte and releases all chunks.
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the readable bytes as contiguous slices, one per chunk.
+    pub fn chunks(&self) -> Chunks<'_, CHUNK> {
+        Chunks {
+            buffer: self,
+            offset: 0,
+        }
+    }
+}
+
+/// Iterator over the readable slices of a [`ChunkedBuffer`], created by
+/// [`ChunkedBuffer::chunks`].
+pub struct Chunks<'a, const CHUNK: usize> {
+    buffer: &'a ChunkedBuffer<CHUNK>,
+    offset: usize,
+}
+
+impl<'a, const CHUNK: usize> Iterator for Chunks<'a, CHUNK> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slice = self.buffer.contiguous_at(self.offset);
+        if slice.is_empty() {
+            return None;
+        }
+
+        self.offset += slice.len();
+
+        Some(slice)
+    }
+}
+
+/// Serializes the readable bytes as a single byte string.
+#[cfg(feature = "serde")]
+impl<const CHUNK: usize> serde::Serialize for ChunkedBuffer<CHUNK> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = alloc::vec![0; self.len];
+        self.read_at(0, &mut bytes);
+
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+/// Deserializes a byte string, or a sequence of bytes for formats without native byte strings.
+#[cfg(feature = "serde")]
+impl<'de, const CHUNK: usize> serde::Deserialize<'de> for ChunkedBuffer<CHUNK> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::fmt;
+        use serde::de::{SeqAccess, Visitor};
+
+        struct BufferVisitor<const CHUNK: usize>;
+
+        impl<'de, const CHUNK: usize> Visitor<'de> for BufferVisitor<CHUNK> {
+            type Value = ChunkedBuffer<CHUNK>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a byte string")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+                let mut buffer = ChunkedBuffer::new();
+                buffer.append(bytes);
+
+                Ok(buffer)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut buffer = ChunkedBuffer::new();
+
+                while let Some(byte) = seq.next_element::<u8>()? {
+                    buffer.append(&[byte]);
+                }
+
+                Ok(buffer)
+            }
+        }
+
+        deserializer.deserialize_bytes(BufferVisitor)
+    }
+}
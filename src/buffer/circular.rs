@@ -0,0 +1,390 @@
+//! Fixed-capacity circular buffer with a configurable overflow policy.
+//!
+//! This module provides a ring buffer storing up to `N` values inline, without heap allocation,
+//! which makes it suitable for sliding-window metrics in `no_std` environments.
+//!
+//! # Overview
+//!
+//! Values live in an array of uninitialized slots. `head` is the slot of the front value and
+//! `len` the number of stored values; positions wrap around modulo `N`. When the buffer is full,
+//! the [`OverflowPolicy`] chosen at construction decides whether a push is rejected with
+//! a [`CapacityError`] or evicts the value at the opposite end and hands it back.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::buffer::{OverflowPolicy, SizedCircularBuffer};
+//!
+//! let mut window: SizedCircularBuffer<u32, 3> = SizedCircularBuffer::new(OverflowPolicy::Overwrite);
+//!
+//! window.push_back(1).unwrap();
+//! window.push_back(2).unwrap();
+//! window.push_back(3).unwrap();
+//!
+//! assert!(matches!(window.push_back(4), Ok(Some(1))));
+//! assert_eq!(window.iter().sum::<u32>(), 9);
+//! ```
+
+use crate::CapacityError;
+
+use core::mem::MaybeUninit;
+use core::ops::{Index, IndexMut};
+
+/// Behavior of a full [`SizedCircularBuffer`] when a new value is pushed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Refuse the new value and hand it back in a `CapacityError`.
+    Reject,
+    /// Evict the value at the opposite end and return it.
+    Overwrite,
+}
+
+/// A fixed-capacity circular buffer.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of values stored in the buffer
+/// - `N`: Compile-time capacity
+///
+/// # Fields
+///
+/// - `slots`: Array of uninitialized value slots
+/// - `head`: Slot of the front value
+/// - `len`: Current number of values
+/// - `policy`: Behavior when pushing into a full buffer
+pub struct SizedCircularBuffer<T, const N: usize> {
+    slots: [MaybeUninit<T>; N],
+    head: usize,
+    len: usize,
+    policy: OverflowPolicy,
+}
+
+impl<T: Clone, const N: usize> Clone for SizedCircularBuffer<T, N> {
+    fn clone(&self) -> Self {
+        let mut new_buffer = Self::new(self.policy);
+
+        for value in self.iter() {
+            new_buffer.slots[new_buffer.len].write(value.clone());
+            new_buffer.len += 1;
+        }
+
+        new_buffer
+    }
+}
+
+impl<T, const N: usize> Default for SizedCircularBuffer<T, N> {
+    fn default() -> Self {
+        Self::new(OverflowPolicy::Reject)
+    }
+}
+
+impl<T, const N: usize> Drop for SizedCircularBuffer<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> SizedCircularBuffer<T, N> {
+    /// Creates an empty buffer using the given overflow policy.
+    pub const fn new(policy: OverflowPolicy) -> Self {
+        Self {
+            slots: [const { MaybeUninit::uninit() }; N],
+            head: 0,
+            len: 0,
+            policy,
+        }
+    }
+
+    /// Returns the number of values currently stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the buffer holds `N` values.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the maximum number of values the buffer can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the overflow policy of the buffer.
+    #[inline]
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Maps a logical position to a slot of the backing array.
+    #[inline]
+    fn slot(&self, index: usize) -> usize {
+        (self.head + index) % N
+    }
+
+    /// Appends a value at the back.
+    ///
+    /// Returns `Ok(None)` when the value was stored in a free slot, or `Ok(Some(front))`
+    /// when the buffer was full and the front value was evicted to make room.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the buffer is full and the policy is
+    /// `OverflowPolicy::Reject`.
+    pub fn push_back(&mut self, value: T) -> Result<Option<T>, CapacityError<T>> {
+        if N == 0 {
+            return match self.policy {
+                OverflowPolicy::Reject => Err(CapacityError::new(value)),
+                OverflowPolicy::Overwrite => Ok(Some(value)),
+            };
+        }
+
+        let evicted = if self.is_full() {
+            match self.policy {
+                OverflowPolicy::Reject => return Err(CapacityError::new(value)),
+                OverflowPolicy::Overwrite => self.pop_front(),
+            }
+        } else {
+            None
+        };
+
+        let slot = self.slot(self.len);
+        self.slots[slot].write(value);
+        self.len += 1;
+
+        Ok(evicted)
+    }
+
+    /// Prepends a value at the front.
+    ///
+    /// Returns `Ok(None)` when the value was stored in a free slot, or `Ok(Some(back))`
+    /// when the buffer was full and the back value was evicted to make room.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the buffer is full and the policy is
+    /// `OverflowPolicy::Reject`.
+    pub fn push_front(&mut self, value: T) -> Result<Option<T>, CapacityError<T>> {
+        if N == 0 {
+            return match self.policy {
+                OverflowPolicy::Reject => Err(CapacityError::new(value)),
+                OverflowPolicy::Overwrite => Ok(Some(value)),
+            };
+        }
+
+        let evicted = if self.is_full() {
+            match self.policy {
+                OverflowPolicy::Reject => return Err(CapacityError::new(value)),
+                OverflowPolicy::Overwrite => self.pop_back(),
+            }
+        } else {
+            None
+        };
+
+        self.head = (self.head + N - 1) % N;
+        self.slots[self.head].write(value);
+        self.len += 1;
+
+        Ok(evicted)
+    }
+
+    /// Removes and returns the front value.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let value = unsafe { self.slots[self.head].assume_init_read() };
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    /// Removes and returns the back value.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let slot = self.slot(self.len - 1);
+        self.len -= 1;
+
+        Some(unsafe { self.slots[slot].assume_init_read() })
+    }
+
+    /// Returns a reference to the front value.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back value.
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Returns a reference to the value at logical position `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(unsafe { self.slots[self.slot(index)].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the value at logical position `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let slot = self.slot(index);
+
+        Some(unsafe { self.slots[slot].assume_init_mut() })
+    }
+
+    /// Returns the stored values as two slices, front part first.
+    ///
+    /// The second slice is empty unless the values wrap around the end of the backing array.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let first_len = self.len.min(N - self.head);
+        let second_len = self.len - first_len;
+
+        // SAFETY: both ranges only cover initialized slots.
+        unsafe {
+            let first = &self.slots[self.head..self.head + first_len];
+            let second = &self.slots[..second_len];
+
+            (
+                &*(first as *const [MaybeUninit<T>] as *const [T]),
+                &*(second as *const [MaybeUninit<T>] as *const [T]),
+            )
+        }
+    }
+
+    /// Returns the stored values as two mutable slices, front part first.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let first_len = self.len.min(N - self.head);
+        let second_len = self.len - first_len;
+
+        let (wrapped, rest) = self.slots.split_at_mut(self.head);
+
+        // SAFETY: both ranges only cover initialized slots.
+        unsafe {
+            (
+                &mut *(&mut rest[..first_len] as *mut [MaybeUninit<T>] as *mut [T]),
+                &mut *(&mut wrapped[..second_len] as *mut [MaybeUninit<T>] as *mut [T]),
+            )
+        }
+    }
+
+    /// Removes every value.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+
+        self.head = 0;
+    }
+
+    /// Returns an iterator over the values, from front to back.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        let (first, second) = self.as_slices();
+
+        first.iter().chain(second.iter())
+    }
+
+    /// Returns an iterator over mutable references to the values, from front to back.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        let (first, second) = self.as_mut_slices();
+
+        first.iter_mut().chain(second.iter_mut())
+    }
+}
+
+impl<T, const N: usize> Index<usize> for SizedCircularBuffer<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "index {index} out of range for buffer of length {}",
+                self.len
+            ),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for SizedCircularBuffer<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("index {index} out of range for buffer of length {len}"),
+        }
+    }
+}
+
+/// Serializes the values as a sequence, from front to back.
+///
+/// The overflow policy is configuration rather than content and is not serialized.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for SizedCircularBuffer<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes a sequence of at most `N` values into a buffer using
+/// `OverflowPolicy::Reject`.
+///
+/// A longer sequence is rejected with an `invalid_length` error instead of being truncated.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de>
+    for SizedCircularBuffer<T, N>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::fmt;
+        use core::marker::PhantomData;
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct BufferVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> Visitor<'de> for BufferVisitor<T, N> {
+            type Value = SizedCircularBuffer<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {N} elements")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                if let Some(hint) = seq.size_hint()
+                    && hint > N
+                {
+                    return Err(A::Error::invalid_length(hint, &self));
+                }
+
+                let mut buffer = SizedCircularBuffer::new(OverflowPolicy::Reject);
+
+                while let Some(value) = seq.next_element()? {
+                    if buffer.push_back(value).is_err() {
+                        return Err(A::Error::invalid_length(N + 1, &self));
+                    }
+                }
+
+                Ok(buffer)
+            }
+        }
+
+        deserializer.deserialize_seq(BufferVisitor(PhantomData))
+    }
+}
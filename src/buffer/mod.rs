@@ -0,0 +1,14 @@
+//! Buffer abstractions built from fixed-size storage and chunked memory blocks.
+//!
+//! - `circular`: Fixed-capacity ring buffer with a configurable overflow policy
+//! - `chunked`: Segmented byte buffer growing by fixed-size chunks (std or alloc)
+
+pub mod circular;
+
+#[cfg(feature = "alloc")]
+pub mod chunked;
+
+pub use circular::{OverflowPolicy, SizedCircularBuffer};
+
+#[cfg(feature = "alloc")]
+pub use chunked::ChunkedBuffer;
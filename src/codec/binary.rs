@@ -0,0 +1,429 @@
+//! Versioned binary snapshot format.
+//!
+//! This module defines the [`Encode`] and [`Decode`] traits, their implementations for
+//! primitive values, and the framing shared by every fixed-capacity container snapshot.
+//!
+//! # Format
+//!
+//! All integers are little-endian. A snapshot is laid out as:
+//!
+//! | Field         | Size          | Content                                         |
+//! |---------------|---------------|-------------------------------------------------|
+//! | `magic`       | 2             | `b"DS"`                                         |
+//! | `version`     | 1             | [`FORMAT_VERSION`]                              |
+//! | `kind`        | 1             | [`SnapshotKind`] of the encoded container       |
+//! | `capacity`    | 4             | Compile-time capacity of the encoded container  |
+//! | `length`      | 4             | Number of encoded elements                      |
+//! | `payload_len` | 4             | Size of the element payload in bytes            |
+//! | payload       | `payload_len` | Elements, encoded back to back in order         |
+//! | `checksum`    | 4             | CRC-32 (IEEE) of every preceding byte           |
+//!
+//! The checksum is verified before any element is decoded, so a torn flash write is reported
+//! as [`CodecError::ChecksumMismatch`] rather than as garbage values.
+
+use core::error::Error;
+use core::fmt;
+
+/// Version written into every snapshot and the only version accepted on load.
+pub const FORMAT_VERSION: u8 = 1;
+
+/// Marker opening every snapshot.
+const MAGIC: [u8; 2] = *b"DS";
+
+/// Size of the snapshot header in bytes.
+pub const HEADER_LEN: usize = 16;
+
+/// Size of the trailing checksum in bytes.
+pub const CHECKSUM_LEN: usize = 4;
+
+/// Errors returned while encoding or decoding binary snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodecError {
+    /// The output buffer cannot hold the encoded value.
+    BufferTooSmall,
+    /// The input ends before the value is complete.
+    UnexpectedEnd,
+    /// The input does not start with the snapshot marker.
+    InvalidMagic,
+    /// The snapshot was written with an unsupported format version.
+    UnsupportedVersion(u8),
+    /// The snapshot holds a different kind of container.
+    KindMismatch,
+    /// The snapshot holds more elements than the target container can store.
+    LengthExceedsCapacity,
+    /// The stored checksum does not match the snapshot content.
+    ChecksumMismatch,
+    /// An encoded value is not valid for its type.
+    InvalidValue,
+    /// The payload holds bytes beyond the encoded elements.
+    TrailingBytes,
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall => f.write_str("output buffer too small"),
+            Self::UnexpectedEnd => f.write_str("unexpected end of input"),
+            Self::InvalidMagic => f.write_str("input is not a snapshot"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot format version {version}")
+            }
+            Self::KindMismatch => f.write_str("snapshot holds a different kind of container"),
+            Self::LengthExceedsCapacity => {
+                f.write_str("snapshot holds more elements than the container capacity")
+            }
+            Self::ChecksumMismatch => f.write_str("snapshot checksum mismatch"),
+            Self::InvalidValue => f.write_str("invalid encoded value"),
+            Self::TrailingBytes => f.write_str("trailing bytes after the encoded elements"),
+        }
+    }
+}
+
+impl Error for CodecError {}
+
+/// Types that can be written in the binary snapshot format.
+pub trait Encode {
+    /// Returns the exact number of bytes written by [`encode`](Encode::encode).
+    fn encoded_len(&self) -> usize;
+
+    /// Writes the value at the beginning of `out` and returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CodecError::BufferTooSmall` if `out` is shorter than `encoded_len()`.
+    fn encode(&self, out: &mut [u8]) -> Result<usize, CodecError>;
+}
+
+/// Types that can be read back from the binary snapshot format without allocation.
+pub trait Decode: Sized {
+    /// Reads a value from the beginning of `input` and returns it with the number of bytes
+    /// consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CodecError::UnexpectedEnd` if `input` is truncated, or another `CodecError`
+    /// if the bytes do not describe a valid value.
+    fn decode(input: &[u8]) -> Result<(Self, usize), CodecError>;
+}
+
+macro_rules! impl_codec_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Encode for $ty {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    size_of::<$ty>()
+                }
+
+                fn encode(&self, out: &mut [u8]) -> Result<usize, CodecError> {
+                    let bytes = self.to_le_bytes();
+                    out.get_mut(..bytes.len())
+                        .ok_or(CodecError::BufferTooSmall)?
+                        .copy_from_slice(&bytes);
+
+                    Ok(bytes.len())
+                }
+            }
+
+            impl Decode for $ty {
+                fn decode(input: &[u8]) -> Result<(Self, usize), CodecError> {
+                    let bytes = input
+                        .get(..size_of::<$ty>())
+                        .ok_or(CodecError::UnexpectedEnd)?;
+
+                    Ok((<$ty>::from_le_bytes(bytes.try_into().unwrap()), bytes.len()))
+                }
+            }
+        )*
+    };
+}
+
+impl_codec_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Encode for bool {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        1
+    }
+
+    fn encode(&self, out: &mut [u8]) -> Result<usize, CodecError> {
+        (*self as u8).encode(out)
+    }
+}
+
+impl Decode for bool {
+    fn decode(input: &[u8]) -> Result<(Self, usize), CodecError> {
+        match u8::decode(input)? {
+            (0, read) => Ok((false, read)),
+            (1, read) => Ok((true, read)),
+            _ => Err(CodecError::InvalidValue),
+        }
+    }
+}
+
+impl Encode for char {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        4
+    }
+
+    fn encode(&self, out: &mut [u8]) -> Result<usize, CodecError> {
+        (*self as u32).encode(out)
+    }
+}
+
+impl Decode for char {
+    fn decode(input: &[u8]) -> Result<(Self, usize), CodecError> {
+        let (code, read) = u32::decode(input)?;
+
+        Ok((char::from_u32(code).ok_or(CodecError::InvalidValue)?, read))
+    }
+}
+
+impl<const N: usize> Encode for [u8; N] {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        N
+    }
+
+    fn encode(&self, out: &mut [u8]) -> Result<usize, CodecError> {
+        out.get_mut(..N)
+            .ok_or(CodecError::BufferTooSmall)?
+            .copy_from_slice(self);
+
+        Ok(N)
+    }
+}
+
+impl<const N: usize> Decode for [u8; N] {
+    fn decode(input: &[u8]) -> Result<(Self, usize), CodecError> {
+        let bytes = input.get(..N).ok_or(CodecError::UnexpectedEnd)?;
+
+        Ok((bytes.try_into().unwrap(), N))
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    fn encoded_len(&self) -> usize {
+        1 + self.as_ref().map_or(0, Encode::encoded_len)
+    }
+
+    fn encode(&self, out: &mut [u8]) -> Result<usize, CodecError> {
+        match self {
+            None => 0u8.encode(out),
+            Some(value) => {
+                if out.len() < self.encoded_len() {
+                    return Err(CodecError::BufferTooSmall);
+                }
+
+                out[0] = 1;
+                Ok(1 + value.encode(&mut out[1..])?)
+            }
+        }
+    }
+}
+
+impl<T: Decode> Decode for Option<T> {
+    fn decode(input: &[u8]) -> Result<(Self, usize), CodecError> {
+        match u8::decode(input)? {
+            (0, read) => Ok((None, read)),
+            (1, read) => {
+                let (value, len) = T::decode(&input[read..])?;
+                Ok((Some(value), read + len))
+            }
+            _ => Err(CodecError::InvalidValue),
+        }
+    }
+}
+
+impl<A: Encode, B: Encode> Encode for (A, B) {
+    fn encoded_len(&self) -> usize {
+        self.0.encoded_len() + self.1.encoded_len()
+    }
+
+    fn encode(&self, out: &mut [u8]) -> Result<usize, CodecError> {
+        if out.len() < self.encoded_len() {
+            return Err(CodecError::BufferTooSmall);
+        }
+
+        let first = self.0.encode(out)?;
+        Ok(first + self.1.encode(&mut out[first..])?)
+    }
+}
+
+impl<A: Decode, B: Decode> Decode for (A, B) {
+    fn decode(input: &[u8]) -> Result<(Self, usize), CodecError> {
+        let (a, first) = A::decode(input)?;
+        let (b, second) = B::decode(&input[first..])?;
+
+        Ok(((a, b), first + second))
+    }
+}
+
+/// Identifies the container stored in a snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SnapshotKind {
+    /// A [`SizedDoubleLinkedList`](crate::double_linked_list::SizedDoubleLinkedList).
+    DoubleLinkedList = 1,
+    /// A [`SizedWorkStealingPool`](crate::workstealing::SizedWorkStealingPool).
+    WorkStealingPool = 2,
+}
+
+/// Header fields of a snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotHeader {
+    /// Container stored in the snapshot.
+    pub kind: SnapshotKind,
+    /// Capacity of the container that wrote the snapshot.
+    pub capacity: u32,
+    /// Number of stored elements.
+    pub length: u32,
+    /// Size of the element payload in bytes.
+    pub payload_len: u32,
+}
+
+impl SnapshotHeader {
+    /// Parses and checks the header at the beginning of `input`.
+    ///
+    /// Only the header is inspected: the checksum is verified by [`read_snapshot`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `CodecError::UnexpectedEnd`, `CodecError::InvalidMagic`,
+    /// `CodecError::UnsupportedVersion` or `CodecError::InvalidValue` for an unknown kind.
+    pub fn read(input: &[u8]) -> Result<Self, CodecError> {
+        let header = input.get(..HEADER_LEN).ok_or(CodecError::UnexpectedEnd)?;
+
+        if header[..2] != MAGIC {
+            return Err(CodecError::InvalidMagic);
+        }
+
+        if header[2] != FORMAT_VERSION {
+            return Err(CodecError::UnsupportedVersion(header[2]));
+        }
+
+        let kind = match header[3] {
+            1 => SnapshotKind::DoubleLinkedList,
+            2 => SnapshotKind::WorkStealingPool,
+            _ => return Err(CodecError::InvalidValue),
+        };
+
+        Ok(Self {
+            kind,
+            capacity: u32::decode(&header[4..])?.0,
+            length: u32::decode(&header[8..])?.0,
+            payload_len: u32::decode(&header[12..])?.0,
+        })
+    }
+}
+
+/// Computes the CRC-32 (IEEE 802.3) of `bytes` without a lookup table.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in bytes {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+/// Returns the size of a snapshot holding `values`.
+pub(crate) fn snapshot_len<'a, T: Encode + 'a>(values: impl Iterator<Item = &'a T>) -> usize {
+    HEADER_LEN + values.map(Encode::encoded_len).sum::<usize>() + CHECKSUM_LEN
+}
+
+/// Writes a complete snapshot of `values` into `out` and returns its size.
+pub(crate) fn write_snapshot<'a, T: Encode + 'a>(
+    kind: SnapshotKind,
+    capacity: usize,
+    values: impl Iterator<Item = &'a T> + Clone,
+    out: &mut [u8],
+) -> Result<usize, CodecError> {
+    let total = snapshot_len(values.clone());
+    if out.len() < total {
+        return Err(CodecError::BufferTooSmall);
+    }
+
+    let mut pos = HEADER_LEN;
+    let mut length = 0u32;
+
+    for value in values {
+        pos += value.encode(&mut out[pos..])?;
+        length += 1;
+    }
+
+    out[..2].copy_from_slice(&MAGIC);
+    out[2] = FORMAT_VERSION;
+    out[3] = kind as u8;
+    (capacity as u32).encode(&mut out[4..])?;
+    length.encode(&mut out[8..])?;
+    ((pos - HEADER_LEN) as u32).encode(&mut out[12..])?;
+
+    let checksum = crc32(&out[..pos]);
+    pos += checksum.encode(&mut out[pos..])?;
+
+    Ok(pos)
+}
+
+/// Validates the snapshot at the beginning of `input` for a container of kind `kind` able to
+/// hold `capacity` elements.
+///
+/// Returns the header, the element payload and the total snapshot size.
+pub(crate) fn read_snapshot(
+    kind: SnapshotKind,
+    capacity: usize,
+    input: &[u8],
+) -> Result<(SnapshotHeader, &[u8], usize), CodecError> {
+    let header = SnapshotHeader::read(input)?;
+
+    if header.kind != kind {
+        return Err(CodecError::KindMismatch);
+    }
+
+    if header.length as usize > capacity {
+        return Err(CodecError::LengthExceedsCapacity);
+    }
+
+    // A crafted payload length may overflow `usize` on 32-bit targets.
+    let end = HEADER_LEN
+        .checked_add(header.payload_len as usize)
+        .ok_or(CodecError::UnexpectedEnd)?;
+    let total = end
+        .checked_add(CHECKSUM_LEN)
+        .ok_or(CodecError::UnexpectedEnd)?;
+    let body = input.get(..end).ok_or(CodecError::UnexpectedEnd)?;
+    let (checksum, _) = u32::decode(&input[end..])?;
+
+    if crc32(body) != checksum {
+        return Err(CodecError::ChecksumMismatch);
+    }
+
+    Ok((header, &body[HEADER_LEN..], total))
+}
+
+/// Decodes the `length` elements of a validated payload in order, handing each one to `push`.
+pub(crate) fn read_elements<T: Decode>(
+    header: &SnapshotHeader,
+    mut payload: &[u8],
+    mut push: impl FnMut(T) -> Result<(), CodecError>,
+) -> Result<(), CodecError> {
+    for _ in 0..header.length {
+        let (value, read) = T::decode(payload)?;
+        payload = &payload[read..];
+
+        push(value)?;
+    }
+
+    if !payload.is_empty() {
+        return Err(CodecError::TrailingBytes);
+    }
+
+    Ok(())
+}
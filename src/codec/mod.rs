@@ -0,0 +1,13 @@
+//! Zero-dependency binary encoding for fixed-capacity containers.
+//!
+//! Provides the [`Encode`]/[`Decode`] traits and a versioned, checksummed snapshot format
+//! used to checkpoint containers to flash or other raw storage.
+//! - `binary`: Traits, primitive encodings and snapshot framing
+//!
+//! Snapshots are written into and read from caller-provided byte slices, so neither side
+//! allocates. Implementations are provided for `SizedDoubleLinkedList` and
+//! `SizedWorkStealingPool`.
+
+pub mod binary;
+
+pub use binary::{CodecError, Decode, Encode, SnapshotHeader, SnapshotKind};
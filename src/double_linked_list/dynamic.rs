@@ -1,10 +1,11 @@
 //! Double-linked list implementation with heap allocation using raw pointers.
 //!
 //! This module provides a generic double-linked list with unlimited capacity using heap allocation.
-//! Unlike the sized variant, this list can grow indefinitely and is designed for std environments
-//! where the capacity is not known at compile time.
+//! Unlike the sized variant, this list can grow indefinitely and is designed for environments
+//! with a heap allocator where the capacity is not known at compile time.
 //!
-//! **Note**: This module is only available when the `no-std` feature is **not** enabled.
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
 //! For scenarios where the maximum capacity is known and less than 64, prefer using
 //! [`SizedDoubleLinkedList`](super::sized::SizedDoubleLinkedList) which offers better
 //! performance through stack allocation.
@@ -48,11 +49,17 @@
 //! list.insert_tail(42);
 //! ```
 
-use crate::LinkedListError;
+use super::sized::{SizedDoubleLinkedList, ValidK};
+use crate::{CapacityError, Const, IndexError, LinkedListError};
 
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
 use core::ptr::NonNull;
-use std::vec::Vec;
 
 /// A double-linked list with heap-allocated nodes using raw pointers.
 ///
@@ -78,8 +85,8 @@ pub struct DoubleLinkedList<T: Sized> {
 /// A single node in the double-linked list.
 ///
 /// Each node stores a value and raw pointers to the previous and next nodes.
-struct Node<T> {
-    value: T,
+pub(crate) struct Node<T> {
+    pub(crate) value: T,
 
     prev: Option<NonNull<Node<T>>>,
     next: Option<NonNull<Node<T>>>,
@@ -134,6 +141,173 @@ impl<T: Sized> Drop for DoubleLinkedList<T> {
     }
 }
 
+// SAFETY: the list owns its nodes exclusively, like a `Box<T>` chain would.
+unsafe impl<T: Send> Send for DoubleLinkedList<T> {}
+
+// SAFETY: shared access only hands out `&T`.
+unsafe impl<T: Sync> Sync for DoubleLinkedList<T> {}
+
+impl<T: PartialEq> PartialEq for DoubleLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for DoubleLinkedList<T> {}
+
+/// Compares lists lexicographically in element order.
+impl<T: PartialOrd> PartialOrd for DoubleLinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for DoubleLinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashes the length followed by every value in order.
+impl<T: Hash> Hash for DoubleLinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DoubleLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Index<usize> for DoubleLinkedList<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        match self.get(idx) {
+            Ok(value) => value,
+            Err(_) => panic!("index {idx} out of range for list of length {}", self.len),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for DoubleLinkedList<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        let len = self.len;
+
+        match self.get_mut(idx) {
+            Ok(value) => value,
+            Err(_) => panic!("index {idx} out of range for list of length {len}"),
+        }
+    }
+}
+
+impl<T> Extend<T> for DoubleLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.link_before(None, value);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for DoubleLinkedList<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for DoubleLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list: Self = Default::default();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for DoubleLinkedList<T> {
+    fn from(values: [T; N]) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<Vec<T>> for DoubleLinkedList<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+/// Moves every value of a fixed-size list into a heap-allocated list, preserving order.
+impl<T, const K: usize> From<SizedDoubleLinkedList<T, K>> for DoubleLinkedList<T>
+where
+    Const<K>: ValidK,
+{
+    fn from(list: SizedDoubleLinkedList<T, K>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+/// Moves every value into a fixed-size list.
+///
+/// # Errors
+///
+/// Returns a `CapacityError` holding the untouched list if it holds more than `K` values.
+impl<T, const K: usize> TryFrom<DoubleLinkedList<T>> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    type Error = CapacityError<DoubleLinkedList<T>>;
+
+    fn try_from(list: DoubleLinkedList<T>) -> Result<Self, Self::Error> {
+        if list.len() > K {
+            return Err(CapacityError::new(list));
+        }
+
+        Ok(list.into_iter().collect())
+    }
+}
+
+/// Moves every value of a vector into a fixed-size list.
+///
+/// # Errors
+///
+/// Returns a `CapacityError` holding the untouched vector if it holds more than `K` values.
+impl<T, const K: usize> TryFrom<Vec<T>> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    type Error = CapacityError<Vec<T>>;
+
+    fn try_from(values: Vec<T>) -> Result<Self, Self::Error> {
+        if values.len() > K {
+            return Err(CapacityError::new(values));
+        }
+
+        Ok(values.into_iter().collect())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoubleLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for DoubleLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
 impl<T: Sized> DoubleLinkedList<T> {
     /// Returns the number of elements currently in the list.
     #[inline]
@@ -156,9 +330,9 @@ impl<T: Sized> DoubleLinkedList<T> {
     }
 
     /// Gets a mutable node pointer by index, traversing from the optimal end.
-    fn get_node_mut(&mut self, idx: usize) -> Result<NonNull<Node<T>>, LinkedListError> {
+    fn get_node_mut(&mut self, idx: usize) -> Result<NonNull<Node<T>>, IndexError> {
         if idx >= self.len {
-            return Err(LinkedListError::IndexOutOfRange);
+            return Err(IndexError::new(idx, self.len));
         }
 
         unsafe {
@@ -181,7 +355,9 @@ impl<T: Sized> DoubleLinkedList<T> {
     }
 
     /// Inserts a value at the end of the list.
-    pub fn insert_tail(&mut self, value: T) -> Result<(), LinkedListError> {
+    ///
+    /// Never fails; the `Result` mirrors the fixed-size list.
+    pub fn insert_tail(&mut self, value: T) -> Result<(), CapacityError<T>> {
         let n = Node::new(value);
         let new = NonNull::new(Box::into_raw(n)).unwrap();
 
@@ -202,7 +378,9 @@ impl<T: Sized> DoubleLinkedList<T> {
     }
 
     /// Inserts a value at the beginning of the list.
-    pub fn insert_head(&mut self, value: T) -> Result<(), LinkedListError> {
+    ///
+    /// Never fails; the `Result` mirrors the fixed-size list.
+    pub fn insert_head(&mut self, value: T) -> Result<(), CapacityError<T>> {
         let n = Node::new(value);
         let new = NonNull::new(Box::into_raw(n)).unwrap();
 
@@ -227,9 +405,9 @@ impl<T: Sized> DoubleLinkedList<T> {
     /// # Errors
     ///
     /// - Returns `LinkedListError::IndexOutOfRange` if `idx >= len()`
-    pub fn insert_after(&mut self, idx: usize, value: T) -> Result<(), LinkedListError> {
+    pub fn insert_after(&mut self, idx: usize, value: T) -> Result<(), LinkedListError<T>> {
         if idx >= self.len {
-            return Err(LinkedListError::IndexOutOfRange);
+            return Err(IndexError::new(idx, self.len).into());
         }
 
         let current = self.get_node_mut(idx)?;
@@ -262,16 +440,16 @@ impl<T: Sized> DoubleLinkedList<T> {
     /// # Errors
     ///
     /// - Returns `LinkedListError::IndexOutOfRange` if `idx >= len()`
-    pub fn insert_before(&mut self, idx: usize, value: T) -> Result<(), LinkedListError> {
+    pub fn insert_before(&mut self, idx: usize, value: T) -> Result<(), LinkedListError<T>> {
         if idx >= self.len {
             if self.len == 0 && idx == 0 {
-                return self.insert_tail(value);
+                return self.insert_tail(value).map_err(LinkedListError::from);
             }
-            return Err(LinkedListError::IndexOutOfRange);
+            return Err(IndexError::new(idx, self.len).into());
         }
 
         if idx == 0 {
-            return self.insert_head(value);
+            return self.insert_head(value).map_err(LinkedListError::from);
         }
 
         let current = self.get_node_mut(idx)?;
@@ -303,10 +481,10 @@ impl<T: Sized> DoubleLinkedList<T> {
     ///
     /// # Errors
     ///
-    /// - Returns `LinkedListError::IndexOutOfRange` if `idx >= len()`
-    pub fn get(&self, idx: usize) -> Result<&T, LinkedListError> {
+    /// Returns an `IndexError` if `idx >= len()`
+    pub fn get(&self, idx: usize) -> Result<&T, IndexError> {
         if idx >= self.len {
-            return Err(LinkedListError::IndexOutOfRange);
+            return Err(IndexError::new(idx, self.len));
         }
 
         unsafe {
@@ -334,10 +512,10 @@ impl<T: Sized> DoubleLinkedList<T> {
     ///
     /// # Errors
     ///
-    /// - Returns `LinkedListError::IndexOutOfRange` if `idx >= len()`
-    pub fn remove(&mut self, idx: usize) -> Result<(), LinkedListError> {
+    /// Returns an `IndexError` if `idx >= len()`
+    pub fn remove(&mut self, idx: usize) -> Result<(), IndexError> {
         if idx >= self.len {
-            return Err(LinkedListError::IndexOutOfRange);
+            return Err(IndexError::new(idx, self.len));
         }
 
         let n = if idx < self.len / 2 {
@@ -478,39 +656,241 @@ impl<T: Sized> DoubleLinkedList<T> {
 
     /// Sorts the list in place using the given comparison function.
     ///
-    /// Uses merge sort (stable sort) with Vec for intermediate storage.
-    pub fn sort_by<F>(&mut self, compare: F)
+    /// Uses a bottom-up merge sort (stable sort) that relinks the nodes instead of moving
+    /// values, so `T` does not need to be `Clone` and no value changes address. If `compare`
+    /// panics, the pending runs are spliced back so the list keeps every element, in an
+    /// unspecified order.
+    ///
+    /// # Complexity
+    /// - Time: O(n log n)
+    /// - Space: O(1)
+    pub fn sort_by<F>(&mut self, mut compare: F)
     where
-        T: Clone,
-        F: Fn(&T, &T) -> Ordering,
+        F: FnMut(&T, &T) -> Ordering,
     {
         if self.len <= 1 {
             return;
         }
 
-        // Collect all values into a Vec
-        let mut values = Vec::with_capacity(self.len);
+        let rest = self.head;
+        let mut runs = SortRuns {
+            list: self,
+            head: None,
+            tail: None,
+            left: None,
+            left_len: 0,
+            rest,
+        };
+        let mut width = 1;
+
+        unsafe {
+            loop {
+                let mut merges = 0;
+
+                while let Some(start) = runs.rest {
+                    merges += 1;
+
+                    // Split off a left run of up to `width` nodes starting at `start`.
+                    let mut q = Some(start);
+                    let mut p_size = 0;
+                    while p_size < width {
+                        p_size += 1;
+                        q = q.and_then(|n| n.as_ref().next);
+                        if q.is_none() {
+                            break;
+                        }
+                    }
+
+                    runs.left = Some(start);
+                    runs.left_len = p_size;
+                    runs.rest = q;
+                    let mut q_size = width;
+
+                    // Merge the left run with the right run at the front of `rest`.
+                    while runs.left_len > 0 || (q_size > 0 && runs.rest.is_some()) {
+                        let take_left = if runs.left_len == 0 {
+                            false
+                        } else if q_size == 0 || runs.rest.is_none() {
+                            true
+                        } else {
+                            let (l, r) = (runs.left.unwrap(), runs.rest.unwrap());
+                            compare(&l.as_ref().value, &r.as_ref().value) != Ordering::Greater
+                        };
+
+                        let e = if take_left {
+                            let e = runs.left.unwrap();
+                            runs.left = e.as_ref().next;
+                            runs.left_len -= 1;
+                            e
+                        } else {
+                            let e = runs.rest.unwrap();
+                            runs.rest = e.as_ref().next;
+                            q_size -= 1;
+                            e
+                        };
+
+                        match runs.tail {
+                            Some(t) => (*t.as_ptr()).next = Some(e),
+                            None => runs.head = Some(e),
+                        }
+
+                        (*e.as_ptr()).prev = runs.tail;
+                        runs.tail = Some(e);
+                    }
+                }
+
+                if let Some(t) = runs.tail {
+                    (*t.as_ptr()).next = None;
+                }
+
+                if merges <= 1 {
+                    break;
+                }
+
+                // The merged pass becomes the input of the next one.
+                runs.rest = runs.head.take();
+                runs.tail = None;
+                width *= 2;
+            }
+        }
+    }
+
+    /// Sorts the list in place using the given comparison function, without guaranteeing
+    /// the order of equal elements.
+    ///
+    /// Relies on the same node-relinking merge sort as [`Self::sort_by`].
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.sort_by(compare);
+    }
+
+    /// Sorts the list in place by the key extracted from each element (stable sort).
+    ///
+    /// The key function is called O(n log n) times; prefer [`Self::sort_by_cached_key`]
+    /// when computing the key is expensive.
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the list in place by the key extracted from each element (stable sort),
+    /// calling the key function only once per element.
+    ///
+    /// Keys are cached alongside node pointers in a `Vec`; values are never moved. Nodes are
+    /// relinked only once sorting succeeded, so a panicking key function leaves the list
+    /// untouched.
+    pub fn sort_by_cached_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        if self.len <= 1 {
+            return;
+        }
+
+        let mut keyed = Vec::with_capacity(self.len);
         let mut current = self.head;
         while let Some(n) = current {
             unsafe {
                 let node = n.as_ref();
-                values.push(node.value.clone());
+                keyed.push((f(&node.value), n));
                 current = node.next;
             }
         }
 
-        // Sort the Vec
-        values.sort_by(&compare);
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        self.relink(keyed.into_iter().map(|(_, n)| n));
+    }
+
+    /// Relinks every node of the list in the order yielded by `nodes`, which must yield each
+    /// node of this list exactly once.
+    fn relink<I>(&mut self, nodes: I)
+    where
+        I: IntoIterator<Item = NonNull<Node<T>>>,
+    {
+        let mut prev: Option<NonNull<Node<T>>> = None;
+        for n in nodes {
+            unsafe {
+                (*n.as_ptr()).prev = prev;
+                match prev {
+                    Some(p) => (*p.as_ptr()).next = Some(n),
+                    None => self.head = Some(n),
+                }
+            }
+            prev = Some(n);
+        }
+
+        unsafe {
+            if let Some(last) = prev {
+                (*last.as_ptr()).next = None;
+            }
+        }
+        self.tail = prev;
+    }
 
-        // Update the list values in place
+    /// Returns `true` if the list is sorted according to the given comparison function,
+    /// meaning no element compares `Greater` than its successor.
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
         let mut current = self.head;
-        let mut idx = 0;
-        while let Some(mut n) = current {
+        while let Some(n) = current {
             unsafe {
-                let node = n.as_mut();
-                node.value = values[idx].clone();
+                let node = n.as_ref();
+                if let Some(next) = node.next
+                    && compare(&node.value, &next.as_ref().value) == Ordering::Greater
+                {
+                    return false;
+                }
                 current = node.next;
-                idx += 1;
+            }
+        }
+        true
+    }
+
+    /// Removes consecutive elements considered equal by `same_bucket`, keeping the first one.
+    ///
+    /// Follows the convention of `Vec::dedup_by`: `same_bucket(a, b)` receives the candidate
+    /// element `a` and the previously kept element `b`, and `a` is removed when it returns
+    /// `true`. Removed nodes are deallocated in place.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let mut kept = match self.head {
+            Some(h) => h,
+            None => return,
+        };
+
+        unsafe {
+            while let Some(candidate) = (*kept.as_ptr()).next {
+                let duplicate = same_bucket(
+                    &mut (*candidate.as_ptr()).value,
+                    &mut (*kept.as_ptr()).value,
+                );
+
+                if !duplicate {
+                    kept = candidate;
+                    continue;
+                }
+
+                let next = (*candidate.as_ptr()).next;
+                (*kept.as_ptr()).next = next;
+
+                match next {
+                    Some(nxt) => (*nxt.as_ptr()).prev = Some(kept),
+                    None => self.tail = Some(kept),
+                }
+
+                // Deallocate the node
+                let _ = Box::from_raw(candidate.as_ptr());
+                self.len -= 1;
             }
         }
     }
@@ -521,7 +901,7 @@ impl<T: Sized> DoubleLinkedList<T> {
     pub fn get_sorted_by<F>(&self, compare: F) -> Self
     where
         T: Clone,
-        F: Fn(&T, &T) -> Ordering,
+        F: FnMut(&T, &T) -> Ordering,
     {
         let mut cloned = self.copy();
         cloned.sort_by(compare);
@@ -586,4 +966,752 @@ impl<T: Sized> DoubleLinkedList<T> {
 
         (result, actual_n)
     }
+
+    /// Moves all elements of `other` to the end of the list in O(1), leaving `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        let (other_head, other_tail) = match (other.head.take(), other.tail.take()) {
+            (Some(h), Some(t)) => (h, t),
+            _ => return,
+        };
+
+        unsafe {
+            if let Some(tail_ptr) = self.tail {
+                (*tail_ptr.as_ptr()).next = Some(other_head);
+                (*other_head.as_ptr()).prev = Some(tail_ptr);
+            } else {
+                // Empty list
+                self.head = Some(other_head);
+            }
+        }
+
+        self.tail = Some(other_tail);
+        self.len += other.len;
+        other.len = 0;
+    }
+
+    /// Moves all elements of `other` into the list, before the node at the specified index,
+    /// leaving `other` empty.
+    ///
+    /// Passing `idx == len()` appends the elements at the end. Only the traversal to `idx`
+    /// depends on the list length; relinking is O(1).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndexError` if `idx > len()`
+    pub fn splice_at(&mut self, idx: usize, other: &mut Self) -> Result<(), IndexError> {
+        if idx > self.len {
+            return Err(IndexError::new(idx, self.len));
+        }
+
+        if idx == self.len {
+            self.append(other);
+            return Ok(());
+        }
+
+        let (other_head, other_tail) = match (other.head.take(), other.tail.take()) {
+            (Some(h), Some(t)) => (h, t),
+            _ => return Ok(()),
+        };
+
+        let current = self.get_node_mut(idx)?;
+
+        unsafe {
+            let prev = current.as_ref().prev;
+
+            (*other_head.as_ptr()).prev = prev;
+            (*other_tail.as_ptr()).next = Some(current);
+            (*current.as_ptr()).prev = Some(other_tail);
+
+            if let Some(prv) = prev {
+                (*prv.as_ptr()).next = Some(other_head);
+            } else {
+                // Spliced before head
+                self.head = Some(other_head);
+            }
+        }
+
+        self.len += other.len;
+        other.len = 0;
+
+        Ok(())
+    }
+
+    /// Splits the list in two at the given index.
+    ///
+    /// Returns a new list containing the elements `[idx, len)`, while `self` keeps
+    /// the elements `[0, idx)`. Only the traversal to `idx` depends on the list length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndexError` if `idx > len()`
+    pub fn split_off(&mut self, idx: usize) -> Result<Self, IndexError> {
+        if idx > self.len {
+            return Err(IndexError::new(idx, self.len));
+        }
+
+        if idx == self.len {
+            return Ok(Default::default());
+        }
+
+        let first = self.get_node_mut(idx)?;
+
+        let new_list = unsafe {
+            let last_kept = first.as_ref().prev;
+            (*first.as_ptr()).prev = None;
+
+            if let Some(prv) = last_kept {
+                (*prv.as_ptr()).next = None;
+            } else {
+                // Split at head
+                self.head = None;
+            }
+
+            let new_list = Self {
+                head: Some(first),
+                tail: self.tail,
+                len: self.len - idx,
+            };
+
+            self.tail = last_kept;
+            new_list
+        };
+
+        self.len = idx;
+
+        Ok(new_list)
+    }
+
+    /// Allocates a node for `value` and links it right before `anchor`,
+    /// or at the tail when `anchor` is `None`. Returns the new node.
+    fn link_before(&mut self, anchor: Option<NonNull<Node<T>>>, value: T) -> NonNull<Node<T>> {
+        let n = Node::new(value);
+        let new = NonNull::new(Box::into_raw(n)).unwrap();
+
+        unsafe {
+            let prev = match anchor {
+                Some(a) => a.as_ref().prev,
+                None => self.tail,
+            };
+
+            (*new.as_ptr()).prev = prev;
+            (*new.as_ptr()).next = anchor;
+
+            match prev {
+                Some(prv) => (*prv.as_ptr()).next = Some(new),
+                None => self.head = Some(new),
+            }
+
+            match anchor {
+                Some(a) => (*a.as_ptr()).prev = Some(new),
+                None => self.tail = Some(new),
+            }
+        }
+
+        self.len += 1;
+        new
+    }
+
+    /// Appends `value` at the tail and returns its node, which stays at the same address
+    /// until it is unlinked.
+    #[cfg(feature = "std")]
+    pub(crate) fn push_back_node(&mut self, value: T) -> NonNull<Node<T>> {
+        self.link_before(None, value)
+    }
+
+    /// Returns the first node, or `None` if the list is empty.
+    #[cfg(feature = "std")]
+    pub(crate) fn front_node(&self) -> Option<NonNull<Node<T>>> {
+        self.head
+    }
+
+    /// Returns the last node, or `None` if the list is empty.
+    #[cfg(feature = "std")]
+    pub(crate) fn back_node(&self) -> Option<NonNull<Node<T>>> {
+        self.tail
+    }
+
+    /// Returns the node following `n`, or `None` if `n` is the last node.
+    #[cfg(feature = "std")]
+    pub(crate) fn next_node(&self, n: NonNull<Node<T>>) -> Option<NonNull<Node<T>>> {
+        unsafe { n.as_ref().next }
+    }
+
+    /// Moves node `n` right before `anchor`, or to the tail when `anchor` is `None`, without
+    /// reallocating it.
+    ///
+    /// `n` and `anchor` must be nodes of this list.
+    #[cfg(feature = "std")]
+    pub(crate) fn move_node_before(
+        &mut self,
+        n: NonNull<Node<T>>,
+        anchor: Option<NonNull<Node<T>>>,
+    ) {
+        if anchor == Some(n) {
+            return;
+        }
+
+        unsafe {
+            let (prev, next) = (n.as_ref().prev, n.as_ref().next);
+
+            if next == anchor {
+                return;
+            }
+
+            match prev {
+                Some(prv) => (*prv.as_ptr()).next = next,
+                None => self.head = next,
+            }
+
+            match next {
+                Some(nxt) => (*nxt.as_ptr()).prev = prev,
+                None => self.tail = prev,
+            }
+
+            let new_prev = match anchor {
+                Some(a) => a.as_ref().prev,
+                None => self.tail,
+            };
+
+            (*n.as_ptr()).prev = new_prev;
+            (*n.as_ptr()).next = anchor;
+
+            match new_prev {
+                Some(prv) => (*prv.as_ptr()).next = Some(n),
+                None => self.head = Some(n),
+            }
+
+            match anchor {
+                Some(a) => (*a.as_ptr()).prev = Some(n),
+                None => self.tail = Some(n),
+            }
+        }
+    }
+
+    /// Walks from both ends at once and returns the position right after the last element
+    /// for which `f` does not return `Greater`, along with the node currently at that
+    /// position (`None` when it is the end of the list).
+    fn upper_bound_by<F>(&self, mut f: F) -> (usize, Option<NonNull<Node<T>>>)
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (mut front, mut back) = (self.head, self.tail);
+        let (mut i, mut j) = (0, self.len);
+
+        unsafe {
+            while i < j {
+                let node = front.unwrap();
+                if f(&node.as_ref().value) == Ordering::Greater {
+                    return (i, Some(node));
+                }
+
+                front = node.as_ref().next;
+                i += 1;
+
+                if i == j {
+                    break;
+                }
+
+                let node = back.unwrap().as_ref();
+                if f(&node.value) != Ordering::Greater {
+                    return (j, node.next);
+                }
+
+                back = node.prev;
+                j -= 1;
+            }
+        }
+
+        (i, front)
+    }
+
+    /// Inserts a value into a list sorted by `compare`, keeping it sorted.
+    ///
+    /// The value is placed after every element comparing equal to it, so repeated inserts
+    /// are stable. The position is searched from both ends at once. Returns the index at
+    /// which the value was inserted.
+    pub fn insert_sorted_by<F>(
+        &mut self,
+        value: T,
+        mut compare: F,
+    ) -> Result<usize, CapacityError<T>>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let (position, anchor) = self.upper_bound_by(|v| compare(v, &value));
+        self.link_before(anchor, value);
+
+        Ok(position)
+    }
+
+    /// Searches a list sorted by `f` for a matching element, in the manner of
+    /// `slice::binary_search_by`.
+    ///
+    /// `f` returns the ordering of an element relative to the target. Returns `Ok(idx)` of
+    /// a matching element, or `Err(idx)` where a matching element could be inserted while
+    /// keeping the list sorted. The list is walked from both ends at once, so the cost is
+    /// bounded by the distance to the nearer end.
+    pub fn find_sorted_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (mut front, mut back) = (self.head, self.tail);
+        let (mut i, mut j) = (0, self.len);
+
+        unsafe {
+            while i < j {
+                let node = front.unwrap().as_ref();
+                match f(&node.value) {
+                    Ordering::Less => {}
+                    Ordering::Equal => return Ok(i),
+                    Ordering::Greater => return Err(i),
+                }
+
+                front = node.next;
+                i += 1;
+
+                if i == j {
+                    break;
+                }
+
+                let node = back.unwrap().as_ref();
+                match f(&node.value) {
+                    Ordering::Greater => {}
+                    Ordering::Equal => return Ok(j - 1),
+                    Ordering::Less => return Err(j),
+                }
+
+                back = node.prev;
+                j -= 1;
+            }
+        }
+
+        Err(i)
+    }
+
+    /// Merges the sorted list `other` into this sorted list, leaving `other` empty.
+    ///
+    /// Uses the same two-cursor merge as [`keep_lowest_vec_by`](crate::vec::core::keep_lowest_vec_by):
+    /// on ties the elements of `self` come first. Nodes are relinked, never reallocated.
+    ///
+    /// Each node is detached from `other` only once its position is found, and both lengths
+    /// are updated as it is spliced in: if `compare` panics, the nodes not merged yet are
+    /// still owned by `other`.
+    pub fn merge_sorted_by<F>(&mut self, other: &mut Self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut cursor = self.head;
+
+        unsafe {
+            while let Some(n) = other.head {
+                while let Some(c) = cursor {
+                    if compare(&c.as_ref().value, &n.as_ref().value) == Ordering::Greater {
+                        break;
+                    }
+
+                    cursor = c.as_ref().next;
+                }
+
+                other.head = n.as_ref().next;
+                match other.head {
+                    Some(nxt) => (*nxt.as_ptr()).prev = None,
+                    None => other.tail = None,
+                }
+                other.len -= 1;
+
+                let prev = match cursor {
+                    Some(c) => c.as_ref().prev,
+                    None => self.tail,
+                };
+
+                (*n.as_ptr()).prev = prev;
+                (*n.as_ptr()).next = cursor;
+
+                match prev {
+                    Some(prv) => (*prv.as_ptr()).next = Some(n),
+                    None => self.head = Some(n),
+                }
+
+                match cursor {
+                    Some(c) => (*c.as_ptr()).prev = Some(n),
+                    None => self.tail = Some(n),
+                }
+
+                self.len += 1;
+            }
+        }
+    }
+
+    /// Detaches node `n` from the list, deallocates it and returns its value.
+    ///
+    /// `n` must be a node of this list.
+    pub(crate) fn unlink(&mut self, n: NonNull<Node<T>>) -> T {
+        unsafe {
+            let node = Box::from_raw(n.as_ptr());
+
+            match node.prev {
+                Some(prv) => (*prv.as_ptr()).next = node.next,
+                None => self.head = node.next,
+            }
+
+            match node.next {
+                Some(nxt) => (*nxt.as_ptr()).prev = node.prev,
+                None => self.tail = node.prev,
+            }
+
+            self.len -= 1;
+            node.value
+        }
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving their order.
+    ///
+    /// Removed nodes are deallocated in a single pass.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut current = self.head;
+        while let Some(n) = current {
+            unsafe {
+                current = n.as_ref().next;
+
+                if !f(&n.as_ref().value) {
+                    self.unlink(n);
+                }
+            }
+        }
+    }
+
+    /// Returns an iterator removing and yielding every element for which `f` returns `true`.
+    ///
+    /// The predicate may mutate the elements it inspects. Elements are removed lazily: if the
+    /// iterator is dropped before being exhausted, the remaining elements are left untouched.
+    pub fn extract_if<F>(&mut self, f: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            current: self.head,
+            list: self,
+            predicate: f,
+        }
+    }
+
+    /// Removes and returns the first element for which `f` returns `true`.
+    pub fn remove_first_where<F>(&mut self, mut f: F) -> Option<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut current = self.head;
+        while let Some(n) = current {
+            unsafe {
+                if f(&n.as_ref().value) {
+                    return Some(self.unlink(n));
+                }
+                current = n.as_ref().next;
+            }
+        }
+        None
+    }
+
+    /// Searches from the tail for a node satisfying a predicate and returns its index.
+    ///
+    /// Returns the index, counted from the head, of the last node for which `f` returns `true`.
+    pub fn position_from_back<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut current = self.tail;
+        let mut idx = self.len;
+        while let Some(n) = current {
+            unsafe {
+                let node = n.as_ref();
+                idx -= 1;
+                if f(&node.value) {
+                    return Some(idx);
+                }
+                current = node.prev;
+            }
+        }
+        None
+    }
+
+    /// Returns the number of elements for which `f` returns `true`.
+    pub fn count_where<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut count = 0;
+        let mut current = self.head;
+        while let Some(n) = current {
+            unsafe {
+                let node = n.as_ref();
+                if f(&node.value) {
+                    count += 1;
+                }
+                current = node.next;
+            }
+        }
+        count
+    }
+
+    /// Searches for a node satisfying a predicate and returns a mutable reference to its value.
+    ///
+    /// Returns the first value for which `predicate` returns `true`.
+    pub fn get_value_where_mut<F>(&mut self, predicate: F) -> Option<&mut T>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut current = self.head;
+        while let Some(mut n) = current {
+            unsafe {
+                let node = n.as_mut();
+                if predicate(&node.value) {
+                    return Some(&mut node.value);
+                }
+                current = node.next;
+            }
+        }
+        None
+    }
+
+    /// Returns a reference to the first value, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|n| unsafe { &(*n.as_ptr()).value })
+    }
+
+    /// Returns a reference to the last value, or `None` if the list is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|n| unsafe { &(*n.as_ptr()).value })
+    }
+
+    /// Removes and returns the first value, or `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|n| self.unlink(n))
+    }
+
+    /// Removes and returns the last value, or `None` if the list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|n| self.unlink(n))
+    }
+
+    /// Returns a double-ended iterator over references to the values, from head to tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Gets a mutable reference to the value at the specified index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndexError` if `idx >= len()`
+    pub fn get_mut(&mut self, idx: usize) -> Result<&mut T, IndexError> {
+        let n = self.get_node_mut(idx)?;
+
+        Ok(unsafe { &mut (*n.as_ptr()).value })
+    }
+}
+
+/// State of a [`DoubleLinkedList::sort_by`] pass, putting the list back together when dropped.
+///
+/// The list is made of the merged nodes (`head` to `tail`), the `left_len` nodes left in the
+/// current left run starting at `left`, then every node reachable from `rest`. Only the links
+/// of the merged nodes are up to date, so a panicking comparator leaves the others to be
+/// relinked here.
+struct SortRuns<'a, T> {
+    list: &'a mut DoubleLinkedList<T>,
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    left: Option<NonNull<Node<T>>>,
+    left_len: usize,
+    rest: Option<NonNull<Node<T>>>,
+}
+
+impl<T> Drop for SortRuns<'_, T> {
+    fn drop(&mut self) {
+        let mut last = self.tail;
+        let mut left = self.left;
+        let mut rest = self.rest;
+
+        let mut link = |n: NonNull<Node<T>>| unsafe {
+            (*n.as_ptr()).prev = last;
+            match last {
+                Some(p) => (*p.as_ptr()).next = Some(n),
+                None => self.head = Some(n),
+            }
+            last = Some(n);
+        };
+
+        // SAFETY: every node of the list is reached exactly once through these three parts.
+        for _ in 0..self.left_len {
+            let n = left.unwrap();
+            left = unsafe { n.as_ref().next };
+            link(n);
+        }
+
+        while let Some(n) = rest {
+            rest = unsafe { n.as_ref().next };
+            link(n);
+        }
+
+        if let Some(t) = last {
+            unsafe { (*t.as_ptr()).next = None };
+        }
+
+        self.list.head = self.head;
+        self.list.tail = last;
+    }
+}
+
+/// Iterator removing the elements matching a predicate, created by
+/// [`DoubleLinkedList::extract_if`].
+pub struct ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    list: &'a mut DoubleLinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+    predicate: F,
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(mut n) = self.current {
+            unsafe {
+                self.current = n.as_ref().next;
+
+                if (self.predicate)(&mut n.as_mut().value) {
+                    return Some(self.list.unlink(n));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Iterator over the values of a [`DoubleLinkedList`], created by [`DoubleLinkedList::iter`].
+pub struct Iter<'a, T> {
+    front: Option<NonNull<Node<T>>>,
+    back: Option<NonNull<Node<T>>>,
+    remaining: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = unsafe { &*self.front?.as_ptr() };
+        self.front = node.next;
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = unsafe { &*self.back?.as_ptr() };
+        self.back = node.prev;
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// SAFETY: the iterator only hands out `&T`.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+/// Owning iterator over the values of a [`DoubleLinkedList`], from head to tail.
+pub struct IntoIter<T> {
+    list: DoubleLinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+/// Serializes the values as a sequence, from head to tail.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for DoubleLinkedList<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for DoubleLinkedList<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{SeqAccess, Visitor};
+
+        struct ListVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>> Visitor<'de> for ListVisitor<T> {
+            type Value = DoubleLinkedList<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut list: DoubleLinkedList<T> = Default::default();
+
+                while let Some(value) = seq.next_element()? {
+                    list.link_before(None, value);
+                }
+
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
 }
@@ -0,0 +1,486 @@
+//! Intrusive double-linked list over caller-owned nodes.
+//!
+//! This module provides a double-linked list whose link fields live inside the user's own
+//! struct. The list never allocates and never owns its elements: it only threads pointers
+//! through the [`Links`] member of values that the caller keeps alive and pinned.
+//!
+//! # Overview
+//!
+//! A value type embeds one [`Links`] member per list it may belong to. An [`Adapter`] tells
+//! the list which member to use, so one object can sit on several lists at once by declaring
+//! one adapter per `Links` field. The [`intrusive_adapter!`](crate::intrusive_adapter) macro
+//! generates such adapters.
+//!
+//! The list borrows its elements for its whole lifetime `'a`, and elements are passed as
+//! `Pin<&'a T>`, so their addresses stay stable while they are linked. Each list has a unique
+//! identifier recorded in the links of its elements, which allows O(1) unlinking of any node
+//! while rejecting nodes that belong to another list.
+//!
+//! # Features
+//!
+//! - **No allocation**: Nodes are provided by the caller, making it usable in `no_std`
+//! - **Multiple membership**: One value can be linked into several lists through several `Links`
+//! - **O(1) unlink**: Any linked node can be removed without traversal
+//! - **Bidirectional traversal**: Double-ended iteration from head and tail
+//!
+//! # Example
+//!
+//! ```ignore
+//! use core::pin::pin;
+//! use datastructures::DoubleLinkedList::intrusive::{IntrusiveList, Links};
+//! use datastructures::intrusive_adapter;
+//!
+//! struct Task {
+//!     id: u32,
+//!     run_queue: Links<Task>,
+//!     all_tasks: Links<Task>,
+//! }
+//!
+//! intrusive_adapter!(RunQueue = Task { run_queue });
+//! intrusive_adapter!(AllTasks = Task { all_tasks });
+//!
+//! let task = pin!(Task { id: 1, run_queue: Links::new(), all_tasks: Links::new() });
+//! let task = task.into_ref();
+//!
+//! let mut run_queue: IntrusiveList<RunQueue> = IntrusiveList::new();
+//! let mut all_tasks: IntrusiveList<AllTasks> = IntrusiveList::new();
+//!
+//! run_queue.push_back(task).unwrap();
+//! all_tasks.push_back(task).unwrap();
+//!
+//! assert!(run_queue.remove(task));
+//! assert!(all_tasks.contains(task));
+//! ```
+
+use core::cell::Cell;
+use core::error::Error;
+use core::fmt;
+use core::marker::{PhantomData, PhantomPinned};
+use core::pin::Pin;
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Source of unique list identifiers. `0` is reserved for unlinked nodes.
+static NEXT_LIST_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Errors returned by `IntrusiveList` operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntrusiveListError {
+    /// The value is already linked into a list through the same `Links` member.
+    AlreadyLinked,
+    /// The anchor value is not linked into this list.
+    NotLinked,
+}
+
+impl fmt::Display for IntrusiveListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::AlreadyLinked => "value is already linked into a list",
+            Self::NotLinked => "anchor value is not linked into this list",
+        })
+    }
+}
+
+impl Error for IntrusiveListError {}
+
+/// Link fields embedded in a value to make it a member of an [`IntrusiveList`].
+///
+/// `Links` is `!Unpin`, so values embedding it must be pinned before being linked.
+pub struct Links<T> {
+    prev: Cell<Option<NonNull<T>>>,
+    next: Cell<Option<NonNull<T>>>,
+    owner: Cell<usize>,
+    _pin: PhantomPinned,
+}
+
+impl<T> Links<T> {
+    /// Creates unlinked link fields.
+    pub const fn new() -> Self {
+        Self {
+            prev: Cell::new(None),
+            next: Cell::new(None),
+            owner: Cell::new(0),
+            _pin: PhantomPinned,
+        }
+    }
+
+    /// Returns `true` if the owning value is currently linked into a list.
+    #[inline]
+    pub fn is_linked(&self) -> bool {
+        self.owner.get() != 0
+    }
+
+    fn reset(&self) {
+        self.prev.set(None);
+        self.next.set(None);
+        self.owner.set(0);
+    }
+}
+
+impl<T> Default for Links<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Maps a value type to one of its embedded [`Links`] members.
+///
+/// # Safety
+///
+/// `links` must always return the same `Links` member embedded in `value`, and that member
+/// must not be shared with another value or another adapter.
+pub unsafe trait Adapter {
+    /// The type of values linked through this adapter.
+    type Value;
+
+    /// Returns the `Links` member used by this adapter.
+    fn links(value: &Self::Value) -> &Links<Self::Value>;
+}
+
+/// Declares a unit struct implementing [`Adapter`] for a `Links` field of a struct.
+///
+/// ```ignore
+/// intrusive_adapter!(pub ByDeadline = Timer { deadline_links });
+/// ```
+#[macro_export]
+macro_rules! intrusive_adapter {
+    ($vis:vis $name:ident = $value:ty { $field:ident }) => {
+        $vis struct $name;
+
+        unsafe impl $crate::double_linked_list::intrusive::Adapter for $name {
+            type Value = $value;
+
+            #[inline]
+            fn links(value: &$value) -> &$crate::double_linked_list::intrusive::Links<$value> {
+                &value.$field
+            }
+        }
+    };
+}
+
+/// An intrusive double-linked list over caller-owned values.
+///
+/// # Type Parameters
+///
+/// - `'a`: Lifetime for which every linked value is borrowed
+/// - `A`: Adapter selecting the `Links` member of the values
+///
+/// # Fields
+///
+/// - `head`: Pointer to the first value (if non-empty)
+/// - `tail`: Pointer to the last value (if non-empty)
+/// - `len`: Current number of linked values
+/// - `id`: Unique identifier recorded in the links of every member
+pub struct IntrusiveList<'a, A: Adapter> {
+    head: Option<NonNull<A::Value>>,
+    tail: Option<NonNull<A::Value>>,
+    len: usize,
+    id: usize,
+    _marker: PhantomData<Pin<&'a A::Value>>,
+}
+
+impl<'a, A: Adapter> Default for IntrusiveList<'a, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, A: Adapter> Drop for IntrusiveList<'a, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<'a, A: Adapter> IntrusiveList<'a, A> {
+    /// Creates an empty list with a fresh identifier.
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            id: NEXT_LIST_ID.fetch_add(1, Ordering::Relaxed),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of linked values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the links of the value behind `ptr`.
+    #[inline]
+    fn links_of(ptr: NonNull<A::Value>) -> &'a Links<A::Value> {
+        // SAFETY: every pointer stored in the list comes from a `Pin<&'a A::Value>`.
+        A::links(unsafe { ptr.as_ref() })
+    }
+
+    /// Returns `true` if `value` is linked into this list.
+    pub fn contains(&self, value: Pin<&'a A::Value>) -> bool {
+        A::links(value.get_ref()).owner.get() == self.id
+    }
+
+    /// Returns the first value of the list.
+    pub fn front(&self) -> Option<Pin<&'a A::Value>> {
+        self.head
+            .map(|ptr| unsafe { Pin::new_unchecked(&*ptr.as_ptr()) })
+    }
+
+    /// Returns the last value of the list.
+    pub fn back(&self) -> Option<Pin<&'a A::Value>> {
+        self.tail
+            .map(|ptr| unsafe { Pin::new_unchecked(&*ptr.as_ptr()) })
+    }
+
+    /// Claims `value` for this list, failing if it is already linked somewhere.
+    fn claim(&self, value: Pin<&'a A::Value>) -> Result<NonNull<A::Value>, IntrusiveListError> {
+        let links = A::links(value.get_ref());
+
+        if links.is_linked() {
+            return Err(IntrusiveListError::AlreadyLinked);
+        }
+
+        links.owner.set(self.id);
+
+        Ok(NonNull::from(value.get_ref()))
+    }
+
+    /// Links `value` at the end of the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IntrusiveListError::AlreadyLinked` if `value` already belongs to a list
+    /// through the same `Links` member.
+    pub fn push_back(&mut self, value: Pin<&'a A::Value>) -> Result<(), IntrusiveListError> {
+        let new = self.claim(value)?;
+        let links = Self::links_of(new);
+
+        links.prev.set(self.tail);
+        links.next.set(None);
+
+        match self.tail {
+            Some(tail) => Self::links_of(tail).next.set(Some(new)),
+            None => self.head = Some(new),
+        }
+
+        self.tail = Some(new);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Links `value` at the beginning of the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IntrusiveListError::AlreadyLinked` if `value` already belongs to a list
+    /// through the same `Links` member.
+    pub fn push_front(&mut self, value: Pin<&'a A::Value>) -> Result<(), IntrusiveListError> {
+        let new = self.claim(value)?;
+        let links = Self::links_of(new);
+
+        links.prev.set(None);
+        links.next.set(self.head);
+
+        match self.head {
+            Some(head) => Self::links_of(head).prev.set(Some(new)),
+            None => self.tail = Some(new),
+        }
+
+        self.head = Some(new);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Links `value` right after `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `IntrusiveListError::NotLinked` if `anchor` is not a member of this list
+    /// - Returns `IntrusiveListError::AlreadyLinked` if `value` already belongs to a list
+    pub fn insert_after(
+        &mut self,
+        anchor: Pin<&'a A::Value>,
+        value: Pin<&'a A::Value>,
+    ) -> Result<(), IntrusiveListError> {
+        if !self.contains(anchor) {
+            return Err(IntrusiveListError::NotLinked);
+        }
+
+        let new = self.claim(value)?;
+        let after = NonNull::from(anchor.get_ref());
+        let after_next = Self::links_of(after).next.get();
+
+        let links = Self::links_of(new);
+        links.prev.set(Some(after));
+        links.next.set(after_next);
+
+        match after_next {
+            Some(next) => Self::links_of(next).prev.set(Some(new)),
+            None => self.tail = Some(new),
+        }
+
+        Self::links_of(after).next.set(Some(new));
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Links `value` right before `anchor`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `IntrusiveListError::NotLinked` if `anchor` is not a member of this list
+    /// - Returns `IntrusiveListError::AlreadyLinked` if `value` already belongs to a list
+    pub fn insert_before(
+        &mut self,
+        anchor: Pin<&'a A::Value>,
+        value: Pin<&'a A::Value>,
+    ) -> Result<(), IntrusiveListError> {
+        if !self.contains(anchor) {
+            return Err(IntrusiveListError::NotLinked);
+        }
+
+        let new = self.claim(value)?;
+        let before = NonNull::from(anchor.get_ref());
+        let before_prev = Self::links_of(before).prev.get();
+
+        let links = Self::links_of(new);
+        links.prev.set(before_prev);
+        links.next.set(Some(before));
+
+        match before_prev {
+            Some(prev) => Self::links_of(prev).next.set(Some(new)),
+            None => self.head = Some(new),
+        }
+
+        Self::links_of(before).prev.set(Some(new));
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Unlinks the value behind `ptr`, which must be a member of this list.
+    fn unlink(&mut self, ptr: NonNull<A::Value>) {
+        let links = Self::links_of(ptr);
+        let prev = links.prev.get();
+        let next = links.next.get();
+
+        match prev {
+            Some(p) => Self::links_of(p).next.set(next),
+            None => self.head = next,
+        }
+
+        match next {
+            Some(n) => Self::links_of(n).prev.set(prev),
+            None => self.tail = prev,
+        }
+
+        links.reset();
+        self.len -= 1;
+    }
+
+    /// Unlinks `value` from the list in O(1).
+    ///
+    /// Returns `false` if `value` is not a member of this list.
+    pub fn remove(&mut self, value: Pin<&'a A::Value>) -> bool {
+        if !self.contains(value) {
+            return false;
+        }
+
+        self.unlink(NonNull::from(value.get_ref()));
+
+        true
+    }
+
+    /// Unlinks and returns the first value of the list.
+    pub fn pop_front(&mut self) -> Option<Pin<&'a A::Value>> {
+        let head = self.front()?;
+        self.unlink(NonNull::from(head.get_ref()));
+
+        Some(head)
+    }
+
+    /// Unlinks and returns the last value of the list.
+    pub fn pop_back(&mut self) -> Option<Pin<&'a A::Value>> {
+        let tail = self.back()?;
+        self.unlink(NonNull::from(tail.get_ref()));
+
+        Some(tail)
+    }
+
+    /// Unlinks every value, leaving them free to join another list.
+    pub fn clear(&mut self) {
+        let mut current = self.head;
+
+        while let Some(ptr) = current {
+            let links = Self::links_of(ptr);
+            current = links.next.get();
+            links.reset();
+        }
+
+        self.head = None;
+        self.tail = None;
+        self.len = 0;
+    }
+
+    /// Returns a double-ended iterator over the linked values, from head to tail.
+    pub fn iter(&self) -> Iter<'a, A> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the values of an [`IntrusiveList`], created by [`IntrusiveList::iter`].
+pub struct Iter<'a, A: Adapter> {
+    head: Option<NonNull<A::Value>>,
+    tail: Option<NonNull<A::Value>>,
+    remaining: usize,
+    _marker: PhantomData<Pin<&'a A::Value>>,
+}
+
+impl<'a, A: Adapter> Iterator for Iter<'a, A> {
+    type Item = Pin<&'a A::Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let ptr = self.head?;
+        self.head = IntrusiveList::<'a, A>::links_of(ptr).next.get();
+        self.remaining -= 1;
+
+        Some(unsafe { Pin::new_unchecked(&*ptr.as_ptr()) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, A: Adapter> DoubleEndedIterator for Iter<'a, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let ptr = self.tail?;
+        self.tail = IntrusiveList::<'a, A>::links_of(ptr).prev.get();
+        self.remaining -= 1;
+
+        Some(unsafe { Pin::new_unchecked(&*ptr.as_ptr()) })
+    }
+}
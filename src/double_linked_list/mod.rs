@@ -2,17 +2,26 @@
 //!
 //! Provides both fixed-size and dynamic double-linked list implementations.
 //! - `sized`: Fixed-size list with compile-time capacity constraints (stack allocation)
-//! - `dynamic`: Dynamic list with heap allocation for unlimited capacity (std only)
+//! - `dynamic`: Dynamic list with heap allocation for unlimited capacity (std or alloc)
+//! - `intrusive`: Intrusive list threading links through caller-owned, pinned values
+//! - `ordered`: Fixed-size list kept sorted by a stored comparator
+//! - `traits`: `LinkedList`, `Deque` and `Queue` traits implemented by the sized and dynamic lists
 //!
 //! Use `SizedDoubleLinkedList` when the capacity is known and ≤ 63 for better performance.
 //! Use `DoubleLinkedList` when the capacity is unknown or may exceed 63 elements.
 
+pub mod intrusive;
+pub mod ordered;
 pub mod sized;
+pub mod traits;
 
-#[cfg(not(feature = "no-std"))]
+#[cfg(feature = "alloc")]
 pub mod dynamic;
 
+pub use intrusive::IntrusiveList;
+pub use ordered::OrderedSizedList;
 pub use sized::SizedDoubleLinkedList;
+pub use traits::{Deque, LinkedList, Queue};
 
-#[cfg(not(feature = "no-std"))]
+#[cfg(feature = "alloc")]
 pub use dynamic::DoubleLinkedList;
@@ -0,0 +1,174 @@
+//! Fixed-size list kept sorted by a stored comparator.
+//!
+//! This module provides [`OrderedSizedList`], a thin wrapper around
+//! [`SizedDoubleLinkedList`] that only exposes operations preserving the sort order.
+//! It is meant for lists ordered by deadline or priority that used to be re-sorted after
+//! every batch of insertions.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::DoubleLinkedList::OrderedSizedList;
+//!
+//! let mut timers = OrderedSizedList::<u64, 8, _>::new(|a: &u64, b: &u64| a.cmp(b));
+//! timers.insert(30).unwrap();
+//! timers.insert(10).unwrap();
+//!
+//! assert_eq!(timers.first(), Some(&10));
+//! ```
+
+use super::sized::{SizedDoubleLinkedList, ValidK};
+use crate::{CapacityError, Const, IndexError};
+
+use core::cmp::Ordering;
+
+/// A fixed-size double-linked list maintained in the order defined by `C`.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of values stored in the list
+/// - `K`: Compile-time capacity (0-63), enforced via the `ValidK` trait
+/// - `C`: Comparator defining the order of the list
+///
+/// # Fields
+///
+/// - `list`: The underlying sorted list
+/// - `compare`: Comparator used for every insertion and lookup
+pub struct OrderedSizedList<T, const K: usize, C>
+where
+    Const<K>: ValidK,
+    C: Fn(&T, &T) -> Ordering,
+{
+    list: SizedDoubleLinkedList<T, K>,
+    compare: C,
+}
+
+impl<T, const K: usize, C> OrderedSizedList<T, K, C>
+where
+    Const<K>: ValidK,
+    C: Fn(&T, &T) -> Ordering,
+{
+    /// Creates an empty list ordered by `compare`.
+    ///
+    /// With a function pointer as comparator, the list can be placed in a `static` item.
+    pub const fn new(compare: C) -> Self {
+        Self {
+            list: SizedDoubleLinkedList::new(),
+            compare,
+        }
+    }
+
+    /// Builds an ordered list from an existing list by sorting it with `compare`.
+    pub fn from_list(mut list: SizedDoubleLinkedList<T, K>, compare: C) -> Self {
+        list.sort_by(&compare);
+
+        Self { list, compare }
+    }
+
+    /// Returns the number of elements currently in the list.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the list contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns `true` if the list has reached its maximum capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.list.is_full()
+    }
+
+    /// Inserts a value at its sorted position and returns that position.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the list is at capacity.
+    pub fn insert(&mut self, value: T) -> Result<usize, CapacityError<T>> {
+        self.list.insert_sorted_by(value, &self.compare)
+    }
+
+    /// Searches for an element comparing equal to `value`.
+    ///
+    /// Returns `Ok(index)` of a matching element, or `Err(index)` where `value` would be
+    /// inserted.
+    pub fn find(&self, value: &T) -> Result<usize, usize> {
+        self.list.find_sorted_by(|v| (self.compare)(v, value))
+    }
+
+    /// Returns `true` if an element compares equal to `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(value).is_ok()
+    }
+
+    /// Returns a reference to the value at the specified index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndexError` if `index >= len()`
+    pub fn get(&self, index: usize) -> Result<&T, IndexError> {
+        self.list.get(index)
+    }
+
+    /// Returns the smallest element.
+    pub fn first(&self) -> Option<&T> {
+        self.list.get(0).ok()
+    }
+
+    /// Returns the largest element.
+    pub fn last(&self) -> Option<&T> {
+        self.list.get(self.len().checked_sub(1)?).ok()
+    }
+
+    /// Removes the node at the specified index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndexError` if `index >= len()`
+    pub fn remove(&mut self, index: usize) -> Result<(), IndexError> {
+        self.list.remove(index)
+    }
+
+    /// Merges another ordered list into this one, leaving `other` empty.
+    ///
+    /// The elements of `other` are inserted one by one with the comparator of `self`, so
+    /// the result stays sorted whatever the order of `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` if the combined length exceeds `K`.
+    /// Both lists are left untouched in that case.
+    pub fn merge<D>(
+        &mut self,
+        other: &mut OrderedSizedList<T, K, D>,
+    ) -> Result<(), CapacityError<()>>
+    where
+        D: Fn(&T, &T) -> Ordering,
+    {
+        if self.len() + other.len() > K {
+            return Err(CapacityError::new(()));
+        }
+
+        while let Some(value) = other.list.pop_front() {
+            // Capacity was checked above, so no insertion can fail.
+            let _ = self.insert(value);
+        }
+
+        Ok(())
+    }
+
+    /// Returns a shared reference to the underlying sorted list.
+    #[inline]
+    pub fn as_list(&self) -> &SizedDoubleLinkedList<T, K> {
+        &self.list
+    }
+
+    /// Consumes the wrapper and returns the underlying sorted list.
+    pub fn into_inner(self) -> SizedDoubleLinkedList<T, K> {
+        self.list
+    }
+}
@@ -34,18 +34,15 @@
 //! ```
 
 use crate::array::core::swap_maybeuninit_to_option_array;
-use crate::{Const, LinkedListError};
+use crate::codec::binary::{self, CodecError, Decode, Encode, SnapshotKind};
+use crate::{CapacityError, Const, IndexError, LinkedListError};
 
 use core::cmp::{Ordering, min};
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::mem::MaybeUninit;
-
-#[cfg(feature = "no-std")]
 use core::mem::swap;
-
-#[cfg(not(feature = "no-std"))]
-extern crate std;
-#[cfg(not(feature = "no-std"))]
-use std::vec::Vec;
+use core::ops::{Index, IndexMut};
 
 /// Trait for validating capacity constants at compile time.
 /// Valid capacities range from 0 to 63.
@@ -134,20 +131,188 @@ where
     Const<K>: ValidK,
 {
     fn default() -> Self {
-        Self {
-            nodes: unsafe { MaybeUninit::<[MaybeUninit<Node<T>>; K]>::uninit().assume_init() },
-            used: 0,
-            len: 0,
-            tail: None,
-            head: None,
+        Self::new()
+    }
+}
+
+impl<T: PartialEq, const K: usize> PartialEq for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const K: usize> Eq for SizedDoubleLinkedList<T, K> where Const<K>: ValidK {}
+
+/// Compares lists lexicographically in element order.
+impl<T: PartialOrd, const K: usize> PartialOrd for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, const K: usize> Ord for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+/// Hashes the length followed by every value in order, so equal lists hash equally
+/// regardless of the slots their nodes occupy.
+impl<T: Hash, const K: usize> Hash for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for value in self.iter() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug, const K: usize> fmt::Debug for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const K: usize> Index<usize> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Ok(value) => value,
+            Err(_) => panic!("index {index} out of range for list of length {}", self.len),
         }
     }
 }
 
+impl<T, const K: usize> IndexMut<usize> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+
+        match self.get_mut(index) {
+            Ok(value) => value,
+            Err(_) => panic!("index {index} out of range for list of length {len}"),
+        }
+    }
+}
+
+/// Appends every value of the iterator at the tail.
+///
+/// # Panics
+///
+/// Panics if the list runs out of capacity. Use `insert_tail` to handle a full list.
+impl<T, const K: usize> Extend<T> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            if self.insert_tail(value).is_err() {
+                panic!("extending beyond the capacity of {K} elements");
+            }
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, const K: usize> Extend<&'a T> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+/// Collects values into a list.
+///
+/// # Panics
+///
+/// Panics if the iterator yields more than `K` values.
+impl<T, const K: usize> FromIterator<T> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list: Self = Default::default();
+        list.extend(iter);
+        list
+    }
+}
+
+/// Builds a list from an array; arrays longer than `K` are rejected at compile time.
+impl<T, const K: usize, const N: usize> From<[T; N]> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn from(values: [T; N]) -> Self {
+        const { assert!(N <= K, "array does not fit in the list") };
+
+        values.into_iter().collect()
+    }
+}
+
+impl<'a, T, const K: usize> IntoIterator for &'a SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, K>;
+
+    fn into_iter(self) -> Iter<'a, T, K> {
+        self.iter()
+    }
+}
+
+impl<T, const K: usize> IntoIterator for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T, K>;
+
+    fn into_iter(self) -> IntoIter<T, K> {
+        IntoIter { list: self }
+    }
+}
+
 impl<T: Sized, const K: usize> SizedDoubleLinkedList<T, K>
 where
     Const<K>: ValidK,
 {
+    /// Creates an empty list with capacity `K`.
+    ///
+    /// Being a `const fn`, the list can be placed in a `static` item without runtime
+    /// initialization.
+    pub const fn new() -> Self {
+        Self {
+            nodes: [const { MaybeUninit::uninit() }; K],
+            used: 0,
+            len: 0,
+            tail: None,
+            head: None,
+        }
+    }
+
     /// Returns the number of elements currently in the list.
     #[inline]
     pub fn len(&self) -> usize {
@@ -196,22 +361,30 @@ where
     ///
     /// # Errors
     ///
-    /// Returns `LinkedListError::ListIsFull` if the list is at capacity.
-    pub fn insert_tail(&mut self, value: T) -> Result<(), LinkedListError> {
-        if self.len == 0 {
-            return self.insert_before(0, value);
+    /// Returns a `CapacityError` holding `value` if the list is at capacity.
+    pub fn insert_tail(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(value));
         }
 
-        self.insert_after(self.len - 1, value)
+        self.link_before(None, value);
+
+        Ok(())
     }
 
     /// Inserts a value at the beginning of the list.
     ///
     /// # Errors
     ///
-    /// Returns `LinkedListError::ListIsFull` if the list is at capacity.
-    pub fn insert_head(&mut self, value: T) -> Result<(), LinkedListError> {
-        self.insert_before(0, value)
+    /// Returns a `CapacityError` holding `value` if the list is at capacity.
+    pub fn insert_head(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(value));
+        }
+
+        self.link_before(self.head, value);
+
+        Ok(())
     }
 
     /// Inserts a value after the node at the specified index.
@@ -219,19 +392,19 @@ where
     /// # Errors
     ///
     /// - Returns `LinkedListError::IndexOutOfRange` if `index >= len()`
-    /// - Returns `LinkedListError::ListIsFull` if the list is at capacity
+    /// - Returns `LinkedListError::ListIsFull` holding `value` if the list is at capacity
     ///
     /// # Note
     ///
     /// For optimal performance, the function starts traversal from the closer end (head or tail)
     /// by comparing the index position with the midpoint of the list.
-    pub fn insert_after(&mut self, index: usize, value: T) -> Result<(), LinkedListError> {
+    pub fn insert_after(&mut self, index: usize, value: T) -> Result<(), LinkedListError<T>> {
         if index >= self.len {
-            return Err(LinkedListError::IndexOutOfRange);
+            return Err(IndexError::new(index, self.len).into());
         }
 
         if self.is_full() {
-            return Err(LinkedListError::ListIsFull);
+            return Err(CapacityError::new(value).into());
         }
 
         let (mut current, steps, forward) = if index < self.len / 2 {
@@ -285,20 +458,21 @@ where
     ///
     /// # Errors
     ///
-    /// - Returns `LinkedListError::IndexOutOfRange` if `index > len()`
-    /// - Returns `LinkedListError::ListIsFull` if the list is at capacity
+    /// - Returns `LinkedListError::IndexOutOfRange` if `index >= len()`, unless the list is
+    ///   empty and `index` is `0`
+    /// - Returns `LinkedListError::ListIsFull` holding `value` if the list is at capacity
     ///
     /// # Note
     ///
     /// For optimal performance, the function starts traversal from the closer end (head or tail)
     /// by comparing the index position with the midpoint of the list.
-    pub fn insert_before(&mut self, index: usize, value: T) -> Result<(), LinkedListError> {
-        if index > self.len {
-            return Err(LinkedListError::IndexOutOfRange);
+    pub fn insert_before(&mut self, index: usize, value: T) -> Result<(), LinkedListError<T>> {
+        if index >= self.len && !(index == 0 && self.len == 0) {
+            return Err(IndexError::new(index, self.len).into());
         }
 
         if self.is_full() {
-            return Err(LinkedListError::ListIsFull);
+            return Err(CapacityError::new(value).into());
         }
 
         if index == 0 {
@@ -389,14 +563,14 @@ where
     ///
     /// # Errors
     ///
-    /// Returns `LinkedListError::IndexOutOfRange` if `index >= len()`
+    /// Returns an `IndexError` if `index >= len()`
     ///
     /// # Note
     ///
     /// Performs traversal from the closer end for efficiency.
-    pub fn get(&self, index: usize) -> Result<&T, LinkedListError> {
+    pub fn get(&self, index: usize) -> Result<&T, IndexError> {
         if index >= self.len {
-            return Err(LinkedListError::IndexOutOfRange);
+            return Err(IndexError::new(index, self.len));
         }
 
         let (mut current, steps, forward) = if index < self.len / 2 {
@@ -424,15 +598,15 @@ where
     ///
     /// # Errors
     ///
-    /// Returns `LinkedListError::IndexOutOfRange` if `index >= len()`
+    /// Returns an `IndexError` if `index >= len()`
     ///
     /// # Note
     ///
     /// Performs traversal from the closer end for efficiency. Special-case handling for
     /// removing the head, tail, or only node in the list.
-    pub fn remove(&mut self, index: usize) -> Result<(), LinkedListError> {
+    pub fn remove(&mut self, index: usize) -> Result<(), IndexError> {
         if index >= self.len {
-            return Err(LinkedListError::IndexOutOfRange);
+            return Err(IndexError::new(index, self.len));
         }
 
         if self.len == 1 {
@@ -654,12 +828,12 @@ where
     /// convention as `std::cmp::Ord::cmp`. The sort is **stable**, preserving the
     /// relative order of elements that compare equal.
     ///
-    /// This version uses stack-allocated buffers for `no-std` compatibility.
+    /// Node indices are merged in two stack-allocated buffers of `K` entries, so sorting never
+    /// allocates and values never move between slots.
     ///
     /// # Arguments
     ///
     /// * `compare` - Comparator function defining the ordering between two values
-    #[cfg(feature = "no-std")]
     pub fn sort_by(&mut self, mut compare: impl FnMut(&T, &T) -> Ordering) {
         if self.len <= 1 {
             return;
@@ -767,9 +941,8 @@ where
     /// Returns a sorted clone of the list using the provided comparator.
     ///
     /// The original list remains unchanged; the returned list is sorted with the
-    /// same stable merge sort logic as [`sort_by`]. Requires `T: Clone` to
+    /// same stable merge sort logic as [`sort_by`](Self::sort_by). Requires `T: Clone` to
     /// duplicate elements into the new list without heap allocation.
-    #[cfg(feature = "no-std")]
     pub fn get_sorted_by(&self, compare: impl FnMut(&T, &T) -> Ordering) -> Self
     where
         T: Clone,
@@ -829,18 +1002,17 @@ where
     /// to partition the first `N` minimal elements (by `compare`) to the front.
     /// Returns an `Option` array where the first `min(N, self.len())` entries contain `Some(value)`,
     /// and remaining entries are `None`. Elements are sorted by the provided comparator.
-    #[cfg(feature = "no-std")]
     pub fn select_n_first_by<const N: usize>(
         &self,
         mut compare: impl FnMut(&T, &T) -> Ordering,
     ) -> [Option<T>; N]
     where
-        T: Copy,
+        T: Clone,
     {
-        let mut out: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut out: [Option<T>; N] = [const { None }; N];
 
         if self.len == 0 || N == 0 {
-            return swap_maybeuninit_to_option_array(out, 0);
+            return out;
         }
 
         // Gather indices in list order.
@@ -947,142 +1119,723 @@ where
         for (dst, &idx) in out.iter_mut().take(target).zip(indices.iter().take(target)) {
             let n = unsafe { &*self.nodes[idx].as_ptr() };
 
-            dst.write(n.value);
+            *dst = Some(n.value.clone());
         }
 
-        swap_maybeuninit_to_option_array(out, target)
+        out
     }
 
-    /// Sorts the list in-place using standard library's sort (faster than no_std version).
+    /// Returns the slot holding the node at the specified index.
     ///
-    /// Sorts the list in-place using the provided comparator.
+    /// Traverses from the closer end. `index` must be lower than `len()`.
+    fn slot_at(&self, index: usize) -> usize {
+        let (mut current, steps, forward) = if index < self.len / 2 {
+            (self.head.unwrap(), index, true)
+        } else {
+            (self.tail.unwrap(), self.len - 1 - index, false)
+        };
+
+        for _ in 0..steps {
+            let node = unsafe { self.nodes[current].assume_init_ref() };
+
+            current = if forward {
+                node.next.unwrap()
+            } else {
+                node.prev.unwrap()
+            };
+        }
+
+        current
+    }
+
+    /// Stores `value` in a free slot and links it right before the `anchor` slot,
+    /// or at the tail when `anchor` is `None`. The list must not be full.
+    fn link_before(&mut self, anchor: Option<usize>, value: T) {
+        let new = self.first_free();
+
+        let prev = match anchor {
+            Some(a) => unsafe { self.nodes[a].assume_init_ref() }.prev,
+            None => self.tail,
+        };
+
+        match prev {
+            Some(p) => unsafe { self.nodes[p].assume_init_mut() }.next = Some(new),
+            None => self.head = Some(new),
+        }
+
+        match anchor {
+            Some(a) => unsafe { self.nodes[a].assume_init_mut() }.prev = Some(new),
+            None => self.tail = Some(new),
+        }
+
+        self.add_used(new);
+        self.nodes[new] = MaybeUninit::new(Node {
+            value,
+            index: new,
+            prev,
+            next: anchor,
+        });
+        self.len += 1;
+    }
+
+    /// Moves every value out of `other`, in order, right before the `anchor` slot
+    /// (or at the tail when `anchor` is `None`), leaving `other` empty.
+    fn relocate_from(&mut self, anchor: Option<usize>, other: &mut Self) {
+        let mut current = other.head;
+
+        while let Some(slot) = current {
+            let node = unsafe { other.nodes[slot].assume_init_read() };
+            current = node.next;
+
+            self.link_before(anchor, node.value);
+        }
+
+        other.used = 0;
+        other.len = 0;
+        other.head = None;
+        other.tail = None;
+    }
+
+    /// Moves all elements of `other` to the end of the list, leaving `other` empty.
     ///
-    /// The comparator should return an [`Ordering`] for two values, following the same
-    /// convention as `std::cmp::Ord::cmp`. The sort is **stable**, preserving the
-    /// relative order of elements that compare equal.
+    /// The nodes of `other` are relocated into free slots of `self`, preserving their order.
     ///
-    /// This version uses `Vec` and standard library sorting for better performance
-    /// when `no-std` feature is not enabled.
+    /// # Errors
     ///
-    /// # Arguments
+    /// Returns a `CapacityError` if `len() + other.len()` exceeds `K`.
+    /// Both lists are left untouched in that case.
+    pub fn try_append(&mut self, other: &mut Self) -> Result<(), CapacityError<()>> {
+        if self.len + other.len > K {
+            return Err(CapacityError::new(()));
+        }
+
+        self.relocate_from(None, other);
+
+        Ok(())
+    }
+
+    /// Moves all elements of `other` into the list, before the node at the specified index,
+    /// leaving `other` empty.
     ///
-    /// * `compare` - Comparator function defining the ordering between two values
-    #[cfg(not(feature = "no-std"))]
-    pub fn sort_by(&mut self, mut compare: impl FnMut(&T, &T) -> Ordering) {
-        if self.len <= 1 {
-            return;
+    /// Passing `index == len()` appends the elements at the end.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `LinkedListError::IndexOutOfRange` if `index > len()`
+    /// - Returns `LinkedListError::ListIsFull` if `len() + other.len()` exceeds `K`; both lists
+    ///   are left untouched in that case
+    pub fn splice_at(&mut self, index: usize, other: &mut Self) -> Result<(), LinkedListError> {
+        if index > self.len {
+            return Err(IndexError::new(index, self.len).into());
         }
 
-        let mut indices = Vec::with_capacity(self.len);
-        let mut current = self.head.unwrap();
+        if self.len + other.len > K {
+            return Err(CapacityError::new(()).into());
+        }
 
-        loop {
-            indices.push(current);
-            let node = unsafe { &*self.nodes[current].as_ptr() };
-            match node.next {
-                Some(next) => current = next,
-                None => break,
-            }
+        let anchor = if index == self.len {
+            None
+        } else {
+            Some(self.slot_at(index))
+        };
+
+        self.relocate_from(anchor, other);
+
+        Ok(())
+    }
+
+    /// Splits the list in two at the given index.
+    ///
+    /// Returns a new list containing the elements `[index, len)`, while `self` keeps
+    /// the elements `[0, index)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndexError` if `index > len()`
+    pub fn split_off(&mut self, index: usize) -> Result<Self, IndexError> {
+        if index > self.len {
+            return Err(IndexError::new(index, self.len));
         }
 
-        indices.sort_unstable_by(|&a, &b| {
-            let va = unsafe { &*self.nodes[a].as_ptr() };
-            let vb = unsafe { &*self.nodes[b].as_ptr() };
-            compare(&va.value, &vb.value)
-        });
+        let mut new_list: Self = Default::default();
 
-        self.head = Some(indices[0]);
-        self.tail = Some(*indices.last().unwrap());
+        if index == self.len {
+            return Ok(new_list);
+        }
 
-        for (pos, &idx) in indices.iter().enumerate() {
-            let prev = if pos == 0 {
-                None
-            } else {
-                Some(indices[pos - 1])
-            };
-            let next = if pos + 1 == self.len {
-                None
-            } else {
-                Some(indices[pos + 1])
-            };
-            let n = unsafe { self.nodes[idx].assume_init_mut() };
-            n.prev = prev;
-            n.next = next;
+        let first = self.slot_at(index);
+        let last_kept = unsafe { self.nodes[first].assume_init_ref() }.prev;
+
+        let mut current = Some(first);
+        while let Some(slot) = current {
+            let node = unsafe { self.nodes[slot].assume_init_read() };
+            current = node.next;
+
+            self.remove_used(slot);
+            new_list.link_before(None, node.value);
+        }
+
+        match last_kept {
+            Some(p) => unsafe { self.nodes[p].assume_init_mut() }.next = None,
+            None => self.head = None,
         }
+
+        self.tail = last_kept;
+        self.len = index;
+
+        Ok(new_list)
     }
 
-    /// Returns a sorted clone using standard library (faster than no_std version).
+    /// Walks from both ends at once and returns the position right after the last element
+    /// for which `f` does not return `Greater`, along with the slot currently at that
+    /// position (`None` when it is the end of the list).
     ///
-    /// This version is available only when the `no-std` feature is **not** enabled.
-    /// Uses `sort_by` internally for optimal performance with heap allocation.
+    /// Stops after O(min(pos, len - pos)) steps from each end on a sorted list.
+    fn upper_bound_by(&self, mut f: impl FnMut(&T) -> Ordering) -> (usize, Option<usize>) {
+        let (mut front, mut back) = (self.head, self.tail);
+        let (mut i, mut j) = (0, self.len);
+
+        while i < j {
+            let slot = front.unwrap();
+            let node = unsafe { self.nodes[slot].assume_init_ref() };
+            if f(&node.value) == Ordering::Greater {
+                return (i, Some(slot));
+            }
+
+            front = node.next;
+            i += 1;
+
+            if i == j {
+                break;
+            }
+
+            let node = unsafe { self.nodes[back.unwrap()].assume_init_ref() };
+            if f(&node.value) != Ordering::Greater {
+                return (j, node.next);
+            }
+
+            back = node.prev;
+            j -= 1;
+        }
+
+        (i, front)
+    }
+
+    /// Inserts a value into a list sorted by `compare`, keeping it sorted.
     ///
-    /// # Arguments
+    /// The value is placed after every element comparing equal to it, so repeated inserts
+    /// are stable. The position is searched from both ends at once. Returns the index at
+    /// which the value was inserted.
     ///
-    /// * `compare` - Comparator function defining the ordering between two values
-    #[cfg(not(feature = "no-std"))]
-    pub fn get_sorted_by(&self, compare: impl FnMut(&T, &T) -> Ordering) -> Self
-    where
-        T: Clone,
-    {
-        let mut cloned = self.clone();
-        cloned.sort_by(compare);
-        cloned
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the list is at capacity.
+    pub fn insert_sorted_by(
+        &mut self,
+        value: T,
+        mut compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> Result<usize, CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(value));
+        }
+
+        let (position, anchor) = self.upper_bound_by(|v| compare(v, &value));
+        self.link_before(anchor, value);
+
+        Ok(position)
     }
 
-    /// Selects and returns up to `N` smallest values using Vec (faster than no_std version).
+    /// Searches a list sorted by `f` for a matching element, in the manner of
+    /// `slice::binary_search_by`.
+    ///
+    /// `f` returns the ordering of an element relative to the target. Returns `Ok(index)` of
+    /// a matching element, or `Err(index)` where a matching element could be inserted while
+    /// keeping the list sorted. The list is walked from both ends at once, so the cost is
+    /// bounded by the distance to the nearer end.
+    pub fn find_sorted_by(&self, mut f: impl FnMut(&T) -> Ordering) -> Result<usize, usize> {
+        let (mut front, mut back) = (self.head, self.tail);
+        let (mut i, mut j) = (0, self.len);
+
+        while i < j {
+            let node = unsafe { self.nodes[front.unwrap()].assume_init_ref() };
+            match f(&node.value) {
+                Ordering::Less => {}
+                Ordering::Equal => return Ok(i),
+                Ordering::Greater => return Err(i),
+            }
+
+            front = node.next;
+            i += 1;
+
+            if i == j {
+                break;
+            }
+
+            let node = unsafe { self.nodes[back.unwrap()].assume_init_ref() };
+            match f(&node.value) {
+                Ordering::Greater => {}
+                Ordering::Equal => return Ok(j - 1),
+                Ordering::Less => return Err(j),
+            }
+
+            back = node.prev;
+            j -= 1;
+        }
+
+        Err(i)
+    }
+
+    /// Merges the sorted list `other` into this sorted list, leaving `other` empty.
     ///
-    /// This version is available only when the `no-std` feature is **not** enabled.
-    /// Collects indices into a `Vec`, uses `select_nth_unstable_by` for optimal performance,
-    /// then sorts the selected values before returning.
+    /// Uses the same two-cursor merge as
+    /// [`keep_lowest_array_by`](crate::array::core::keep_lowest_array_by): on ties the
+    /// elements of `self` come first. Nodes of `other` are relocated into free slots.
     ///
-    /// # Arguments
+    /// Each node is moved out of `other` only once its place is found, so if `compare` panics
+    /// both lists stay valid and every element is kept by one of them.
     ///
-    /// * `compare` - Comparator function defining the ordering between two values
-    #[cfg(not(feature = "no-std"))]
-    pub fn select_n_first_by<const N: usize>(
-        &self,
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` if `len() + other.len()` exceeds `K`.
+    /// Both lists are left untouched in that case.
+    pub fn merge_sorted_by(
+        &mut self,
+        other: &mut Self,
         mut compare: impl FnMut(&T, &T) -> Ordering,
-    ) -> Vec<T>
+    ) -> Result<(), CapacityError<()>> {
+        if self.len + other.len > K {
+            return Err(CapacityError::new(()));
+        }
+
+        let mut cursor = self.head;
+
+        while let Some(slot) = other.head {
+            let moved = unsafe { other.nodes[slot].assume_init_ref() };
+
+            while let Some(c) = cursor {
+                let kept = unsafe { self.nodes[c].assume_init_ref() };
+                if compare(&kept.value, &moved.value) == Ordering::Greater {
+                    break;
+                }
+
+                cursor = kept.next;
+            }
+
+            let value = other.unlink(slot);
+            self.link_before(cursor, value);
+        }
+
+        Ok(())
+    }
+
+    /// Detaches the node stored in `slot`, frees the slot and returns the node's value.
+    ///
+    /// `slot` must hold a node of this list.
+    fn unlink(&mut self, slot: usize) -> T {
+        let node = unsafe { self.nodes[slot].assume_init_read() };
+
+        match node.prev {
+            Some(p) => unsafe { self.nodes[p].assume_init_mut() }.next = node.next,
+            None => self.head = node.next,
+        }
+
+        match node.next {
+            Some(n) => unsafe { self.nodes[n].assume_init_mut() }.prev = node.prev,
+            None => self.tail = node.prev,
+        }
+
+        self.remove_used(slot);
+        self.len -= 1;
+
+        node.value
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, preserving their order.
+    ///
+    /// Removed values are dropped and their slots become free in a single pass.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        let mut current = self.head;
+
+        while let Some(slot) = current {
+            let node = unsafe { self.nodes[slot].assume_init_ref() };
+            current = node.next;
+
+            if !f(&node.value) {
+                self.unlink(slot);
+            }
+        }
+    }
+
+    /// Returns an iterator removing and yielding every element for which `f` returns `true`.
+    ///
+    /// The predicate may mutate the elements it inspects. Elements are removed lazily: if the
+    /// iterator is dropped before being exhausted, the remaining elements are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use datastructures::DoubleLinkedList::SizedDoubleLinkedList;
+    ///
+    /// let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+    /// list.insert_tail(1);
+    /// list.insert_tail(2);
+    /// list.insert_tail(3);
+    ///
+    /// let mut evens = [0; 1];
+    /// for (slot, value) in evens.iter_mut().zip(list.extract_if(|v| *v % 2 == 0)) {
+    ///     *slot = value;
+    /// }
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn extract_if<F>(&mut self, f: F) -> ExtractIf<'_, T, K, F>
     where
-        T: Clone,
+        F: FnMut(&mut T) -> bool,
     {
-        if self.len == 0 || N == 0 {
-            return Vec::new();
+        ExtractIf {
+            current: self.head,
+            list: self,
+            predicate: f,
         }
+    }
 
-        let mut indices = Vec::with_capacity(self.len);
-        let mut current = self.head.unwrap();
+    /// Removes and returns the first element for which `f` returns `true`.
+    pub fn remove_first_where(&mut self, mut f: impl FnMut(&T) -> bool) -> Option<T> {
+        let mut current = self.head;
+
+        while let Some(slot) = current {
+            let node = unsafe { self.nodes[slot].assume_init_ref() };
+            if f(&node.value) {
+                return Some(self.unlink(slot));
+            }
+
+            current = node.next;
+        }
+
+        None
+    }
+
+    /// Returns the position of the last element that matches the predicate.
+    ///
+    /// Traverses the list from tail to head. Unlike [`get_index_where`](Self::get_index_where),
+    /// the returned value is the logical position counted from the head, usable with
+    /// [`get`](Self::get) and [`remove`](Self::remove).
+    pub fn position_from_back(&self, mut f: impl FnMut(&T) -> bool) -> Option<usize> {
+        let mut current = self.tail;
+        let mut position = self.len;
+
+        while let Some(slot) = current {
+            let node = unsafe { self.nodes[slot].assume_init_ref() };
+            position -= 1;
+
+            if f(&node.value) {
+                return Some(position);
+            }
+
+            current = node.prev;
+        }
+
+        None
+    }
+
+    /// Returns the number of elements for which `f` returns `true`.
+    pub fn count_where(&self, mut f: impl FnMut(&T) -> bool) -> usize {
+        let mut count = 0;
+        let mut current = self.head;
+
+        while let Some(slot) = current {
+            let node = unsafe { self.nodes[slot].assume_init_ref() };
+            if f(&node.value) {
+                count += 1;
+            }
+
+            current = node.next;
+        }
+
+        count
+    }
+
+    /// Returns a mutable reference to the first value that matches the predicate.
+    ///
+    /// Iterates from head to tail like [`get_value_where`](Self::get_value_where).
+    pub fn get_value_where_mut(&mut self, f: impl Fn(&T) -> bool) -> Option<&mut T> {
+        let mut current = self.head?;
 
         loop {
-            indices.push(current);
-            let n = unsafe { &*self.nodes[current].as_ptr() };
-            match n.next {
-                Some(next) => current = next,
-                None => break,
+            let node = unsafe { &*self.nodes[current].as_ptr() };
+            if f(&node.value) {
+                return Some(&mut unsafe { self.nodes[current].assume_init_mut() }.value);
             }
+
+            current = node.next?;
         }
+    }
 
-        let target = min(N, self.len);
+    /// Returns a reference to the first value, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.head
+            .map(|slot| &unsafe { self.nodes[slot].assume_init_ref() }.value)
+    }
 
-        let mut cmp_indices = |&a: &usize, &b: &usize| {
-            let va = unsafe { &*self.nodes[a].as_ptr() };
-            let vb = unsafe { &*self.nodes[b].as_ptr() };
-            compare(&va.value, &vb.value)
-        };
+    /// Returns a reference to the last value, or `None` if the list is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.tail
+            .map(|slot| &unsafe { self.nodes[slot].assume_init_ref() }.value)
+    }
+
+    /// Removes and returns the first value, or `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|slot| self.unlink(slot))
+    }
 
-        if target < self.len {
-            indices.select_nth_unstable_by(target - 1, &mut cmp_indices);
+    /// Removes and returns the last value, or `None` if the list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|slot| self.unlink(slot))
+    }
+
+    /// Returns a double-ended iterator over references to the values, from head to tail.
+    pub fn iter(&self) -> Iter<'_, T, K> {
+        Iter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
         }
+    }
+
+    /// Returns a mutable reference to the value at the specified index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndexError` if `index >= len()`
+    pub fn get_mut(&mut self, index: usize) -> Result<&mut T, IndexError> {
+        if index >= self.len {
+            return Err(IndexError::new(index, self.len));
+        }
+
+        let slot = self.slot_at(index);
+
+        Ok(&mut unsafe { self.nodes[slot].assume_init_mut() }.value)
+    }
+}
+
+/// Iterator removing the elements matching a predicate, created by
+/// [`SizedDoubleLinkedList::extract_if`].
+pub struct ExtractIf<'a, T, const K: usize, F>
+where
+    Const<K>: ValidK,
+    F: FnMut(&mut T) -> bool,
+{
+    list: &'a mut SizedDoubleLinkedList<T, K>,
+    current: Option<usize>,
+    predicate: F,
+}
+
+impl<T, const K: usize, F> Iterator for ExtractIf<'_, T, K, F>
+where
+    Const<K>: ValidK,
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(slot) = self.current {
+            let node = unsafe { self.list.nodes[slot].assume_init_mut() };
+            self.current = node.next;
+
+            if (self.predicate)(&mut node.value) {
+                return Some(self.list.unlink(slot));
+            }
+        }
+
+        None
+    }
+}
+
+/// Iterator over the values of a [`SizedDoubleLinkedList`], created by
+/// [`SizedDoubleLinkedList::iter`].
+pub struct Iter<'a, T, const K: usize>
+where
+    Const<K>: ValidK,
+{
+    list: &'a SizedDoubleLinkedList<T, K>,
+    front: Option<usize>,
+    back: Option<usize>,
+    remaining: usize,
+}
+
+impl<T, const K: usize> Clone for Iter<'_, T, K>
+where
+    Const<K>: ValidK,
+{
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T, const K: usize> Iterator for Iter<'a, T, K>
+where
+    Const<K>: ValidK,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = unsafe { self.list.nodes[self.front?].assume_init_ref() };
+        self.front = node.next;
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, const K: usize> DoubleEndedIterator for Iter<'a, T, K>
+where
+    Const<K>: ValidK,
+{
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = unsafe { self.list.nodes[self.back?].assume_init_ref() };
+        self.back = node.prev;
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+}
+
+impl<T, const K: usize> ExactSizeIterator for Iter<'_, T, K> where Const<K>: ValidK {}
+
+/// Owning iterator over the values of a [`SizedDoubleLinkedList`], from head to tail.
+pub struct IntoIter<T, const K: usize>
+where
+    Const<K>: ValidK,
+{
+    list: SizedDoubleLinkedList<T, K>,
+}
+
+impl<T, const K: usize> Iterator for IntoIter<T, K>
+where
+    Const<K>: ValidK,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, const K: usize> DoubleEndedIterator for IntoIter<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, const K: usize> ExactSizeIterator for IntoIter<T, K> where Const<K>: ValidK {}
+
+/// Serializes the values as a sequence, from head to tail.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const K: usize> serde::Serialize for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes a sequence of at most `K` values.
+///
+/// A longer sequence is rejected with an `invalid_length` error instead of being truncated.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const K: usize> serde::Deserialize<'de>
+    for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct ListVisitor<T, const K: usize>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const K: usize> Visitor<'de> for ListVisitor<T, K>
+        where
+            Const<K>: ValidK,
+        {
+            type Value = SizedDoubleLinkedList<T, K>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {K} elements")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                if let Some(hint) = seq.size_hint()
+                    && hint > K
+                {
+                    return Err(A::Error::invalid_length(hint, &self));
+                }
+
+                let mut list: SizedDoubleLinkedList<T, K> = Default::default();
+
+                while let Some(value) = seq.next_element()? {
+                    if list.insert_tail(value).is_err() {
+                        return Err(A::Error::invalid_length(K + 1, &self));
+                    }
+                }
+
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+/// Writes the list as a binary snapshot recording `K`, the length and the values in order.
+impl<T: Encode, const K: usize> Encode for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn encoded_len(&self) -> usize {
+        binary::snapshot_len(self.iter())
+    }
+
+    fn encode(&self, out: &mut [u8]) -> Result<usize, CodecError> {
+        binary::write_snapshot(SnapshotKind::DoubleLinkedList, K, self.iter(), out)
+    }
+}
+
+/// Reads a list from a binary snapshot, checking the checksum and that the length fits in `K`.
+impl<T: Decode, const K: usize> Decode for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn decode(input: &[u8]) -> Result<(Self, usize), CodecError> {
+        let (header, payload, read) =
+            binary::read_snapshot(SnapshotKind::DoubleLinkedList, K, input)?;
 
-        indices.truncate(target);
-        indices.sort_unstable_by(&mut cmp_indices);
+        let mut list: Self = Default::default();
+        binary::read_elements(&header, payload, |value| {
+            list.insert_tail(value)
+                .map_err(|_| CodecError::LengthExceedsCapacity)
+        })?;
 
-        indices
-            .iter()
-            .map(|&idx| {
-                let n = unsafe { &*self.nodes[idx].as_ptr() };
-                n.value.clone()
-            })
-            .collect()
+        Ok((list, read))
     }
 }
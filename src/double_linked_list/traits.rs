@@ -0,0 +1,479 @@
+//! Common interface shared by the double-linked list implementations.
+//!
+//! This module defines traits implemented by both
+//! [`SizedDoubleLinkedList`](super::sized::SizedDoubleLinkedList) and
+//! [`DoubleLinkedList`](super::dynamic::DoubleLinkedList), so generic code can switch between
+//! fixed and dynamic storage through a type parameter.
+//!
+//! # Traits
+//!
+//! - [`LinkedList`]: Indexed insertion, removal, lookup and sorting
+//! - [`Deque`]: Insertion and removal at both ends
+//! - [`Queue`]: First-in, first-out access
+//!
+//! Indices are always logical positions counted from the head. Operations that may grow the
+//! list return `Result` because the fixed-size list can run out of slots; the dynamic list
+//! never reports a `CapacityError`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::DoubleLinkedList::{Deque, LinkedList};
+//!
+//! fn drain_sorted<L: LinkedList<u32> + Deque<u32>>(list: &mut L, out: &mut [u32]) {
+//!     list.sort_by(|a, b| a.cmp(b));
+//!
+//!     for slot in out.iter_mut() {
+//!         match list.pop_front() {
+//!             Some(value) => *slot = value,
+//!             None => break,
+//!         }
+//!     }
+//! }
+//! ```
+
+use super::sized::{SizedDoubleLinkedList, ValidK};
+use crate::{CapacityError, Const, IndexError, LinkedListError};
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use super::dynamic::{self, DoubleLinkedList};
+
+/// Indexed double-linked list operations.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of values stored in the list
+pub trait LinkedList<T> {
+    /// Iterator over references to the values, from head to tail.
+    type Iter<'a>: DoubleEndedIterator<Item = &'a T> + ExactSizeIterator
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns the maximum number of elements, or `None` if the list grows without bound.
+    fn capacity(&self) -> Option<usize>;
+
+    /// Returns the number of elements currently in the list.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the list contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if no further element can be inserted.
+    fn is_full(&self) -> bool {
+        self.capacity()
+            .is_some_and(|capacity| self.len() >= capacity)
+    }
+
+    /// Inserts a value at the beginning of the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the list is at capacity.
+    fn insert_head(&mut self, value: T) -> Result<(), CapacityError<T>>;
+
+    /// Inserts a value at the end of the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the list is at capacity.
+    fn insert_tail(&mut self, value: T) -> Result<(), CapacityError<T>>;
+
+    /// Inserts a value right after the element at `index`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `LinkedListError::IndexOutOfRange` if `index >= len()`
+    /// - Returns `LinkedListError::ListIsFull` holding `value` if the list is at capacity
+    fn insert_after(&mut self, index: usize, value: T) -> Result<(), LinkedListError<T>>;
+
+    /// Inserts a value right before the element at `index`.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `LinkedListError::IndexOutOfRange` if `index >= len()`, unless the list is
+    ///   empty and `index` is `0`
+    /// - Returns `LinkedListError::ListIsFull` holding `value` if the list is at capacity
+    fn insert_before(&mut self, index: usize, value: T) -> Result<(), LinkedListError<T>>;
+
+    /// Returns a reference to the value at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndexError` if `index >= len()`
+    fn get(&self, index: usize) -> Result<&T, IndexError>;
+
+    /// Removes the element at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `IndexError` if `index >= len()`
+    fn remove(&mut self, index: usize) -> Result<(), IndexError>;
+
+    /// Returns a double-ended iterator over the values, from head to tail.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Applies `f` to every value, from head to tail.
+    fn iter_and_compute(&mut self, f: impl Fn(&mut T));
+
+    /// Returns the first value for which `f` returns `true`.
+    fn get_value_where(&self, f: impl Fn(&T) -> bool) -> Option<&T>;
+
+    /// Returns a mutable reference to the first value for which `f` returns `true`.
+    fn get_value_where_mut(&mut self, f: impl Fn(&T) -> bool) -> Option<&mut T>;
+
+    /// Returns the position of the first value for which `f` returns `true`.
+    fn position_where(&self, f: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(f)
+    }
+
+    /// Returns the position of the last value for which `f` returns `true`.
+    fn position_from_back(&self, f: impl FnMut(&T) -> bool) -> Option<usize>;
+
+    /// Returns the number of values for which `f` returns `true`.
+    fn count_where(&self, f: impl FnMut(&T) -> bool) -> usize;
+
+    /// Keeps only the values for which `f` returns `true`, preserving their order.
+    fn retain(&mut self, f: impl FnMut(&T) -> bool);
+
+    /// Removes and returns the first value for which `f` returns `true`.
+    fn remove_first_where(&mut self, f: impl FnMut(&T) -> bool) -> Option<T>;
+
+    /// Sorts the list with a stable sort using `compare`.
+    fn sort_by(&mut self, compare: impl FnMut(&T, &T) -> Ordering);
+
+    /// Inserts a value into a list sorted by `compare`, after any equal elements.
+    ///
+    /// Returns the position of the inserted value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the list is at capacity.
+    fn insert_sorted_by(
+        &mut self,
+        value: T,
+        compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> Result<usize, CapacityError<T>>;
+
+    /// Searches a sorted list with a comparator against the target.
+    ///
+    /// Returns `Ok(index)` of a matching element, or `Err(index)` where it would be inserted.
+    fn find_sorted_by(&self, f: impl FnMut(&T) -> Ordering) -> Result<usize, usize>;
+}
+
+/// Double-ended queue operations.
+pub trait Deque<T> {
+    /// Inserts a value at the front.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the container is at capacity.
+    fn push_front(&mut self, value: T) -> Result<(), CapacityError<T>>;
+
+    /// Inserts a value at the back.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the container is at capacity.
+    fn push_back(&mut self, value: T) -> Result<(), CapacityError<T>>;
+
+    /// Removes and returns the front value.
+    fn pop_front(&mut self) -> Option<T>;
+
+    /// Removes and returns the back value.
+    fn pop_back(&mut self) -> Option<T>;
+
+    /// Returns a reference to the front value.
+    fn front(&self) -> Option<&T>;
+
+    /// Returns a reference to the back value.
+    fn back(&self) -> Option<&T>;
+}
+
+/// First-in, first-out queue operations.
+pub trait Queue<T> {
+    /// Adds a value at the back of the queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the queue is at capacity.
+    fn enqueue(&mut self, value: T) -> Result<(), CapacityError<T>>;
+
+    /// Removes and returns the value at the front of the queue.
+    fn dequeue(&mut self) -> Option<T>;
+
+    /// Returns a reference to the value at the front of the queue.
+    fn peek(&self) -> Option<&T>;
+}
+
+impl<T, const K: usize> LinkedList<T> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    type Iter<'a>
+        = super::sized::Iter<'a, T, K>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn capacity(&self) -> Option<usize> {
+        Some(K)
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn insert_head(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_head(value)
+    }
+
+    fn insert_tail(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_tail(value)
+    }
+
+    fn insert_after(&mut self, index: usize, value: T) -> Result<(), LinkedListError<T>> {
+        self.insert_after(index, value)
+    }
+
+    fn insert_before(&mut self, index: usize, value: T) -> Result<(), LinkedListError<T>> {
+        self.insert_before(index, value)
+    }
+
+    fn get(&self, index: usize) -> Result<&T, IndexError> {
+        self.get(index)
+    }
+
+    fn remove(&mut self, index: usize) -> Result<(), IndexError> {
+        self.remove(index)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+
+    fn iter_and_compute(&mut self, f: impl Fn(&mut T)) {
+        self.iter_and_compute(f)
+    }
+
+    fn get_value_where(&self, f: impl Fn(&T) -> bool) -> Option<&T> {
+        self.get_value_where(f)
+    }
+
+    fn get_value_where_mut(&mut self, f: impl Fn(&T) -> bool) -> Option<&mut T> {
+        self.get_value_where_mut(f)
+    }
+
+    fn position_from_back(&self, f: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.position_from_back(f)
+    }
+
+    fn count_where(&self, f: impl FnMut(&T) -> bool) -> usize {
+        self.count_where(f)
+    }
+
+    fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        self.retain(f)
+    }
+
+    fn remove_first_where(&mut self, f: impl FnMut(&T) -> bool) -> Option<T> {
+        self.remove_first_where(f)
+    }
+
+    fn sort_by(&mut self, compare: impl FnMut(&T, &T) -> Ordering) {
+        self.sort_by(compare)
+    }
+
+    fn insert_sorted_by(
+        &mut self,
+        value: T,
+        compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> Result<usize, CapacityError<T>> {
+        self.insert_sorted_by(value, compare)
+    }
+
+    fn find_sorted_by(&self, f: impl FnMut(&T) -> Ordering) -> Result<usize, usize> {
+        self.find_sorted_by(f)
+    }
+}
+
+impl<T, const K: usize> Deque<T> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn push_front(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_head(value)
+    }
+
+    fn push_back(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_tail(value)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.front()
+    }
+
+    fn back(&self) -> Option<&T> {
+        self.back()
+    }
+}
+
+impl<T, const K: usize> Queue<T> for SizedDoubleLinkedList<T, K>
+where
+    Const<K>: ValidK,
+{
+    fn enqueue(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_tail(value)
+    }
+
+    fn dequeue(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.front()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> LinkedList<T> for DoubleLinkedList<T> {
+    type Iter<'a>
+        = dynamic::Iter<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn insert_head(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_head(value)
+    }
+
+    fn insert_tail(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_tail(value)
+    }
+
+    fn insert_after(&mut self, index: usize, value: T) -> Result<(), LinkedListError<T>> {
+        self.insert_after(index, value)
+    }
+
+    fn insert_before(&mut self, index: usize, value: T) -> Result<(), LinkedListError<T>> {
+        self.insert_before(index, value)
+    }
+
+    fn get(&self, index: usize) -> Result<&T, IndexError> {
+        self.get(index)
+    }
+
+    fn remove(&mut self, index: usize) -> Result<(), IndexError> {
+        self.remove(index)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+
+    fn iter_and_compute(&mut self, f: impl Fn(&mut T)) {
+        self.iter_and_compute(f)
+    }
+
+    fn get_value_where(&self, f: impl Fn(&T) -> bool) -> Option<&T> {
+        self.get_value_where(f)
+    }
+
+    fn get_value_where_mut(&mut self, f: impl Fn(&T) -> bool) -> Option<&mut T> {
+        self.get_value_where_mut(f)
+    }
+
+    fn position_from_back(&self, f: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.position_from_back(f)
+    }
+
+    fn count_where(&self, f: impl FnMut(&T) -> bool) -> usize {
+        self.count_where(f)
+    }
+
+    fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        self.retain(f)
+    }
+
+    fn remove_first_where(&mut self, f: impl FnMut(&T) -> bool) -> Option<T> {
+        self.remove_first_where(f)
+    }
+
+    fn sort_by(&mut self, compare: impl FnMut(&T, &T) -> Ordering) {
+        self.sort_by(compare)
+    }
+
+    fn insert_sorted_by(
+        &mut self,
+        value: T,
+        compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> Result<usize, CapacityError<T>> {
+        self.insert_sorted_by(value, compare)
+    }
+
+    fn find_sorted_by(&self, f: impl FnMut(&T) -> Ordering) -> Result<usize, usize> {
+        self.find_sorted_by(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Deque<T> for DoubleLinkedList<T> {
+    fn push_front(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_head(value)
+    }
+
+    fn push_back(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_tail(value)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.front()
+    }
+
+    fn back(&self) -> Option<&T> {
+        self.back()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Queue<T> for DoubleLinkedList<T> {
+    fn enqueue(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        self.insert_tail(value)
+    }
+
+    fn dequeue(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.front()
+    }
+}
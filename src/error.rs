@@ -0,0 +1,142 @@
+//! Error types shared by every container of the crate.
+//!
+//! Fixed-capacity containers report a full container with [`CapacityError`], which hands the
+//! rejected value back to the caller instead of dropping it. Positional accesses report the
+//! requested index and the length at the time of the call with [`IndexError`].
+//! [`LinkedListError`] combines both for operations that can fail either way, such as
+//! inserting next to a given index.
+//!
+//! Every error implements `Display` and [`core::error::Error`], so they can be boxed or
+//! propagated with `?` both with and without the standard library.
+
+use core::error::Error;
+use core::fmt;
+
+/// A value was rejected because the container has reached its capacity.
+///
+/// The rejected value is handed back and can be recovered with [`CapacityError::into_inner`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T> {
+    value: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Wraps the value rejected by a full container.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    /// Returns a reference to the rejected value.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes the error and returns the rejected value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+/// The rejected value is omitted so the error can be unwrapped whatever its type.
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CapacityError").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("container is full")
+    }
+}
+
+impl<T> Error for CapacityError<T> {}
+
+/// An index was out of range for the container it was used with.
+///
+/// # Fields
+///
+/// - `index`: The requested index
+/// - `len`: The number of elements at the time of the call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndexError {
+    pub index: usize,
+    pub len: usize,
+}
+
+impl IndexError {
+    /// Creates an error for `index` used on a container holding `len` elements.
+    #[inline]
+    pub const fn new(index: usize, len: usize) -> Self {
+        Self { index, len }
+    }
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} out of range for length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl Error for IndexError {}
+
+/// Errors that can occur during linked list operations taking both an index and a value.
+///
+/// `T` is the type handed back when the list is full; operations that do not consume a value
+/// use the default `()`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum LinkedListError<T = ()> {
+    /// The index is out of range for the current list size.
+    IndexOutOfRange(IndexError),
+    /// The list has reached its maximum capacity.
+    ListIsFull(CapacityError<T>),
+}
+
+impl<T> LinkedListError<T> {
+    /// Returns the rejected value if the list was full.
+    pub fn into_value(self) -> Option<T> {
+        match self {
+            Self::IndexOutOfRange(_) => None,
+            Self::ListIsFull(error) => Some(error.into_inner()),
+        }
+    }
+}
+
+impl<T> fmt::Debug for LinkedListError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IndexOutOfRange(error) => f.debug_tuple("IndexOutOfRange").field(error).finish(),
+            Self::ListIsFull(error) => f.debug_tuple("ListIsFull").field(error).finish(),
+        }
+    }
+}
+
+impl<T> fmt::Display for LinkedListError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IndexOutOfRange(error) => fmt::Display::fmt(error, f),
+            Self::ListIsFull(error) => fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+impl<T> Error for LinkedListError<T> {}
+
+impl<T> From<IndexError> for LinkedListError<T> {
+    fn from(error: IndexError) -> Self {
+        Self::IndexOutOfRange(error)
+    }
+}
+
+impl<T> From<CapacityError<T>> for LinkedListError<T> {
+    fn from(error: CapacityError<T>) -> Self {
+        Self::ListIsFull(error)
+    }
+}
@@ -0,0 +1,73 @@
+//! Built-in hasher usable without the standard library.
+//!
+//! This module provides [`FnvHasher`], an implementation of the 64-bit FNV-1a hash, and
+//! [`BuildFnvHasher`], the default `BuildHasher` of the fixed-capacity hash containers.
+//!
+//! # Overview
+//!
+//! FNV-1a folds each input byte into the state with a XOR followed by a multiplication by a
+//! prime. It needs no random seed, so it is fully deterministic, fast on the short keys
+//! typical of embedded lookup tables, and spreads entropy into the low bits used to pick a
+//! bucket.
+//!
+//! It offers no protection against keys chosen to collide (HashDoS). Containers indexed by
+//! untrusted input should use a keyed hasher, such as `std::hash::RandomState`, through
+//! their `with_hasher` constructor.
+
+use core::hash::{BuildHasher, Hasher};
+
+/// FNV-1a 64-bit offset basis.
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime.
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A 64-bit FNV-1a hasher.
+///
+/// # Fields
+///
+/// - `state`: Hash of the bytes written so far
+#[derive(Clone, Copy, Debug)]
+pub struct FnvHasher {
+    state: u64,
+}
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FnvHasher {
+    /// Creates a hasher in its initial state.
+    pub const fn new() -> Self {
+        Self {
+            state: OFFSET_BASIS,
+        }
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state ^= byte as u64;
+            self.state = self.state.wrapping_mul(PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+/// Builds [`FnvHasher`] instances, all starting from the same state.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BuildFnvHasher;
+
+impl BuildHasher for BuildFnvHasher {
+    type Hasher = FnvHasher;
+
+    fn build_hasher(&self) -> FnvHasher {
+        FnvHasher::new()
+    }
+}
@@ -0,0 +1,524 @@
+//! Hash map remembering the order of its entries.
+//!
+//! This module provides [`LinkedHashMap`], combining a `std::collections::HashMap` index with
+//! a [`DoubleLinkedList`] holding the entries in order.
+//!
+//! **Note**: This module requires the `std` feature.
+//!
+//! # Overview
+//!
+//! Entries are stored as `(key, value)` nodes of a `DoubleLinkedList`, and the hash index maps
+//! each key to its node. Lookups go through the index, while removals and reordering relink
+//! nodes in place, so every operation is O(1) on average. The index refers to the key stored
+//! in the node instead of a copy, so keys need not be `Clone`.
+//!
+//! The map keeps one of two orders, chosen at construction:
+//! - [`Order::Insertion`]: entries stay where they were first inserted; replacing the value of
+//!   an existing key keeps its position.
+//! - [`Order::Access`]: inserting or accessing a key through [`get_mut`](LinkedHashMap::get_mut)
+//!   moves it to the back, so the front is always the least recently used entry.
+//!
+//! [`get`](LinkedHashMap::get) never reorders, so the map can be inspected through a shared
+//! reference. [`move_to_back`](LinkedHashMap::move_to_back) and
+//! [`pop_front`](LinkedHashMap::pop_front) are the building blocks of LRU-style caches.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::hash::{LinkedHashMap, Order};
+//!
+//! let mut cache = LinkedHashMap::with_order(Order::Access);
+//! cache.insert("a", 1);
+//! cache.insert("b", 2);
+//!
+//! cache.get_mut("a");
+//! assert_eq!(cache.pop_front(), Some(("b", 2)));
+//! ```
+
+use crate::double_linked_list::DoubleLinkedList;
+use crate::double_linked_list::dynamic::{self, Node};
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hash, Hasher, RandomState};
+use std::iter::FusedIterator;
+use std::mem;
+use std::ptr::NonNull;
+
+/// Order in which a [`LinkedHashMap`] keeps its entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// Entries keep the position of their first insertion.
+    #[default]
+    Insertion,
+    /// Inserted and accessed entries move to the back.
+    Access,
+}
+
+/// Node of the list holding an entry.
+type Link<K, V> = NonNull<Node<(K, V)>>;
+
+/// Index key pointing at the key stored in a list node.
+struct KeyRef<K>(NonNull<K>);
+
+impl<K: Hash> Hash for KeyRef<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        unsafe { self.0.as_ref() }.hash(state);
+    }
+}
+
+impl<K: PartialEq> PartialEq for KeyRef<K> {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { self.0.as_ref() == other.0.as_ref() }
+    }
+}
+
+impl<K: Eq> Eq for KeyRef<K> {}
+
+/// Borrowed form of a key, letting the index be queried with any `Q` that `K` borrows as.
+#[repr(transparent)]
+struct Query<Q: ?Sized>(Q);
+
+impl<Q: ?Sized> Query<Q> {
+    fn new(query: &Q) -> &Self {
+        // SAFETY: `Query` is a transparent wrapper around `Q`.
+        unsafe { &*(query as *const Q as *const Query<Q>) }
+    }
+}
+
+impl<Q: Hash + ?Sized> Hash for Query<Q> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<Q: PartialEq + ?Sized> PartialEq for Query<Q> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<Q: Eq + ?Sized> Eq for Query<Q> {}
+
+impl<K: Borrow<Q>, Q: ?Sized> Borrow<Query<Q>> for KeyRef<K> {
+    fn borrow(&self) -> &Query<Q> {
+        Query::new(unsafe { self.0.as_ref() }.borrow())
+    }
+}
+
+/// A hash map iterating over its entries in insertion or access order.
+///
+/// # Type Parameters
+///
+/// - `K`: The type of keys
+/// - `V`: The type of values
+/// - `S`: Builder of the hasher of the index, `RandomState` by default
+///
+/// # Fields
+///
+/// - `entries`: Entries in order, from front to back
+/// - `index`: Map from the key of each entry to its node
+/// - `order`: Order kept by the map
+pub struct LinkedHashMap<K, V, S = RandomState> {
+    entries: DoubleLinkedList<(K, V)>,
+    index: HashMap<KeyRef<K>, Link<K, V>, S>,
+    order: Order,
+}
+
+// SAFETY: the pointers in the index refer to nodes owned by `entries`.
+unsafe impl<K: Send, V: Send, S: Send> Send for LinkedHashMap<K, V, S> {}
+unsafe impl<K: Sync, V: Sync, S: Sync> Sync for LinkedHashMap<K, V, S> {}
+
+impl<K, V, S: Default> Default for LinkedHashMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_order_and_hasher(Order::Insertion, S::default())
+    }
+}
+
+impl<K, V, S> Clone for LinkedHashMap<K, V, S>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    S: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        let mut map = Self::with_order_and_hasher(self.order, self.index.hasher().clone());
+        for (key, value) in self {
+            map.insert(key.clone(), value.clone());
+        }
+
+        map
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for LinkedHashMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Two maps are equal if they hold the same entries in the same order.
+impl<K: PartialEq, V: PartialEq, S> PartialEq for LinkedHashMap<K, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K: Eq, V: Eq, S> Eq for LinkedHashMap<K, V, S> {}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> FromIterator<(K, V)> for LinkedHashMap<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for LinkedHashMap<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a LinkedHashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<K, V, S> IntoIterator for LinkedHashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = dynamic::IntoIter<(K, V)>;
+
+    fn into_iter(mut self) -> dynamic::IntoIter<(K, V)> {
+        // The index points into the nodes, so it must not outlive them.
+        self.index.clear();
+        mem::take(&mut self.entries).into_iter()
+    }
+}
+
+impl<K, V> LinkedHashMap<K, V> {
+    /// Creates an empty map keeping insertion order.
+    pub fn new() -> Self {
+        Self::with_order(Order::Insertion)
+    }
+
+    /// Creates an empty map keeping `order`.
+    pub fn with_order(order: Order) -> Self {
+        Self::with_order_and_hasher(order, RandomState::new())
+    }
+}
+
+impl<K, V, S> LinkedHashMap<K, V, S> {
+    /// Creates an empty map keeping `order`, hashing keys with `hasher`.
+    pub fn with_order_and_hasher(order: Order, hasher: S) -> Self {
+        Self {
+            entries: DoubleLinkedList::default(),
+            index: HashMap::with_hasher(hasher),
+            order,
+        }
+    }
+
+    /// Returns the order kept by the map.
+    #[inline]
+    pub fn order(&self) -> Order {
+        self.order
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.index.clear();
+        self.entries = DoubleLinkedList::default();
+    }
+
+    /// Returns the front entry, the oldest or least recently used one.
+    pub fn front(&self) -> Option<(&K, &V)> {
+        self.entries.front().map(|(key, value)| (key, value))
+    }
+
+    /// Returns the back entry, the newest or most recently used one.
+    pub fn back(&self) -> Option<(&K, &V)> {
+        self.entries.back().map(|(key, value)| (key, value))
+    }
+
+    /// Returns a double-ended iterator over the entries, from front to back.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// Returns a double-ended iterator over the keys, from front to back.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns a double-ended iterator over the values, from front to back.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<K, V, S> LinkedHashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Returns the node holding `key`, if present.
+    fn node<Q>(&self, key: &Q) -> Option<Link<K, V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.get(Query::new(key)).copied()
+    }
+
+    /// Unlinks `node` and returns its entry.
+    fn remove_node(&mut self, node: Link<K, V>) -> (K, V) {
+        let key = unsafe { &node.as_ref().value.0 };
+        self.index.remove(Query::new(key));
+
+        self.entries.unlink(node)
+    }
+
+    /// Inserts a key-value pair, returning the previous value of the key if present.
+    ///
+    /// A new key is appended at the back. An existing key keeps its position in insertion
+    /// order and moves to the back in access order.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(mut node) = self.node(&key) {
+            if self.order == Order::Access {
+                self.entries.move_node_before(node, None);
+            }
+
+            let (_, current) = unsafe { &mut node.as_mut().value };
+            return Some(mem::replace(current, value));
+        }
+
+        let node = self.entries.push_back_node((key, value));
+        let key = unsafe { NonNull::from(&node.as_ref().value.0) };
+        self.index.insert(KeyRef(key), node);
+
+        None
+    }
+
+    /// Returns a reference to the value of `key`, if present, without reordering.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.node(key)
+            .map(|node| unsafe { &(*node.as_ptr()).value.1 })
+    }
+
+    /// Returns a mutable reference to the value of `key`, if present.
+    ///
+    /// In access order, the entry moves to the back.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node = self.node(key)?;
+
+        if self.order == Order::Access {
+            self.entries.move_node_before(node, None);
+        }
+
+        Some(unsafe { &mut (*node.as_ptr()).value.1 })
+    }
+
+    /// Returns `true` if the map contains `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.contains_key(Query::new(key))
+    }
+
+    /// Removes `key` and returns its value, if present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes `key` and returns the stored key and its value, if present.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node = self.node(key)?;
+        Some(self.remove_node(node))
+    }
+
+    /// Moves the entry of `key` to the back, returning `false` if the key is absent.
+    pub fn move_to_back<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(node) = self.node(key) else {
+            return false;
+        };
+
+        self.entries.move_node_before(node, None);
+        true
+    }
+
+    /// Moves the entry of `key` to the front, returning `false` if the key is absent.
+    pub fn move_to_front<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(node) = self.node(key) else {
+            return false;
+        };
+
+        self.entries
+            .move_node_before(node, self.entries.front_node());
+        true
+    }
+
+    /// Removes and returns the front entry, the oldest or least recently used one.
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        let node = self.entries.front_node()?;
+        Some(self.remove_node(node))
+    }
+
+    /// Removes and returns the back entry, the newest or most recently used one.
+    pub fn pop_back(&mut self) -> Option<(K, V)> {
+        let node = self.entries.back_node()?;
+        Some(self.remove_node(node))
+    }
+
+    /// Keeps only the entries for which `keep` returns `true`, preserving their order.
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &mut V) -> bool) {
+        let mut current = self.entries.front_node();
+
+        while let Some(mut node) = current {
+            current = self.entries.next_node(node);
+            let (key, value) = unsafe { &mut node.as_mut().value };
+
+            if !keep(key, value) {
+                self.remove_node(node);
+            }
+        }
+    }
+}
+
+/// Iterator over the entries of a [`LinkedHashMap`], from front to back.
+///
+/// # Fields
+///
+/// - `inner`: Iterator over the underlying list
+pub struct Iter<'a, K, V> {
+    inner: dynamic::Iter<'a, (K, V)>,
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, value)| (key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, value)| (key, value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// Serializes the entries as a map, from front to back.
+#[cfg(feature = "serde")]
+impl<K: serde::Serialize, V: serde::Serialize, S> serde::Serialize for LinkedHashMap<K, V, S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+/// Deserializes a map in insertion order, hashed with a default-built `S`.
+///
+/// Entries are inserted in the order they appear, so a round trip keeps the order of the
+/// entries but not the [`Order`] of the map. When a key appears twice, the last value wins and
+/// the key keeps its first position.
+#[cfg(feature = "serde")]
+impl<'de, K, V, S> serde::Deserialize<'de> for LinkedHashMap<K, V, S>
+where
+    K: serde::Deserialize<'de> + Hash + Eq,
+    V: serde::Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{MapAccess, Visitor};
+        use std::marker::PhantomData;
+
+        struct MapVisitor<K, V, S>(PhantomData<(K, V, S)>);
+
+        impl<'de, K, V, S> Visitor<'de> for MapVisitor<K, V, S>
+        where
+            K: serde::Deserialize<'de> + Hash + Eq,
+            V: serde::Deserialize<'de>,
+            S: BuildHasher + Default,
+        {
+            type Value = LinkedHashMap<K, V, S>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut map = LinkedHashMap::default();
+
+                while let Some((key, value)) = access.next_entry()? {
+                    map.insert(key, value);
+                }
+
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+}
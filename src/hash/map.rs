@@ -0,0 +1,787 @@
+//! Fixed-capacity hash map with Robin Hood open addressing.
+//!
+//! This module provides [`SizedHashMap`], an associative container storing up to `N` entries
+//! inline, without allocation.
+//!
+//! # Overview
+//!
+//! Entries live in an array of `N` slots. A key is hashed with the map's `BuildHasher` to
+//! its ideal slot, and probing continues linearly from there. Each slot records the probe
+//! distance of its entry (`0` for an empty slot):
+//! - Insertion applies the Robin Hood rule: a new entry farther from its ideal slot than the
+//!   resident takes the slot, and the resident is carried forward. Probe distances stay
+//!   balanced even when the map is completely full.
+//! - Lookups stop as soon as they meet a resident closer to its ideal slot than the searched
+//!   key would be, so a miss does not scan the whole cluster.
+//! - Removal shifts the following displaced entries back by one slot (backward-shift
+//!   deletion). No tombstone is left behind, so lookups do not degrade after many removals.
+//!
+//! The default hasher is [`BuildFnvHasher`], which needs no standard library; any other
+//! `BuildHasher` can be passed to [`SizedHashMap::with_hasher`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::hash::SizedHashMap;
+//!
+//! let mut routes: SizedHashMap<u16, &str, 16> = SizedHashMap::new();
+//! routes.insert(80, "http").unwrap();
+//! routes.insert(443, "https").unwrap();
+//!
+//! *routes.entry(8080).unwrap().or_insert("proxy") = "alt-http";
+//! assert_eq!(routes.get(&8080), Some(&"alt-http"));
+//! assert_eq!(routes.remove(&80), Some("http"));
+//! ```
+
+use super::hasher::BuildFnvHasher;
+use crate::CapacityError;
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+use core::mem::{self, MaybeUninit};
+use core::ops::Index;
+use core::slice;
+
+/// A fixed-capacity hash map.
+///
+/// # Type Parameters
+///
+/// - `K`: The type of keys, hashed with `S`
+/// - `V`: The type of values
+/// - `N`: Compile-time capacity (less than `u32::MAX`)
+/// - `S`: Builder of the hasher, [`BuildFnvHasher`] by default
+///
+/// # Fields
+///
+/// - `entries`: Array of uninitialized key-value slots
+/// - `distances`: Probe distance plus one of each slot's entry, `0` for an empty slot
+/// - `len`: Current number of entries
+/// - `hasher`: Builder of the hasher mapping keys to their ideal slot
+pub struct SizedHashMap<K, V, const N: usize, S = BuildFnvHasher> {
+    entries: [MaybeUninit<(K, V)>; N],
+    distances: [u32; N],
+    len: usize,
+    hasher: S,
+}
+
+impl<K, V, const N: usize, S: Default> Default for SizedHashMap<K, V, N, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, const N: usize, S> Drop for SizedHashMap<K, V, N, S> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<K: Clone, V: Clone, const N: usize, S: Clone> Clone for SizedHashMap<K, V, N, S> {
+    fn clone(&self) -> Self {
+        let mut map = Self::with_hasher(self.hasher.clone());
+
+        // Same hasher, same layout: entries are copied slot by slot.
+        for index in 0..N {
+            if self.distances[index] != 0 {
+                let (key, value) = unsafe { self.entries[index].assume_init_ref() };
+                map.entries[index].write((key.clone(), value.clone()));
+                map.distances[index] = self.distances[index];
+                map.len += 1;
+            }
+        }
+
+        map
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize, S> fmt::Debug for SizedHashMap<K, V, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, const N: usize, S> PartialEq for SizedHashMap<K, V, N, S>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, const N: usize, S: BuildHasher> Eq for SizedHashMap<K, V, N, S> {}
+
+impl<K, Q, V, const N: usize, S> Index<&Q> for SizedHashMap<K, V, N, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in SizedHashMap")
+    }
+}
+
+impl<'a, K, V, const N: usize, S> IntoIterator for &'a SizedHashMap<K, V, N, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, const N: usize, S> IntoIterator for &'a mut SizedHashMap<K, V, N, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, const N: usize> SizedHashMap<K, V, N> {
+    /// Creates an empty map with capacity `N`, hashing keys with [`BuildFnvHasher`].
+    pub const fn new() -> Self {
+        Self::with_hasher(BuildFnvHasher)
+    }
+}
+
+impl<K, V, const N: usize, S> SizedHashMap<K, V, N, S> {
+    /// Creates an empty map with capacity `N`, hashing keys with `hasher`.
+    pub const fn with_hasher(hasher: S) -> Self {
+        const {
+            assert!(
+                N < u32::MAX as usize,
+                "too many slots for 32-bit probe distances"
+            )
+        };
+
+        Self {
+            entries: [const { MaybeUninit::uninit() }; N],
+            distances: [0; N],
+            len: 0,
+            hasher,
+        }
+    }
+
+    /// Returns the hasher builder of the map.
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        &self.hasher
+    }
+
+    /// Returns the number of entries currently stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if every slot is occupied.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the maximum number of entries the map can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes and drops every entry.
+    pub fn clear(&mut self) {
+        for index in 0..N {
+            if self.distances[index] != 0 {
+                self.distances[index] = 0;
+                unsafe { self.entries[index].assume_init_drop() };
+            }
+        }
+
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the entries, in slot order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            entries: self.entries.iter(),
+            distances: self.distances.iter(),
+            remaining: self.len,
+        }
+    }
+
+    /// Returns an iterator over the entries with mutable values, in slot order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            entries: self.entries.iter_mut(),
+            distances: self.distances.iter(),
+            remaining: self.len,
+        }
+    }
+
+    /// Returns an iterator over the keys, in slot order.
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &K> + FusedIterator {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values, in slot order.
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &V> + FusedIterator {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over mutable references to the values, in slot order.
+    pub fn values_mut(&mut self) -> impl ExactSizeIterator<Item = &mut V> + FusedIterator {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Keeps only the entries for which `keep` returns `true`.
+    ///
+    /// Removed entries are dropped in place, then the remaining ones are shifted back towards
+    /// their ideal slot in a single pass.
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &mut V) -> bool) {
+        let mut removed = false;
+
+        for index in 0..N {
+            if self.distances[index] == 0 {
+                continue;
+            }
+
+            let (key, value) = unsafe { self.entries[index].assume_init_mut() };
+            if !keep(key, value) {
+                self.distances[index] = 0;
+                self.len -= 1;
+                unsafe { self.entries[index].assume_init_drop() };
+                removed = true;
+            }
+        }
+
+        if removed {
+            self.compact();
+        }
+    }
+
+    /// Moves every displaced entry back over the empty slots preceding it.
+    ///
+    /// Each pass walks the table once from an empty slot. A probe sequence wrapping past that
+    /// slot may still see holes left before it, so passes repeat until nothing moves; every
+    /// move lowers the total probe distance, so this terminates, usually after one or two.
+    fn compact(&mut self) {
+        loop {
+            let Some(start) = self.distances.iter().position(|&d| d == 0) else {
+                return;
+            };
+
+            let mut moved = false;
+            let mut empty_run = 0;
+
+            for step in 0..N {
+                let index = (start + step) % N;
+                let distance = self.distances[index];
+
+                if distance == 0 {
+                    empty_run += 1;
+                    continue;
+                }
+
+                let shift = empty_run.min(distance as usize - 1);
+                if shift > 0 {
+                    let target = (index + N - shift) % N;
+                    let entry = unsafe { self.entries[index].assume_init_read() };
+
+                    self.entries[target].write(entry);
+                    self.distances[target] = distance - shift as u32;
+                    self.distances[index] = 0;
+                    moved = true;
+                }
+
+                // The slots between the moved entry and `index` are now the empty run.
+                empty_run = shift;
+            }
+
+            if !moved {
+                return;
+            }
+        }
+    }
+
+    /// Removes the entry at `index`, shifting the following displaced entries back.
+    fn remove_at(&mut self, index: usize) -> (K, V) {
+        let entry = unsafe { self.entries[index].assume_init_read() };
+        self.distances[index] = 0;
+        self.len -= 1;
+
+        let mut hole = index;
+        loop {
+            let next = (hole + 1) % N;
+            let distance = self.distances[next];
+
+            // Stop at an empty slot or an entry already in its ideal slot.
+            if distance <= 1 {
+                break;
+            }
+
+            let moved = unsafe { self.entries[next].assume_init_read() };
+            self.entries[hole].write(moved);
+            self.distances[hole] = distance - 1;
+            self.distances[next] = 0;
+            hole = next;
+        }
+
+        entry
+    }
+}
+
+impl<K, V, const N: usize, S> SizedHashMap<K, V, N, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Returns the ideal slot of `key`.
+    fn ideal<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        (self.hasher.hash_one(key) % N as u64) as usize
+    }
+
+    /// Returns the slot holding `key`, if present.
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.len == 0 {
+            return None;
+        }
+
+        let mut index = self.ideal(key);
+
+        for distance in 1..=N as u32 {
+            let resident = self.distances[index];
+
+            // An empty slot or a resident closer to its ideal slot ends the probe sequence.
+            if resident < distance {
+                return None;
+            }
+
+            if resident == distance {
+                let (candidate, _) = unsafe { self.entries[index].assume_init_ref() };
+                if candidate.borrow() == key {
+                    return Some(index);
+                }
+            }
+
+            index = (index + 1) % N;
+        }
+
+        None
+    }
+
+    /// Places an entry whose key is absent, returning the slot it lands in.
+    ///
+    /// The map must not be full.
+    fn place(&mut self, key: K, value: V) -> usize {
+        let mut index = self.ideal(&key);
+        let mut carried = (key, value);
+        let mut distance = 1;
+        let mut placed = None;
+
+        loop {
+            let resident = self.distances[index];
+
+            if resident == 0 {
+                self.entries[index].write(carried);
+                self.distances[index] = distance;
+                self.len += 1;
+
+                return placed.unwrap_or(index);
+            }
+
+            if resident < distance {
+                // Robin Hood: the entry farther from home takes the slot.
+                mem::swap(&mut carried, unsafe {
+                    self.entries[index].assume_init_mut()
+                });
+                self.distances[index] = distance;
+                distance = resident;
+                placed.get_or_insert(index);
+            }
+
+            index = (index + 1) % N;
+            distance += 1;
+        }
+    }
+
+    /// Inserts a key-value pair, returning the previous value of the key if present.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding the pair if the key is absent and the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+        if let Some(index) = self.find(&key) {
+            let (_, current) = unsafe { self.entries[index].assume_init_mut() };
+            return Ok(Some(mem::replace(current, value)));
+        }
+
+        if self.is_full() {
+            return Err(CapacityError::new((key, value)));
+        }
+
+        self.place(key, value);
+        Ok(None)
+    }
+
+    /// Returns a reference to the value of `key`, if present.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Returns references to the stored key and value of `key`, if present.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        let (key, value) = unsafe { self.entries[index].assume_init_ref() };
+
+        Some((key, value))
+    }
+
+    /// Returns a mutable reference to the value of `key`, if present.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        let (_, value) = unsafe { self.entries[index].assume_init_mut() };
+
+        Some(value)
+    }
+
+    /// Returns `true` if the map contains `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Removes `key` and returns its value, if present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes `key` and returns the stored key and its value, if present.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(key)?;
+        Some(self.remove_at(index))
+    }
+
+    /// Returns the entry of `key` for in-place manipulation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `key` if the key is absent and the map is full, so a
+    /// [`VacantEntry`] can always be filled.
+    pub fn entry(&mut self, key: K) -> Result<Entry<'_, K, V, N, S>, CapacityError<K>> {
+        match self.find(&key) {
+            Some(index) => Ok(Entry::Occupied(OccupiedEntry { map: self, index })),
+            None if self.is_full() => Err(CapacityError::new(key)),
+            None => Ok(Entry::Vacant(VacantEntry { map: self, key })),
+        }
+    }
+}
+
+/// A view into a single slot of a [`SizedHashMap`].
+pub enum Entry<'a, K, V, const N: usize, S> {
+    /// The key is present.
+    Occupied(OccupiedEntry<'a, K, V, N, S>),
+    /// The key is absent and a free slot is available.
+    Vacant(VacantEntry<'a, K, V, N, S>),
+}
+
+impl<'a, K, V, const N: usize, S> Entry<'a, K, V, N, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the key is absent, and returns the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `default` if the key is absent, and returns the value.
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts the default value if the key is absent, and returns the value.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Runs `f` on the value if the key is present.
+    pub fn and_modify(mut self, f: impl FnOnce(&mut V)) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+/// An entry whose key is present in the map.
+///
+/// # Fields
+///
+/// - `map`: The borrowed map
+/// - `index`: Slot holding the entry
+pub struct OccupiedEntry<'a, K, V, const N: usize, S> {
+    map: &'a mut SizedHashMap<K, V, N, S>,
+    index: usize,
+}
+
+impl<'a, K, V, const N: usize, S> OccupiedEntry<'a, K, V, N, S> {
+    /// Returns the stored key.
+    pub fn key(&self) -> &K {
+        unsafe { &self.map.entries[self.index].assume_init_ref().0 }
+    }
+
+    /// Returns a reference to the value.
+    pub fn get(&self) -> &V {
+        unsafe { &self.map.entries[self.index].assume_init_ref().1 }
+    }
+
+    /// Returns a mutable reference to the value.
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { &mut self.map.entries[self.index].assume_init_mut().1 }
+    }
+
+    /// Converts the entry into a mutable reference to the value, borrowed from the map.
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { &mut self.map.entries[self.index].assume_init_mut().1 }
+    }
+
+    /// Replaces the value and returns the previous one.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry and returns its value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Removes the entry and returns the stored key and its value.
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.remove_at(self.index)
+    }
+}
+
+/// An entry whose key is absent from the map, with a free slot reserved for it.
+///
+/// # Fields
+///
+/// - `map`: The borrowed map, not full
+/// - `key`: The absent key
+pub struct VacantEntry<'a, K, V, const N: usize, S> {
+    map: &'a mut SizedHashMap<K, V, N, S>,
+    key: K,
+}
+
+impl<'a, K, V, const N: usize, S> VacantEntry<'a, K, V, N, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Returns the key.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Takes back the key without inserting.
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts `value` under the key and returns a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let index = self.map.place(self.key, value);
+        unsafe { &mut self.map.entries[index].assume_init_mut().1 }
+    }
+}
+
+/// Iterator over the entries of a [`SizedHashMap`].
+///
+/// # Fields
+///
+/// - `entries`: Remaining slots
+/// - `distances`: Probe distances of the remaining slots, `0` when empty
+/// - `remaining`: Number of entries left to yield
+pub struct Iter<'a, K, V> {
+    entries: slice::Iter<'a, MaybeUninit<(K, V)>>,
+    distances: slice::Iter<'a, u32>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let entry = self.entries.next()?;
+
+            if *self.distances.next()? != 0 {
+                self.remaining -= 1;
+                let (key, value) = unsafe { entry.assume_init_ref() };
+                return Some((key, value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// Iterator over the entries of a [`SizedHashMap`] with mutable values.
+///
+/// # Fields
+///
+/// - `entries`: Remaining slots
+/// - `distances`: Probe distances of the remaining slots, `0` when empty
+/// - `remaining`: Number of entries left to yield
+pub struct IterMut<'a, K, V> {
+    entries: slice::IterMut<'a, MaybeUninit<(K, V)>>,
+    distances: slice::Iter<'a, u32>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let entry = self.entries.next()?;
+
+            if *self.distances.next()? != 0 {
+                self.remaining -= 1;
+                let (key, value) = unsafe { entry.assume_init_mut() };
+                return Some((&*key, value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+/// Serializes the entries as a map, in slot order.
+#[cfg(feature = "serde")]
+impl<K: serde::Serialize, V: serde::Serialize, const N: usize, S> serde::Serialize
+    for SizedHashMap<K, V, N, S>
+{
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+/// Deserializes a map of at most `N` distinct keys, hashed with a default-built `S`.
+///
+/// A map holding more keys is rejected with an `invalid_length` error instead of being
+/// truncated. When a key appears twice, the last value wins.
+#[cfg(feature = "serde")]
+impl<'de, K, V, const N: usize, S> serde::Deserialize<'de> for SizedHashMap<K, V, N, S>
+where
+    K: serde::Deserialize<'de> + Hash + Eq,
+    V: serde::Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+        use serde::de::{Error, MapAccess, Visitor};
+
+        struct MapVisitor<K, V, const N: usize, S>(PhantomData<(K, V, S)>);
+
+        impl<'de, K, V, const N: usize, S> Visitor<'de> for MapVisitor<K, V, N, S>
+        where
+            K: serde::Deserialize<'de> + Hash + Eq,
+            V: serde::Deserialize<'de>,
+            S: BuildHasher + Default,
+        {
+            type Value = SizedHashMap<K, V, N, S>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a map of at most {N} entries")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut map = SizedHashMap::default();
+
+                while let Some((key, value)) = access.next_entry()? {
+                    if map.insert(key, value).is_err() {
+                        return Err(A::Error::invalid_length(N + 1, &self));
+                    }
+                }
+
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+}
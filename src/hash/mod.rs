@@ -0,0 +1,25 @@
+//! Hash containers.
+//!
+//! Provides open-addressing associative containers storing their entries inline, and an
+//! ordered map over a linked list.
+//! - `map`: `SizedHashMap` with Robin Hood probing, backward-shift deletion and an entry API
+//! - `set`: `SizedHashSet` built on the map with unit values
+//! - `hasher`: `FnvHasher`, the deterministic default hasher usable without the standard library
+//! - `linked`: `LinkedHashMap` iterating in insertion or access order (std)
+//!
+//! All containers take the `BuildHasher` as a type parameter, so keys can be hashed with any
+//! hasher, including `std::hash::RandomState` when the input is untrusted.
+
+pub mod hasher;
+pub mod map;
+pub mod set;
+
+#[cfg(feature = "std")]
+pub mod linked;
+
+pub use hasher::{BuildFnvHasher, FnvHasher};
+pub use map::{Entry, OccupiedEntry, SizedHashMap, VacantEntry};
+pub use set::SizedHashSet;
+
+#[cfg(feature = "std")]
+pub use linked::{LinkedHashMap, Order};
@@ -0,0 +1,276 @@
+//! Fixed-capacity hash set.
+//!
+//! This module provides [`SizedHashSet`], a set of up to `N` unique values built on
+//! [`SizedHashMap`] with unit values, sharing its Robin Hood probing and tombstone-free
+//! removal.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::hash::SizedHashSet;
+//!
+//! let mut seen: SizedHashSet<u32, 32> = SizedHashSet::new();
+//!
+//! assert_eq!(seen.insert(7), Ok(true));
+//! assert_eq!(seen.insert(7), Ok(false));
+//! assert!(seen.contains(&7));
+//! ```
+
+use super::hasher::BuildFnvHasher;
+use super::map::{self, SizedHashMap};
+use crate::CapacityError;
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+
+/// A fixed-capacity hash set.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of values, hashed with `S`
+/// - `N`: Compile-time capacity (less than `u32::MAX`)
+/// - `S`: Builder of the hasher, [`BuildFnvHasher`] by default
+///
+/// # Fields
+///
+/// - `map`: Map from the values to `()`
+pub struct SizedHashSet<T, const N: usize, S = BuildFnvHasher> {
+    map: SizedHashMap<T, (), N, S>,
+}
+
+impl<T, const N: usize, S: Default> Default for SizedHashSet<T, N, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<T: Clone, const N: usize, S: Clone> Clone for SizedHashSet<T, N, S> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize, S> fmt::Debug for SizedHashSet<T, N, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Hash + Eq, const N: usize, S: BuildHasher> PartialEq for SizedHashSet<T, N, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<T: Hash + Eq, const N: usize, S: BuildHasher> Eq for SizedHashSet<T, N, S> {}
+
+impl<'a, T, const N: usize, S> IntoIterator for &'a SizedHashSet<T, N, S> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T, const N: usize> SizedHashSet<T, N> {
+    /// Creates an empty set with capacity `N`, hashing values with [`BuildFnvHasher`].
+    pub const fn new() -> Self {
+        Self::with_hasher(BuildFnvHasher)
+    }
+}
+
+impl<T, const N: usize, S> SizedHashSet<T, N, S> {
+    /// Creates an empty set with capacity `N`, hashing values with `hasher`.
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: SizedHashMap::with_hasher(hasher),
+        }
+    }
+
+    /// Returns the hasher builder of the set.
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        self.map.hasher()
+    }
+
+    /// Returns the number of values currently stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the set contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns `true` if every slot is occupied.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Returns the maximum number of values the set can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes and drops every value.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns an iterator over the values, in slot order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.map.iter(),
+        }
+    }
+
+    /// Keeps only the values for which `keep` returns `true`.
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) {
+        self.map.retain(|value, _| keep(value));
+    }
+}
+
+impl<T, const N: usize, S> SizedHashSet<T, N, S>
+where
+    T: Hash + Eq,
+    S: BuildHasher,
+{
+    /// Adds a value, returning `false` if an equal value was already present.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if it is absent and the set is full.
+    pub fn insert(&mut self, value: T) -> Result<bool, CapacityError<T>> {
+        match self.map.entry(value) {
+            Ok(map::Entry::Occupied(_)) => Ok(false),
+            Ok(map::Entry::Vacant(entry)) => {
+                entry.insert(());
+                Ok(true)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns `true` if the set contains `value`.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Returns a reference to the stored value equal to `value`, if present.
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get_key_value(value).map(|(stored, _)| stored)
+    }
+
+    /// Removes `value`, returning `true` if it was present.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Removes and returns the stored value equal to `value`, if present.
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove_entry(value).map(|(stored, _)| stored)
+    }
+}
+
+/// Iterator over the values of a [`SizedHashSet`].
+///
+/// # Fields
+///
+/// - `inner`: Iterator over the underlying map
+pub struct Iter<'a, T> {
+    inner: map::Iter<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next().map(|(value, _)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Serializes the values as a sequence, in slot order.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize, S> serde::Serialize for SizedHashSet<T, N, S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes a sequence of at most `N` distinct values, hashed with a default-built `S`.
+///
+/// A sequence holding more distinct values is rejected with an `invalid_length` error instead
+/// of being truncated. Duplicates are merged.
+#[cfg(feature = "serde")]
+impl<'de, T, const N: usize, S> serde::Deserialize<'de> for SizedHashSet<T, N, S>
+where
+    T: serde::Deserialize<'de> + Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct SetVisitor<T, const N: usize, S>(PhantomData<(T, S)>);
+
+        impl<'de, T, const N: usize, S> Visitor<'de> for SetVisitor<T, N, S>
+        where
+            T: serde::Deserialize<'de> + Hash + Eq,
+            S: BuildHasher + Default,
+        {
+            type Value = SizedHashSet<T, N, S>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {N} distinct elements")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut set = SizedHashSet::default();
+
+                while let Some(value) = seq.next_element()? {
+                    if set.insert(value).is_err() {
+                        return Err(A::Error::invalid_length(N + 1, &self));
+                    }
+                }
+
+                Ok(set)
+            }
+        }
+
+        deserializer.deserialize_seq(SetVisitor(PhantomData))
+    }
+}
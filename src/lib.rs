@@ -1,24 +1,48 @@
-#![cfg_attr(feature = "no-std", no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! Data structure implementations with optional `no_std` support.
 //!
 //! Provides a collection of generic data structures.
-//! When the `no_std` feature is enabled, all data structures are adapted to work without
-//! the standard library with compile-time size guarantees, making them suitable for embedded
-//! and kernel environments.
+//! The `std` feature is enabled by default. Building with `default-features = false` makes the
+//! crate `no_std`: the fixed-capacity data structures keep working without the standard library
+//! with compile-time size guarantees, making them suitable for embedded and kernel environments.
+//! Enabling the `alloc` feature on its own brings back the heap-backed containers
+//! (`DoubleLinkedList`, `Slab`, `ChunkedBuffer` and the `vec` helpers) on targets providing a
+//! global allocator.
+//!
+//! The optional `serde` feature implements `Serialize` and `Deserialize` for the lists, buffers,
+//! slabs, fixed-capacity hash containers, `LinkedHashMap`, sorted maps, prefix tables and the
+//! work-stealing pool; fixed-capacity containers reject inputs holding more elements than they
+//! can store, and `LinkedHashMap` keeps the order of its entries.
 //!
 //! # Modules
 //!
-//! - [`mod@double_linked_list`] - Fixed-size and unlimited capacity double-linked lists
+//! - [`mod@double_linked_list`] - Fixed-size, unlimited capacity and intrusive double-linked lists
 //! - [`mod@array`] - Array manipulation and conversion utilities
 //! - [`mod@option`] - Option type utilities and comparisons
 //! - [`mod@vec`] - Vector helpers for merging and MaybeUninit conversions
 //! - [`mod@workstealing`] - Chase-Lev-inspired fixed-capacity work-stealing deque
+//! - [`mod@slab`] - Fixed-size and growable slabs with generational keys
+//! - [`mod@pool`] - Free-list memory pools for fixed-size blocks
+//! - [`mod@buffer`] - Circular buffers and chunked byte buffers
+//! - [`mod@codec`] - Versioned binary snapshots for fixed-capacity containers
+//! - [`mod@sync`] - Const-constructible locks for sharing containers from `static` items
+//! - [`mod@reclaim`] - Epoch and hazard-pointer reclamation for lock-free structures (std or alloc)
+//! - [`mod@sorted_list`] - Lock-free sorted list for concurrent sets (std or alloc)
+//! - [`mod@stack`] - Fixed-size and unbounded lock-free stacks
+//! - [`mod@sorted_map`] - Fixed-size and unbounded ordered maps with range and floor/ceiling queries
+//! - [`mod@prefix`] - Fixed-size and unbounded longest-prefix-match tables for routing
+//! - [`mod@hash`] - Fixed-capacity hash map and set with open addressing, and an ordered `LinkedHashMap`
+//! - [`mod@error`] - Capacity and index errors shared by every container
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-/// Fixed-size and unlimited capacity double-linked list implementations.
+/// Fixed-size, unlimited capacity and intrusive double-linked list implementations.
 ///
-/// Provides `SizedDoubleLinkedList` for compile-time bounded lists and `DoubleLinkedList`
-/// for unlimited capacity. Supports bidirectional traversal, sorting, and selection operations.
+/// Provides `SizedDoubleLinkedList` for compile-time bounded lists, `DoubleLinkedList`
+/// for unlimited capacity and `IntrusiveList` for caller-owned nodes. Supports bidirectional
+/// traversal, sorting, and selection operations.
 pub mod double_linked_list;
 pub use double_linked_list as DoubleLinkedList;
 
@@ -33,8 +57,8 @@ pub mod array;
 /// Provides comparison and manipulation functions for `Option` types.
 pub mod option;
 
-#[cfg(not(feature = "no-std"))]
-/// Vector utilities for dynamic collections (std-only).
+#[cfg(feature = "alloc")]
+/// Vector utilities for dynamic collections (requires `std` or `alloc`).
 ///
 /// Provides helpers to merge sorted `Vec` values (`keep_lowest_vec`/`keep_lowest_vec_by`)
 /// and to convert `MaybeUninit` slices into `Vec<Option<T>>` when using `std`.
@@ -45,14 +69,86 @@ pub mod vec;
 /// Offers a bounded, lock-free deque with owner `insert`/`take` and worker `steal` operations.
 pub mod workstealing;
 
-/// Errors that can occur during linked list operations.
-#[derive(Debug)]
-pub enum LinkedListError {
-    /// The index is out of range for the current list size.
-    IndexOutOfRange,
-    /// The list has reached its maximum capacity.
-    ListIsFull,
-}
+/// Slab storage handing out generational keys.
+///
+/// Provides `SizedSlab` for compile-time bounded storage and `Slab` for growable storage.
+/// Stale keys are detected through per-slot generations.
+pub mod slab;
+
+/// Free-list memory pools handing out fixed-size blocks.
+///
+/// Offers `FixedBlockPool` for single-threaded use and `LockFreeBlockPool` for concurrent use,
+/// both returning `PoolBox` smart pointers that release their block on drop.
+pub mod pool;
+
+/// Buffer abstractions built from fixed-size storage and chunked memory blocks.
+///
+/// Provides `SizedCircularBuffer`, a fixed-capacity ring buffer with a configurable overflow
+/// policy, and `ChunkedBuffer` (std or alloc), a segmented byte buffer that grows without
+/// reallocation and releases consumed prefix chunks.
+pub mod buffer;
+
+/// Zero-dependency binary encoding for checkpointing containers.
+///
+/// Defines the `Encode`/`Decode` traits and a versioned snapshot format validated by length
+/// and checksum on load, readable from a `&[u8]` without allocation.
+pub mod codec;
+
+/// Locks adding interior mutability to containers placed in `static` items.
+///
+/// Provides `Locked`, a const-constructible wrapper over a ticket lock, a spin lock or a
+/// platform critical section, so preallocated lists and pools can be shared between threads and
+/// interrupt handlers without the standard library, plus the std-backed `MutexLocked`.
+pub mod sync;
+
+#[cfg(feature = "alloc")]
+/// Deferred destruction of nodes removed from lock-free structures (requires `std` or `alloc`).
+///
+/// Provides an epoch-based `Collector` and a hazard-pointer `HazardDomain` behind the common
+/// `Reclaim` trait, whose guards keep protected pointers valid and drop deferred values once no
+/// thread can still reach them.
+pub mod reclaim;
+
+#[cfg(feature = "alloc")]
+/// Concurrent sorted lists (requires `std` or `alloc`).
+///
+/// Provides `LockFreeSortedList`, a Harris-Michael ordered set with lock-free updates and
+/// weakly consistent iteration, generic over the `reclaim` scheme of its unlinked nodes.
+pub mod sorted_list;
+
+/// Treiber stacks shared between threads without locking.
+///
+/// Offers `SizedLockFreeStack`, a fixed-capacity stack protected against ABA by tagged indices,
+/// and `LockFreeStack` (std or alloc), an unbounded stack reclaiming nodes through `reclaim`.
+pub mod stack;
+
+/// Maps keeping their entries sorted by key.
+///
+/// Offers `SizedSortedMap`, a fixed-capacity map backed by sorted arrays, and `SortedMap`
+/// (std or alloc), an unbounded map of sorted leaves, both answering range, floor and ceiling
+/// queries.
+pub mod sorted_map;
+
+/// Longest-prefix-match tables built on Patricia tries.
+///
+/// Offers `SizedPrefixTable`, storing its nodes in a fixed array of slots, and `PrefixTable`
+/// (std or alloc), with heap-allocated nodes, both keyed by integer or IP address prefixes.
+pub mod prefix;
+
+/// Fixed-capacity hash containers with Robin Hood open addressing.
+///
+/// Provides `SizedHashMap` and `SizedHashSet`, storing up to `N` entries inline with a pluggable
+/// `BuildHasher` and the built-in `FnvHasher`, so keyed lookups need no linear scan. With the
+/// `std` feature, `LinkedHashMap` also keeps its entries in insertion or access order.
+pub mod hash;
+
+/// Error types shared by every container.
+///
+/// Defines `CapacityError`, handing back values rejected by a full container, `IndexError`,
+/// reporting out-of-range indices with the container length, and `LinkedListError` combining both.
+pub mod error;
+
+pub use error::{CapacityError, IndexError, LinkedListError};
 
 /// Const generic wrapper for compile-time integer constants.
 ///
@@ -0,0 +1,262 @@
+//! Single-threaded free-list pool for fixed-size blocks.
+//!
+//! This module provides a memory pool that carves an inline buffer of `COUNT` blocks of
+//! `BLOCK` bytes each. Free blocks are chained through an embedded free list: the index of
+//! the next free block is stored in the first bytes of each free block, so the pool needs no
+//! bookkeeping memory besides the buffer itself.
+//!
+//! # Overview
+//!
+//! Blocks that were never handed out are allocated by bumping a watermark, which keeps
+//! construction O(1). Freed blocks are pushed on the free list and reused first.
+//! Both `alloc` and `free` are O(1).
+//!
+//! Every block is aligned to [`BLOCK_ALIGN`] bytes. Storing a type larger than `BLOCK` or
+//! more aligned than `BLOCK_ALIGN` is rejected at compile time.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::pool::FixedBlockPool;
+//!
+//! let pool: FixedBlockPool<64, 8> = FixedBlockPool::new();
+//!
+//! let frame = pool.alloc([0u8; 48]).unwrap();
+//! assert_eq!(pool.available(), 7);
+//!
+//! drop(frame);
+//! assert_eq!(pool.available(), 8);
+//! ```
+
+use crate::CapacityError;
+
+use core::cell::{Cell, UnsafeCell};
+use core::fmt;
+use core::mem::{ManuallyDrop, MaybeUninit, align_of, size_of};
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+/// Alignment, in bytes, of every block handed out by the pools.
+pub const BLOCK_ALIGN: usize = 16;
+
+/// A single block of raw storage.
+#[repr(C, align(16))]
+pub(crate) struct Block<const BLOCK: usize>([MaybeUninit<u8>; BLOCK]);
+
+impl<const BLOCK: usize> Block<BLOCK> {
+    pub(crate) const fn uninit() -> Self {
+        Self([MaybeUninit::uninit(); BLOCK])
+    }
+}
+
+/// Checks at compile time that `T` fits into a block of `BLOCK` bytes.
+pub(crate) const fn assert_fits<T, const BLOCK: usize>() {
+    assert!(
+        size_of::<T>() <= BLOCK,
+        "type does not fit into a pool block"
+    );
+    assert!(
+        align_of::<T>() <= BLOCK_ALIGN,
+        "type is over-aligned for a pool block"
+    );
+}
+
+/// Pools able to take back the blocks of a [`PoolBox`].
+///
+/// # Safety
+///
+/// `release` must only be called once per allocated block, after its value was dropped.
+pub unsafe trait BlockPool {
+    /// Returns the block at `index` to the pool.
+    ///
+    /// # Safety
+    ///
+    /// The block must have been handed out by this pool and must not be used afterwards.
+    unsafe fn release(&self, index: usize);
+}
+
+/// Owning pointer to a value stored in a pool block.
+///
+/// Dropping a `PoolBox` drops the value and returns its block to the pool.
+pub struct PoolBox<'a, T, P: BlockPool> {
+    ptr: NonNull<T>,
+    index: usize,
+    pool: &'a P,
+}
+
+impl<'a, T, P: BlockPool> PoolBox<'a, T, P> {
+    /// Wraps a freshly written block.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to an initialized `T` stored in block `index` of `pool`.
+    pub(crate) unsafe fn from_block(ptr: NonNull<T>, index: usize, pool: &'a P) -> Self {
+        Self { ptr, index, pool }
+    }
+
+    /// Returns the index of the block holding the value.
+    #[inline]
+    pub fn block_index(&self) -> usize {
+        self.index
+    }
+
+    /// Moves the value out of the pool and releases its block.
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+        let value = unsafe { this.ptr.as_ptr().read() };
+
+        unsafe { this.pool.release(this.index) };
+
+        value
+    }
+}
+
+impl<'a, T, P: BlockPool> Deref for PoolBox<'a, T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<'a, T, P: BlockPool> DerefMut for PoolBox<'a, T, P> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<'a, T, P: BlockPool> Drop for PoolBox<'a, T, P> {
+    fn drop(&mut self) {
+        unsafe {
+            self.ptr.as_ptr().drop_in_place();
+            self.pool.release(self.index);
+        }
+    }
+}
+
+impl<'a, T: fmt::Debug, P: BlockPool> fmt::Debug for PoolBox<'a, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+unsafe impl<'a, T: Send, P: BlockPool + Sync> Send for PoolBox<'a, T, P> {}
+unsafe impl<'a, T: Sync, P: BlockPool + Sync> Sync for PoolBox<'a, T, P> {}
+
+/// A single-threaded pool of `COUNT` blocks of `BLOCK` bytes.
+///
+/// # Type Parameters
+///
+/// - `BLOCK`: Size of each block in bytes (at least `size_of::<usize>()`)
+/// - `COUNT`: Number of blocks in the pool
+///
+/// # Fields
+///
+/// - `blocks`: Inline block storage
+/// - `free`: Index of the first block of the free list (`COUNT` when empty)
+/// - `watermark`: Number of blocks that have been handed out at least once
+/// - `in_use`: Number of blocks currently allocated
+pub struct FixedBlockPool<const BLOCK: usize, const COUNT: usize> {
+    blocks: UnsafeCell<[Block<BLOCK>; COUNT]>,
+    free: Cell<usize>,
+    watermark: Cell<usize>,
+    in_use: Cell<usize>,
+}
+
+impl<const BLOCK: usize, const COUNT: usize> Default for FixedBlockPool<BLOCK, COUNT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BLOCK: usize, const COUNT: usize> FixedBlockPool<BLOCK, COUNT> {
+    /// Creates a pool with every block free.
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                BLOCK >= size_of::<usize>(),
+                "blocks must be able to hold a free-list link"
+            )
+        };
+
+        Self {
+            blocks: UnsafeCell::new([const { Block::uninit() }; COUNT]),
+            free: Cell::new(COUNT),
+            watermark: Cell::new(0),
+            in_use: Cell::new(0),
+        }
+    }
+
+    /// Returns the total number of blocks.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        COUNT
+    }
+
+    /// Returns the number of blocks that can still be allocated.
+    #[inline]
+    pub fn available(&self) -> usize {
+        COUNT - self.in_use.get()
+    }
+
+    /// Returns `true` if every block is in use.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.in_use.get() == COUNT
+    }
+
+    /// Returns a pointer to the first byte of the block at `index`.
+    #[inline]
+    fn block_ptr(&self, index: usize) -> *mut u8 {
+        unsafe { (self.blocks.get() as *mut Block<BLOCK>).add(index) as *mut u8 }
+    }
+
+    /// Takes a block from the free list, or from the untouched blocks.
+    fn take_block(&self) -> Option<usize> {
+        let head = self.free.get();
+
+        if head != COUNT {
+            let next = unsafe { (self.block_ptr(head) as *const usize).read() };
+            self.free.set(next);
+
+            return Some(head);
+        }
+
+        let watermark = self.watermark.get();
+        if watermark == COUNT {
+            return None;
+        }
+
+        self.watermark.set(watermark + 1);
+
+        Some(watermark)
+    }
+
+    /// Moves `value` into a free block.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if every block is in use.
+    pub fn alloc<T>(&self, value: T) -> Result<PoolBox<'_, T, Self>, CapacityError<T>> {
+        const { assert_fits::<T, BLOCK>() };
+
+        let Some(index) = self.take_block() else {
+            return Err(CapacityError::new(value));
+        };
+        let ptr = self.block_ptr(index) as *mut T;
+
+        unsafe { ptr.write(value) };
+        self.in_use.set(self.in_use.get() + 1);
+
+        Ok(unsafe { PoolBox::from_block(NonNull::new_unchecked(ptr), index, self) })
+    }
+}
+
+unsafe impl<const BLOCK: usize, const COUNT: usize> BlockPool for FixedBlockPool<BLOCK, COUNT> {
+    unsafe fn release(&self, index: usize) {
+        unsafe { (self.block_ptr(index) as *mut usize).write(self.free.get()) };
+
+        self.free.set(index);
+        self.in_use.set(self.in_use.get() - 1);
+    }
+}
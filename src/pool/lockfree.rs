@@ -0,0 +1,190 @@
+//! Lock-free free-list pool for fixed-size blocks.
+//!
+//! This module provides the thread-safe counterpart of
+//! [`FixedBlockPool`](super::fixed::FixedBlockPool). The free list is a Treiber stack whose
+//! head is packed with a modification tag into a single `AtomicU64`, following the packed
+//! state of [`SizedWorkStealingPool`](crate::workstealing::SizedWorkStealingPool).
+//!
+//! # Overview
+//!
+//! The upper 32 bits of the head hold a tag incremented on every successful update and the
+//! lower 32 bits hold the index of the first free block. A thread that read a head, got
+//! preempted while the same block was popped and pushed back, then retries its CAS sees a
+//! different tag and fails instead of corrupting the list (ABA protection).
+//!
+//! Free-list links are kept in a side array of atomics rather than inside the blocks, so a
+//! stale read of a link never races with the value written into a freshly allocated block.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::pool::LockFreeBlockPool;
+//!
+//! static POOL: LockFreeBlockPool<64, 32> = LockFreeBlockPool::new();
+//!
+//! let packet = POOL.alloc([0u8; 64]).unwrap();
+//! std::thread::spawn(move || drop(packet));
+//! ```
+
+use super::fixed::{Block, BlockPool, PoolBox, assert_fits};
+use crate::CapacityError;
+
+use core::cell::UnsafeCell;
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+
+/// Index marking the end of the free list.
+const NIL: u32 = u32::MAX;
+
+const fn pack(tag: u32, index: u32) -> u64 {
+    ((tag as u64) << 32) | (index as u64)
+}
+
+fn unpack(value: u64) -> (u32, u32) {
+    let tag = (value >> 32) as u32;
+    let index = (value & 0xFFFF_FFFF) as u32;
+
+    (tag, index)
+}
+
+/// A lock-free pool of `COUNT` blocks of `BLOCK` bytes.
+///
+/// # Type Parameters
+///
+/// - `BLOCK`: Size of each block in bytes
+/// - `COUNT`: Number of blocks in the pool (less than `u32::MAX`)
+///
+/// # Fields
+///
+/// - `blocks`: Inline block storage
+/// - `next`: Free-list link of each block
+/// - `head`: Packed tag and index of the first free block
+/// - `watermark`: Number of blocks that have been handed out at least once
+/// - `in_use`: Number of blocks currently allocated
+pub struct LockFreeBlockPool<const BLOCK: usize, const COUNT: usize> {
+    blocks: UnsafeCell<[Block<BLOCK>; COUNT]>,
+    next: [AtomicU32; COUNT],
+    head: AtomicU64,
+    watermark: AtomicUsize,
+    in_use: AtomicUsize,
+}
+
+// SAFETY: a block is only ever accessed by the `PoolBox` that owns it, and the free list is
+// manipulated exclusively through atomics.
+unsafe impl<const BLOCK: usize, const COUNT: usize> Sync for LockFreeBlockPool<BLOCK, COUNT> {}
+
+impl<const BLOCK: usize, const COUNT: usize> Default for LockFreeBlockPool<BLOCK, COUNT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BLOCK: usize, const COUNT: usize> LockFreeBlockPool<BLOCK, COUNT> {
+    /// Creates a pool with every block free.
+    pub const fn new() -> Self {
+        const { assert!(COUNT < NIL as usize, "too many blocks for 32-bit indices") };
+
+        Self {
+            blocks: UnsafeCell::new([const { Block::uninit() }; COUNT]),
+            next: [const { AtomicU32::new(NIL) }; COUNT],
+            head: AtomicU64::new(pack(0, NIL)),
+            watermark: AtomicUsize::new(0),
+            in_use: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the total number of blocks.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        COUNT
+    }
+
+    /// Returns the number of blocks that can still be allocated.
+    ///
+    /// The value is a snapshot and may be outdated as soon as it is returned.
+    #[inline]
+    pub fn available(&self) -> usize {
+        COUNT - self.in_use.load(Ordering::Acquire)
+    }
+
+    /// Returns a pointer to the first byte of the block at `index`.
+    #[inline]
+    fn block_ptr(&self, index: usize) -> *mut u8 {
+        unsafe { (self.blocks.get() as *mut Block<BLOCK>).add(index) as *mut u8 }
+    }
+
+    /// Pops a block from the free list, or bumps the watermark.
+    fn take_block(&self) -> Option<usize> {
+        loop {
+            let state_old = self.head.load(Ordering::Acquire);
+            let (tag, index) = unpack(state_old);
+
+            if index == NIL {
+                break;
+            }
+
+            let next = self.next[index as usize].load(Ordering::Acquire);
+            let state_new = pack(tag.wrapping_add(1), next);
+
+            match self.head.compare_exchange(
+                state_old,
+                state_new,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return Some(index as usize),
+                Err(_) => continue,
+            }
+        }
+
+        self.watermark
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |w| {
+                (w < COUNT).then_some(w + 1)
+            })
+            .ok()
+    }
+
+    /// Moves `value` into a free block.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if every block is in use.
+    pub fn alloc<T>(&self, value: T) -> Result<PoolBox<'_, T, Self>, CapacityError<T>> {
+        const { assert_fits::<T, BLOCK>() };
+
+        let Some(index) = self.take_block() else {
+            return Err(CapacityError::new(value));
+        };
+        let ptr = self.block_ptr(index) as *mut T;
+
+        unsafe { ptr.write(value) };
+        self.in_use.fetch_add(1, Ordering::AcqRel);
+
+        Ok(unsafe { PoolBox::from_block(NonNull::new_unchecked(ptr), index, self) })
+    }
+}
+
+unsafe impl<const BLOCK: usize, const COUNT: usize> BlockPool for LockFreeBlockPool<BLOCK, COUNT> {
+    unsafe fn release(&self, index: usize) {
+        // Decrement before publishing the block so `in_use` never exceeds `COUNT`.
+        self.in_use.fetch_sub(1, Ordering::AcqRel);
+
+        loop {
+            let state_old = self.head.load(Ordering::Acquire);
+            let (tag, head) = unpack(state_old);
+
+            self.next[index].store(head, Ordering::Release);
+            let state_new = pack(tag.wrapping_add(1), index as u32);
+
+            match self.head.compare_exchange(
+                state_old,
+                state_new,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(_) => continue,
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Free-list memory pools for fixed-size blocks.
+//!
+//! Provides both a single-threaded and a lock-free pool carving a static buffer into blocks.
+//! - `fixed`: Single-threaded pool with an embedded free list
+//! - `lockfree`: Thread-safe pool using a tagged-index CAS for ABA protection
+//!
+//! Allocations are handed out as [`PoolBox`] smart pointers that drop their value and return
+//! the block to the pool when they go out of scope.
+
+pub mod fixed;
+pub mod lockfree;
+
+pub use fixed::{BlockPool, FixedBlockPool, PoolBox};
+pub use lockfree::LockFreeBlockPool;
@@ -0,0 +1,121 @@
+//! Addresses matched bit by bit against prefixes.
+//!
+//! This module provides the [`Address`] trait, implemented for the unsigned integers and the
+//! IPv4 and IPv6 addresses of `core::net`, along with the bit helpers shared by the prefix
+//! tables.
+//!
+//! # Overview
+//!
+//! Every address is widened to a `u128` with its most significant bit first (left-aligned),
+//! so a prefix of length `len` is the top `len` bits of that value whatever the address
+//! width: `10.0.0.0/8` and `2001:db8::/32` are compared the same way.
+
+use core::fmt;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+/// An address made of up to 128 bits, matched most significant bit first.
+pub trait Address: Copy {
+    /// Width of the address in bits, at most 128.
+    const BITS: u8;
+
+    /// Returns the bits of the address, left-aligned in a `u128`.
+    fn to_left_aligned(self) -> u128;
+
+    /// Builds an address from bits left-aligned in a `u128`, ignoring the bits past `BITS`.
+    fn from_left_aligned(bits: u128) -> Self;
+}
+
+macro_rules! impl_address {
+    ($($ty:ty),*) => {
+        $(
+            impl Address for $ty {
+                const BITS: u8 = <$ty>::BITS as u8;
+
+                #[inline]
+                fn to_left_aligned(self) -> u128 {
+                    (self as u128) << (128 - Self::BITS)
+                }
+
+                #[inline]
+                fn from_left_aligned(bits: u128) -> Self {
+                    (bits >> (128 - Self::BITS)) as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_address!(u8, u16, u32, u64, u128);
+
+impl Address for Ipv4Addr {
+    const BITS: u8 = 32;
+
+    #[inline]
+    fn to_left_aligned(self) -> u128 {
+        u32::from(self).to_left_aligned()
+    }
+
+    #[inline]
+    fn from_left_aligned(bits: u128) -> Self {
+        Ipv4Addr::from(u32::from_left_aligned(bits))
+    }
+}
+
+impl Address for Ipv6Addr {
+    const BITS: u8 = 128;
+
+    #[inline]
+    fn to_left_aligned(self) -> u128 {
+        u128::from(self)
+    }
+
+    #[inline]
+    fn from_left_aligned(bits: u128) -> Self {
+        Ipv6Addr::from(bits)
+    }
+}
+
+/// Returns the left-aligned bits of `prefix` cut to its first `len` bits.
+///
+/// # Panics
+///
+/// Panics if `len` exceeds `A::BITS`.
+pub(crate) fn prefix_bits<A: Address>(prefix: A, len: u8) -> u128 {
+    assert!(len <= A::BITS, "prefix length exceeds the address width");
+
+    prefix.to_left_aligned() & mask(len)
+}
+
+/// Returns a mask keeping the first `len` bits.
+#[inline]
+pub(crate) fn mask(len: u8) -> u128 {
+    u128::MAX.checked_shl(128 - len as u32).unwrap_or(0)
+}
+
+/// Returns bit `index` of `bits`, counted from the most significant one. `index` must be
+/// lower than 128.
+#[inline]
+pub(crate) fn bit(bits: u128, index: u8) -> usize {
+    ((bits >> (127 - index)) & 1) as usize
+}
+
+/// Returns the length of the common prefix of `a` and `b`, capped at `max`.
+#[inline]
+pub(crate) fn common_len(a: u128, b: u128, max: u8) -> u8 {
+    ((a ^ b).leading_zeros() as u8).min(max)
+}
+
+/// Returns `true` if the first `len` bits of `a` and `b` are equal.
+#[inline]
+pub(crate) fn matches(a: u128, b: u128, len: u8) -> bool {
+    (a ^ b) & mask(len) == 0
+}
+
+/// Formats a prefix as `address/len`.
+pub(crate) struct Prefix<A>(pub(crate) A, pub(crate) u8);
+
+impl<A: fmt::Debug> fmt::Debug for Prefix<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}/{}", self.0, self.1)
+    }
+}
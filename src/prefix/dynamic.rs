@@ -0,0 +1,459 @@
+//! Unbounded longest-prefix-match table with heap-allocated nodes.
+//!
+//! This module provides [`PrefixTable`], mapping bit prefixes such as `10.0.0.0/8` to values
+//! and finding the most specific prefix covering an address.
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+//!
+//! # Overview
+//!
+//! The table is a Patricia trie: a binary trie over the address bits where chains of nodes
+//! with a single child are compressed into one node holding the whole run of bits. Each node
+//! stores a prefix and the children extend it, branching on the first bit past it:
+//! - Nodes holding a value stand for an inserted prefix.
+//! - Nodes without value only join two subtries diverging at their length, and are removed
+//!   as soon as they lose a child.
+//!
+//! A lookup follows the bits of the address from the root, remembering the last prefix
+//! holding a value, so [`longest_match`](PrefixTable::longest_match) visits at most one node
+//! per distinct prefix length on the path, whatever the number of prefixes.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use core::net::Ipv4Addr;
+//! use datastructures::prefix::PrefixTable;
+//!
+//! let mut routes: PrefixTable<&str, Ipv4Addr> = PrefixTable::new();
+//! routes.insert(Ipv4Addr::new(0, 0, 0, 0), 0, "default");
+//! routes.insert(Ipv4Addr::new(10, 0, 0, 0), 8, "lan");
+//! routes.insert(Ipv4Addr::new(10, 1, 0, 0), 16, "lab");
+//!
+//! let (prefix, len, route) = routes.longest_match(Ipv4Addr::new(10, 1, 2, 3)).unwrap();
+//! assert_eq!((prefix, len, *route), (Ipv4Addr::new(10, 1, 0, 0), 16, "lab"));
+//! assert_eq!(routes.longest_match(Ipv4Addr::new(192, 168, 0, 1)).unwrap().2, &"default");
+//! ```
+
+use super::address::{self, Address, Prefix};
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// A node of the trie.
+///
+/// # Fields
+///
+/// - `bits`: Left-aligned prefix bits, zero past `len`
+/// - `len`: Length of the prefix in bits
+/// - `value`: Value of the prefix, `None` for a node joining two subtries
+/// - `children`: Subtries whose next bit after the prefix is `0` and `1`
+#[derive(Clone)]
+struct Node<V> {
+    bits: u128,
+    len: u8,
+    value: Option<V>,
+    children: [Option<Box<Node<V>>>; 2],
+}
+
+impl<V> Node<V> {
+    fn new(bits: u128, len: u8, value: Option<V>) -> Box<Self> {
+        Box::new(Self {
+            bits,
+            len,
+            value,
+            children: [None, None],
+        })
+    }
+}
+
+/// An unbounded table mapping bit prefixes to values, answering longest-prefix-match lookups.
+///
+/// # Type Parameters
+///
+/// - `V`: The type of values
+/// - `A`: The type of addresses the prefixes are made of, `u32` by default
+///
+/// # Fields
+///
+/// - `root`: Root of the trie, `None` when empty
+/// - `len`: Number of prefixes holding a value
+pub struct PrefixTable<V, A = u32> {
+    root: Option<Box<Node<V>>>,
+    len: usize,
+    _address: PhantomData<A>,
+}
+
+impl<V, A> Default for PrefixTable<V, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Clone, A> Clone for PrefixTable<V, A> {
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            len: self.len,
+            _address: PhantomData,
+        }
+    }
+}
+
+impl<V: fmt::Debug, A: Address + fmt::Debug> fmt::Debug for PrefixTable<V, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.iter()
+                    .map(|(prefix, len, value)| (Prefix(prefix, len), value)),
+            )
+            .finish()
+    }
+}
+
+impl<V, A: Address> FromIterator<(A, u8, V)> for PrefixTable<V, A> {
+    fn from_iter<I: IntoIterator<Item = (A, u8, V)>>(iter: I) -> Self {
+        let mut table = Self::new();
+        table.extend(iter);
+        table
+    }
+}
+
+impl<V, A: Address> Extend<(A, u8, V)> for PrefixTable<V, A> {
+    fn extend<I: IntoIterator<Item = (A, u8, V)>>(&mut self, iter: I) {
+        for (prefix, len, value) in iter {
+            self.insert(prefix, len, value);
+        }
+    }
+}
+
+impl<'a, V, A: Address> IntoIterator for &'a PrefixTable<V, A> {
+    type Item = (A, u8, &'a V);
+    type IntoIter = Iter<'a, V, A>;
+
+    fn into_iter(self) -> Iter<'a, V, A> {
+        self.iter()
+    }
+}
+
+impl<V, A> PrefixTable<V, A> {
+    /// Creates an empty table.
+    pub const fn new() -> Self {
+        Self {
+            root: None,
+            len: 0,
+            _address: PhantomData,
+        }
+    }
+
+    /// Returns the number of prefixes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the table contains no prefix.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes every prefix.
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.len = 0;
+    }
+}
+
+impl<V, A: Address> PrefixTable<V, A> {
+    /// Returns the node holding exactly `bits` and `len`, with or without value.
+    fn find(&self, bits: u128, len: u8) -> Option<&Node<V>> {
+        let mut current = self.root.as_deref();
+
+        while let Some(node) = current {
+            if node.len > len || !address::matches(node.bits, bits, node.len) {
+                return None;
+            }
+            if node.len == len {
+                return Some(node);
+            }
+
+            current = node.children[address::bit(bits, node.len)].as_deref();
+        }
+
+        None
+    }
+
+    /// Inserts a value for the first `len` bits of `prefix`, returning the previous value of
+    /// the prefix if present.
+    ///
+    /// Bits of `prefix` past `len` are ignored, so `10.1.2.3/8` stands for `10.0.0.0/8`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn insert(&mut self, prefix: A, len: u8, value: V) -> Option<V> {
+        let bits = address::prefix_bits(prefix, len);
+        let mut slot = &mut self.root;
+
+        loop {
+            let Some(node) = slot.as_deref() else {
+                *slot = Some(Node::new(bits, len, Some(value)));
+                self.len += 1;
+                return None;
+            };
+
+            let node_len = node.len;
+            let common = address::common_len(node.bits, bits, node_len.min(len));
+
+            if common < node_len {
+                // The prefix diverges from the node, or stops within it: a new node takes
+                // its place, either the prefix itself or a node joining both.
+                let below_side = address::bit(node.bits, common);
+                let below = slot.take();
+                let mut above = if common == len {
+                    Node::new(bits, len, Some(value))
+                } else {
+                    let mut join = Node::new(bits & address::mask(common), common, None);
+                    join.children[address::bit(bits, common)] =
+                        Some(Node::new(bits, len, Some(value)));
+                    join
+                };
+
+                above.children[below_side] = below;
+                *slot = Some(above);
+                self.len += 1;
+
+                return None;
+            }
+
+            let node = slot.as_mut().unwrap();
+
+            if node_len == len {
+                let previous = node.value.replace(value);
+                self.len += usize::from(previous.is_none());
+
+                return previous;
+            }
+
+            slot = &mut node.children[address::bit(bits, node_len)];
+        }
+    }
+
+    /// Returns a reference to the value of exactly the first `len` bits of `prefix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn get(&self, prefix: A, len: u8) -> Option<&V> {
+        let bits = address::prefix_bits(prefix, len);
+        self.find(bits, len)?.value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value of exactly the first `len` bits of `prefix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn get_mut(&mut self, prefix: A, len: u8) -> Option<&mut V> {
+        let bits = address::prefix_bits(prefix, len);
+        let mut current = self.root.as_deref_mut();
+
+        while let Some(node) = current {
+            if node.len > len || !address::matches(node.bits, bits, node.len) {
+                return None;
+            }
+            if node.len == len {
+                return node.value.as_mut();
+            }
+
+            current = node.children[address::bit(bits, node.len)].as_deref_mut();
+        }
+
+        None
+    }
+
+    /// Returns `true` if the table holds a value for exactly the first `len` bits of `prefix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn contains(&self, prefix: A, len: u8) -> bool {
+        self.get(prefix, len).is_some()
+    }
+
+    /// Removes exactly the first `len` bits of `prefix` and returns its value, if present.
+    ///
+    /// More specific prefixes are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn remove(&mut self, prefix: A, len: u8) -> Option<V> {
+        let bits = address::prefix_bits(prefix, len);
+        let value = Self::remove_from(&mut self.root, bits, len)?;
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    /// Removes the value of `bits` and `len` from the subtrie in `slot`, then drops or
+    /// bypasses the nodes left without value and with fewer than two children.
+    fn remove_from(slot: &mut Option<Box<Node<V>>>, bits: u128, len: u8) -> Option<V> {
+        let node = slot.as_mut()?;
+
+        if node.len > len || !address::matches(node.bits, bits, node.len) {
+            return None;
+        }
+
+        let value = if node.len == len {
+            node.value.take()?
+        } else {
+            let child = &mut node.children[address::bit(bits, node.len)];
+            Self::remove_from(child, bits, len)?
+        };
+
+        if node.value.is_none() {
+            match &mut node.children {
+                [Some(_), Some(_)] => {}
+                [child, None] | [None, child] => *slot = child.take(),
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Returns the longest prefix covering `addr`, with its length and value.
+    pub fn longest_match(&self, addr: A) -> Option<(A, u8, &V)> {
+        let bits = addr.to_left_aligned();
+        let mut current = self.root.as_deref();
+        let mut best = None;
+
+        while let Some(node) = current {
+            if !address::matches(node.bits, bits, node.len) {
+                break;
+            }
+            if let Some(value) = &node.value {
+                best = Some((node, value));
+            }
+            if node.len == 128 {
+                break;
+            }
+
+            current = node.children[address::bit(bits, node.len)].as_deref();
+        }
+
+        best.map(|(node, value)| (A::from_left_aligned(node.bits), node.len, value))
+    }
+
+    /// Returns an iterator over the prefixes and their values in prefix order: by address,
+    /// a prefix coming before the longer prefixes it covers.
+    pub fn iter(&self) -> Iter<'_, V, A> {
+        Iter {
+            stack: self.root.as_deref().into_iter().collect(),
+            remaining: self.len,
+            _address: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the prefixes of a [`PrefixTable`], in prefix order.
+///
+/// # Fields
+///
+/// - `stack`: Roots of the subtries not walked yet, the next one on top
+/// - `remaining`: Number of prefixes not yielded yet
+pub struct Iter<'a, V, A> {
+    stack: Vec<&'a Node<V>>,
+    remaining: usize,
+    _address: PhantomData<A>,
+}
+
+impl<'a, V, A: Address> Iterator for Iter<'a, V, A> {
+    type Item = (A, u8, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            self.stack.extend(
+                node.children
+                    .iter()
+                    .rev()
+                    .filter_map(|child| child.as_deref()),
+            );
+
+            if let Some(value) = &node.value {
+                self.remaining -= 1;
+                return Some((A::from_left_aligned(node.bits), node.len, value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<V, A: Address> ExactSizeIterator for Iter<'_, V, A> {}
+
+impl<V, A: Address> FusedIterator for Iter<'_, V, A> {}
+
+/// Serializes the table as a sequence of `(prefix, len, value)` tuples, in prefix order.
+#[cfg(feature = "serde")]
+impl<V: serde::Serialize, A: Address + serde::Serialize> serde::Serialize for PrefixTable<V, A> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes a sequence of `(prefix, len, value)` tuples, inserting them one by one.
+///
+/// A prefix length exceeding the address width is rejected with an `invalid_value` error.
+#[cfg(feature = "serde")]
+impl<'de, V, A> serde::Deserialize<'de> for PrefixTable<V, A>
+where
+    V: serde::Deserialize<'de>,
+    A: Address + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, SeqAccess, Unexpected, Visitor};
+
+        struct TableVisitor<V, A>(PhantomData<(V, A)>);
+
+        impl<'de, V, A> Visitor<'de> for TableVisitor<V, A>
+        where
+            V: serde::Deserialize<'de>,
+            A: Address + serde::Deserialize<'de>,
+        {
+            type Value = PrefixTable<V, A>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence of prefixes")
+            }
+
+            fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+                let mut table = PrefixTable::new();
+
+                while let Some((prefix, len, value)) = seq.next_element::<(A, u8, V)>()? {
+                    if len > A::BITS {
+                        return Err(S::Error::invalid_value(
+                            Unexpected::Unsigned(len.into()),
+                            &"a prefix length within the address width",
+                        ));
+                    }
+
+                    table.insert(prefix, len, value);
+                }
+
+                Ok(table)
+            }
+        }
+
+        deserializer.deserialize_seq(TableVisitor(PhantomData))
+    }
+}
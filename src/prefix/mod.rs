@@ -0,0 +1,21 @@
+//! Longest-prefix-match tables.
+//!
+//! Provides both fixed-size and dynamic Patricia tries mapping bit prefixes to values.
+//! - `address`: `Address` trait for the integers and the `core::net` IP addresses
+//! - `sized`: Fixed-size table storing its nodes in an array of slots (no allocation)
+//! - `dynamic`: Unbounded table with heap-allocated nodes (std or alloc)
+//!
+//! Both tables offer `insert`, `remove`, exact `get` and `longest_match` lookups, and iterate in
+//! prefix order, for instance to hold IPv4 or IPv6 routing tables.
+
+pub mod address;
+pub mod sized;
+
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+
+pub use address::Address;
+pub use sized::SizedPrefixTable;
+
+#[cfg(feature = "alloc")]
+pub use dynamic::PrefixTable;
@@ -0,0 +1,597 @@
+//! Fixed-size longest-prefix-match table.
+//!
+//! This module provides [`SizedPrefixTable`], the allocation-free counterpart of
+//! [`PrefixTable`](super::dynamic::PrefixTable), for routing tables on targets without a heap.
+//! Valid capacities range from 0 to 63, enforced via the `ValidK` trait.
+//!
+//! # Overview
+//!
+//! The table is the same Patricia trie as `PrefixTable`, with its nodes stored in an array of
+//! slots and linked by index. Free slots are tracked through a bitmask (`used`), reusing the
+//! slot-allocation strategy of
+//! [`SizedDoubleLinkedList`](crate::double_linked_list::SizedDoubleLinkedList).
+//!
+//! The capacity counts nodes rather than prefixes: a prefix diverging from the others needs a
+//! second node joining both branches, so `n` prefixes take between `n` and `2n - 1` slots.
+//! Each node also links to its parent, so the table is walked in prefix order without a stack.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::prefix::SizedPrefixTable;
+//!
+//! let mut routes: SizedPrefixTable<u8, 16> = SizedPrefixTable::new();
+//! routes.insert(0x0a00_0000, 8, 1).unwrap();
+//! routes.insert(0x0a01_0000, 16, 2).unwrap();
+//!
+//! assert_eq!(routes.longest_match(0x0a01_0203), Some((0x0a01_0000, 16, &2)));
+//! assert_eq!(routes.longest_match(0x0a02_0203), Some((0x0a00_0000, 8, &1)));
+//! assert_eq!(routes.longest_match(0x0b00_0000), None);
+//! ```
+
+use super::address::{self, Address, Prefix};
+use crate::double_linked_list::sized::ValidK;
+use crate::{CapacityError, Const};
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+/// A node of the trie.
+///
+/// # Fields
+///
+/// - `bits`: Left-aligned prefix bits, zero past `len`
+/// - `len`: Length of the prefix in bits
+/// - `value`: Value of the prefix, `None` for a node joining two subtries
+/// - `parent`: Slot of the parent node, `None` for the root
+/// - `children`: Slots of the subtries whose next bit after the prefix is `0` and `1`
+struct Node<V> {
+    bits: u128,
+    len: u8,
+    value: Option<V>,
+    parent: Option<usize>,
+    children: [Option<usize>; 2],
+}
+
+/// A fixed-size table mapping bit prefixes to values, answering longest-prefix-match lookups.
+///
+/// # Type Parameters
+///
+/// - `V`: The type of values
+/// - `N`: Compile-time capacity in nodes (0-63), enforced via the `ValidK` trait
+/// - `A`: The type of addresses the prefixes are made of, `u32` by default
+///
+/// # Fields
+///
+/// - `nodes`: Array of uninitialized node slots
+/// - `used`: Bitmask indicating which slots contain valid nodes
+/// - `len`: Number of prefixes holding a value
+/// - `root`: Slot of the root node (if non-empty)
+pub struct SizedPrefixTable<V, const N: usize, A = u32>
+where
+    Const<N>: ValidK,
+{
+    nodes: [MaybeUninit<Node<V>>; N],
+    used: u64,
+    len: usize,
+    root: Option<usize>,
+    _address: PhantomData<A>,
+}
+
+impl<V, const N: usize, A> Default for SizedPrefixTable<V, N, A>
+where
+    Const<N>: ValidK,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, const N: usize, A> Drop for SizedPrefixTable<V, N, A>
+where
+    Const<N>: ValidK,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<V: Clone, const N: usize, A> Clone for SizedPrefixTable<V, N, A>
+where
+    Const<N>: ValidK,
+{
+    fn clone(&self) -> Self {
+        let mut table = Self::new();
+
+        // Same slots, same links: nodes are copied slot by slot.
+        for index in 0..N {
+            if self.used & (1 << index) != 0 {
+                let node = self.node(index);
+                table.nodes[index].write(Node {
+                    bits: node.bits,
+                    len: node.len,
+                    value: node.value.clone(),
+                    parent: node.parent,
+                    children: node.children,
+                });
+                table.add_used(index);
+            }
+        }
+
+        table.len = self.len;
+        table.root = self.root;
+
+        table
+    }
+}
+
+impl<V: fmt::Debug, const N: usize, A: Address + fmt::Debug> fmt::Debug
+    for SizedPrefixTable<V, N, A>
+where
+    Const<N>: ValidK,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.iter()
+                    .map(|(prefix, len, value)| (Prefix(prefix, len), value)),
+            )
+            .finish()
+    }
+}
+
+impl<'a, V, const N: usize, A: Address> IntoIterator for &'a SizedPrefixTable<V, N, A>
+where
+    Const<N>: ValidK,
+{
+    type Item = (A, u8, &'a V);
+    type IntoIter = Iter<'a, V, N, A>;
+
+    fn into_iter(self) -> Iter<'a, V, N, A> {
+        self.iter()
+    }
+}
+
+impl<V, const N: usize, A> SizedPrefixTable<V, N, A>
+where
+    Const<N>: ValidK,
+{
+    /// Creates an empty table with room for `N` nodes.
+    pub const fn new() -> Self {
+        Self {
+            nodes: [const { MaybeUninit::uninit() }; N],
+            used: 0,
+            len: 0,
+            root: None,
+            _address: PhantomData,
+        }
+    }
+
+    /// Returns the number of prefixes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the table contains no prefix.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of nodes the table can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of nodes in use, including the ones joining two subtries.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.used.count_ones() as usize
+    }
+
+    /// Removes every prefix and frees every slot.
+    pub fn clear(&mut self) {
+        while self.used != 0 {
+            let index = self.used.trailing_zeros() as usize;
+            self.release(index);
+        }
+
+        self.len = 0;
+        self.root = None;
+    }
+
+    /// Returns the node stored in `index`, which must be in use.
+    #[inline]
+    fn node(&self, index: usize) -> &Node<V> {
+        unsafe { self.nodes[index].assume_init_ref() }
+    }
+
+    /// Returns the node stored in `index` mutably, which must be in use.
+    #[inline]
+    fn node_mut(&mut self, index: usize) -> &mut Node<V> {
+        unsafe { self.nodes[index].assume_init_mut() }
+    }
+
+    /// Marks a slot as used in the bitmask.
+    #[inline]
+    fn add_used(&mut self, index: usize) {
+        self.used |= 1 << index;
+    }
+
+    /// Stores a node without links in the first free slot and returns the slot. The table
+    /// must not be full.
+    fn allocate(&mut self, bits: u128, len: u8, value: Option<V>) -> usize {
+        let index = (!self.used).trailing_zeros() as usize;
+
+        self.nodes[index].write(Node {
+            bits,
+            len,
+            value,
+            parent: None,
+            children: [None, None],
+        });
+        self.add_used(index);
+
+        index
+    }
+
+    /// Frees the slot `index` and returns its node.
+    fn release(&mut self, index: usize) -> Node<V> {
+        self.used &= !(1 << index);
+        unsafe { self.nodes[index].assume_init_read() }
+    }
+
+    /// Makes `child` the child of `parent` on `side`, or the root when `parent` is `None`.
+    fn link(&mut self, parent: Option<usize>, side: usize, child: Option<usize>) {
+        match parent {
+            Some(p) => self.node_mut(p).children[side] = child,
+            None => self.root = child,
+        }
+
+        if let Some(c) = child {
+            self.node_mut(c).parent = parent;
+        }
+    }
+
+    /// Returns the side of `parent` holding `child`.
+    fn side(&self, parent: usize, child: usize) -> usize {
+        usize::from(self.node(parent).children[1] == Some(child))
+    }
+
+    /// Returns the slot following `index` in prefix order.
+    fn successor(&self, index: usize) -> Option<usize> {
+        let [left, right] = self.node(index).children;
+        if let Some(child) = left.or(right) {
+            return Some(child);
+        }
+
+        let mut child = index;
+        while let Some(parent) = self.node(child).parent {
+            let [left, right] = self.node(parent).children;
+            if left == Some(child) && right.is_some() {
+                return right;
+            }
+
+            child = parent;
+        }
+
+        None
+    }
+
+    /// Returns an iterator over the prefixes and their values in prefix order: by address,
+    /// a prefix coming before the longer prefixes it covers.
+    pub fn iter(&self) -> Iter<'_, V, N, A> {
+        Iter {
+            table: self,
+            next: self.root,
+            remaining: self.len,
+        }
+    }
+}
+
+impl<V, const N: usize, A: Address> SizedPrefixTable<V, N, A>
+where
+    Const<N>: ValidK,
+{
+    /// Returns the slot of the node holding exactly `bits` and `len`, with or without value.
+    fn find(&self, bits: u128, len: u8) -> Option<usize> {
+        let mut current = self.root;
+
+        while let Some(index) = current {
+            let node = self.node(index);
+
+            if node.len > len || !address::matches(node.bits, bits, node.len) {
+                return None;
+            }
+            if node.len == len {
+                return Some(index);
+            }
+
+            current = node.children[address::bit(bits, node.len)];
+        }
+
+        None
+    }
+
+    /// Inserts a value for the first `len` bits of `prefix`, returning the previous value of
+    /// the prefix if present.
+    ///
+    /// Bits of `prefix` past `len` are ignored, so `10.1.2.3/8` stands for `10.0.0.0/8`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the prefix is absent and the free slots
+    /// cannot hold its node, plus the node joining it to the trie when it needs one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn insert(&mut self, prefix: A, len: u8, value: V) -> Result<Option<V>, CapacityError<V>> {
+        let bits = address::prefix_bits(prefix, len);
+        let free = N - self.node_count();
+        let (mut parent, mut side) = (None, 0);
+        let mut current = self.root;
+
+        while let Some(index) = current {
+            let node = self.node(index);
+            let common = address::common_len(node.bits, bits, node.len.min(len));
+
+            if common < node.len {
+                // The prefix diverges from the node, or stops within it: a new node takes
+                // its place, either the prefix itself or a node joining both.
+                let below_side = address::bit(node.bits, common);
+
+                let above = if common == len {
+                    if free < 1 {
+                        return Err(CapacityError::new(value));
+                    }
+
+                    self.allocate(bits, len, Some(value))
+                } else {
+                    if free < 2 {
+                        return Err(CapacityError::new(value));
+                    }
+
+                    let join = self.allocate(bits & address::mask(common), common, None);
+                    let leaf = self.allocate(bits, len, Some(value));
+                    self.link(Some(join), address::bit(bits, common), Some(leaf));
+                    join
+                };
+
+                self.link(parent, side, Some(above));
+                self.link(Some(above), below_side, Some(index));
+                self.len += 1;
+
+                return Ok(None);
+            }
+
+            if node.len == len {
+                let previous = self.node_mut(index).value.replace(value);
+                self.len += usize::from(previous.is_none());
+
+                return Ok(previous);
+            }
+
+            (parent, side) = (Some(index), address::bit(bits, node.len));
+            current = node.children[side];
+        }
+
+        if free < 1 {
+            return Err(CapacityError::new(value));
+        }
+
+        let leaf = self.allocate(bits, len, Some(value));
+        self.link(parent, side, Some(leaf));
+        self.len += 1;
+
+        Ok(None)
+    }
+
+    /// Returns a reference to the value of exactly the first `len` bits of `prefix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn get(&self, prefix: A, len: u8) -> Option<&V> {
+        let index = self.find(address::prefix_bits(prefix, len), len)?;
+        self.node(index).value.as_ref()
+    }
+
+    /// Returns a mutable reference to the value of exactly the first `len` bits of `prefix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn get_mut(&mut self, prefix: A, len: u8) -> Option<&mut V> {
+        let index = self.find(address::prefix_bits(prefix, len), len)?;
+        self.node_mut(index).value.as_mut()
+    }
+
+    /// Returns `true` if the table holds a value for exactly the first `len` bits of `prefix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn contains(&self, prefix: A, len: u8) -> bool {
+        self.get(prefix, len).is_some()
+    }
+
+    /// Removes exactly the first `len` bits of `prefix` and returns its value, if present.
+    ///
+    /// More specific prefixes are kept. The slots of the nodes left without value and with
+    /// fewer than two children are freed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `A::BITS`.
+    pub fn remove(&mut self, prefix: A, len: u8) -> Option<V> {
+        let mut index = self.find(address::prefix_bits(prefix, len), len)?;
+        let value = self.node_mut(index).value.take()?;
+        self.len -= 1;
+
+        loop {
+            let node = self.node(index);
+            if node.value.is_some() {
+                break;
+            }
+
+            let child = match node.children {
+                [Some(_), Some(_)] => break,
+                [child, None] | [None, child] => child,
+            };
+            let parent = node.parent;
+            let side = parent.map_or(0, |p| self.side(p, index));
+
+            self.release(index);
+            self.link(parent, side, child);
+
+            match parent {
+                Some(p) => index = p,
+                None => break,
+            }
+        }
+
+        Some(value)
+    }
+
+    /// Returns the longest prefix covering `addr`, with its length and value.
+    pub fn longest_match(&self, addr: A) -> Option<(A, u8, &V)> {
+        let bits = addr.to_left_aligned();
+        let mut current = self.root;
+        let mut best = None;
+
+        while let Some(index) = current {
+            let node = self.node(index);
+
+            if !address::matches(node.bits, bits, node.len) {
+                break;
+            }
+            if let Some(value) = &node.value {
+                best = Some((node, value));
+            }
+            if node.len == 128 {
+                break;
+            }
+
+            current = node.children[address::bit(bits, node.len)];
+        }
+
+        best.map(|(node, value)| (A::from_left_aligned(node.bits), node.len, value))
+    }
+}
+
+/// Iterator over the prefixes of a [`SizedPrefixTable`], in prefix order.
+///
+/// # Fields
+///
+/// - `table`: The table being walked
+/// - `next`: Slot of the next node to visit
+/// - `remaining`: Number of prefixes not yielded yet
+pub struct Iter<'a, V, const N: usize, A>
+where
+    Const<N>: ValidK,
+{
+    table: &'a SizedPrefixTable<V, N, A>,
+    next: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, V, const N: usize, A: Address> Iterator for Iter<'a, V, N, A>
+where
+    Const<N>: ValidK,
+{
+    type Item = (A, u8, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(index) = self.next {
+            let node = self.table.node(index);
+            self.next = self.table.successor(index);
+
+            if let Some(value) = &node.value {
+                self.remaining -= 1;
+                return Some((A::from_left_aligned(node.bits), node.len, value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<V, const N: usize, A: Address> ExactSizeIterator for Iter<'_, V, N, A> where Const<N>: ValidK {}
+
+impl<V, const N: usize, A: Address> FusedIterator for Iter<'_, V, N, A> where Const<N>: ValidK {}
+
+/// Serializes the table as a sequence of `(prefix, len, value)` tuples, in prefix order.
+#[cfg(feature = "serde")]
+impl<V: serde::Serialize, const N: usize, A: Address + serde::Serialize> serde::Serialize
+    for SizedPrefixTable<V, N, A>
+where
+    Const<N>: ValidK,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes a sequence of `(prefix, len, value)` tuples, inserting them one by one.
+///
+/// A prefix length exceeding the address width is rejected with an `invalid_value` error, and
+/// a sequence whose nodes do not fit in the `N` slots with a custom error, instead of being
+/// truncated.
+#[cfg(feature = "serde")]
+impl<'de, V, const N: usize, A> serde::Deserialize<'de> for SizedPrefixTable<V, N, A>
+where
+    Const<N>: ValidK,
+    V: serde::Deserialize<'de>,
+    A: Address + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, SeqAccess, Unexpected, Visitor};
+
+        struct TableVisitor<V, const N: usize, A>(PhantomData<(V, A)>);
+
+        impl<'de, V, const N: usize, A> Visitor<'de> for TableVisitor<V, N, A>
+        where
+            Const<N>: ValidK,
+            V: serde::Deserialize<'de>,
+            A: Address + serde::Deserialize<'de>,
+        {
+            type Value = SizedPrefixTable<V, N, A>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of prefixes fitting in {N} nodes")
+            }
+
+            fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+                let mut table = SizedPrefixTable::new();
+
+                while let Some((prefix, len, value)) = seq.next_element::<(A, u8, V)>()? {
+                    if len > A::BITS {
+                        return Err(S::Error::invalid_value(
+                            Unexpected::Unsigned(len.into()),
+                            &"a prefix length within the address width",
+                        ));
+                    }
+
+                    if table.insert(prefix, len, value).is_err() {
+                        return Err(S::Error::custom(format_args!(
+                            "prefixes exceed the capacity of {N} nodes"
+                        )));
+                    }
+                }
+
+                Ok(table)
+            }
+        }
+
+        deserializer.deserialize_seq(TableVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,289 @@
+//! Epoch-based memory reclamation.
+//!
+//! This module provides a [`Collector`] deferring the destruction of values removed from
+//! lock-free structures until no thread can still be reading them.
+//!
+//! # Overview
+//!
+//! A thread accessing a shared structure first pins the collector with [`Collector::pin`],
+//! which records the current global epoch in one of [`SLOTS`] participant slots and returns a
+//! [`Guard`]. Pointers loaded while the guard is alive stay valid until it is dropped.
+//!
+//! A value unlinked from the structure is handed to [`Guard::defer_drop`], which tags it with
+//! the global epoch. The global epoch only advances once every pinned slot has observed it, so
+//! a value tagged with epoch `e` can be dropped as soon as the global epoch reaches `e + 2`:
+//! every guard that might have loaded a pointer to it has been released by then.
+//!
+//! Deferred values are kept in a lock-free stack and dropped by [`Collector::collect`], which
+//! runs automatically every [`COLLECT_EVERY`] deferrals. Values still pending when the
+//! collector is dropped are dropped with it.
+//!
+//! Participant slots are claimed on each pin rather than registered per thread, so the
+//! collector works without thread-local storage. At most [`SLOTS`] guards can be alive at
+//! once; further calls to `pin` spin until a slot is released.
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::reclaim::epoch::Collector;
+//! use std::sync::atomic::{AtomicPtr, Ordering};
+//!
+//! static COLLECTOR: Collector = Collector::new();
+//! let shared = AtomicPtr::new(Box::into_raw(Box::new(1u64)));
+//!
+//! let guard = COLLECTOR.pin();
+//! let old = shared.swap(Box::into_raw(Box::new(2u64)), Ordering::AcqRel);
+//! unsafe { guard.defer_drop(old) };
+//! ```
+
+use alloc::boxed::Box;
+
+use core::hint;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering, fence};
+
+/// Number of participant slots, bounding the number of guards alive at the same time.
+pub const SLOTS: usize = 64;
+
+/// Number of deferred values after which a collection is attempted.
+pub const COLLECT_EVERY: usize = 64;
+
+/// Slot value of a participant slot that is not pinned.
+const VACANT: usize = 0;
+
+/// A value waiting for the global epoch to advance before being dropped.
+struct Deferred {
+    epoch: usize,
+    data: *mut (),
+    drop: unsafe fn(*mut ()),
+    next: *mut Deferred,
+}
+
+unsafe fn drop_box<T>(data: *mut ()) {
+    drop(unsafe { Box::from_raw(data as *mut T) });
+}
+
+/// Encodes a pinned slot: the epoch shifted left with the lowest bit set.
+fn pinned(epoch: usize) -> usize {
+    (epoch << 1) | 1
+}
+
+/// Epoch-based garbage collector.
+///
+/// # Fields
+///
+/// - `epoch`: Global epoch
+/// - `slots`: Participant slots, `VACANT` or the pinned epoch
+/// - `garbage`: Lock-free stack of deferred values
+/// - `deferred`: Number of deferrals, used to trigger collections
+pub struct Collector {
+    epoch: AtomicUsize,
+    slots: [AtomicUsize; SLOTS],
+    garbage: AtomicPtr<Deferred>,
+    deferred: AtomicUsize,
+}
+
+// SAFETY: deferred values are only accepted when `Send`, and dropped by a single collecting
+// thread after unlinking them from the stack.
+unsafe impl Send for Collector {}
+unsafe impl Sync for Collector {}
+
+impl Default for Collector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Collector {
+    fn drop(&mut self) {
+        // No guard can be alive, so every pending value is unreachable.
+        let mut current = *self.garbage.get_mut();
+
+        while !current.is_null() {
+            let deferred = unsafe { Box::from_raw(current) };
+            unsafe { (deferred.drop)(deferred.data) };
+            current = deferred.next;
+        }
+    }
+}
+
+impl Collector {
+    /// Creates a collector with no pinned participant and no pending value.
+    pub const fn new() -> Self {
+        Self {
+            epoch: AtomicUsize::new(0),
+            slots: [const { AtomicUsize::new(VACANT) }; SLOTS],
+            garbage: AtomicPtr::new(ptr::null_mut()),
+            deferred: AtomicUsize::new(0),
+        }
+    }
+
+    /// Pins the current thread, protecting every pointer it loads until the guard is dropped.
+    pub fn pin(&self) -> Guard<'_> {
+        loop {
+            for (slot, state) in self.slots.iter().enumerate() {
+                let epoch = self.epoch.load(Ordering::SeqCst);
+
+                if state
+                    .compare_exchange(VACANT, pinned(epoch), Ordering::SeqCst, Ordering::Relaxed)
+                    .is_ok()
+                {
+                    // Publish the pin before any pointer of the structure is loaded.
+                    fence(Ordering::SeqCst);
+
+                    return Guard {
+                        collector: self,
+                        slot,
+                    };
+                }
+            }
+
+            hint::spin_loop();
+        }
+    }
+
+    /// Returns the current global epoch.
+    #[inline]
+    pub fn epoch(&self) -> usize {
+        self.epoch.load(Ordering::SeqCst)
+    }
+
+    /// Returns the number of guards currently alive.
+    pub fn pinned_count(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|s| s.load(Ordering::Relaxed) != VACANT)
+            .count()
+    }
+
+    /// Advances the global epoch if every pinned participant has observed it, and returns the
+    /// resulting epoch.
+    fn try_advance(&self) -> usize {
+        let global = self.epoch.load(Ordering::SeqCst);
+        fence(Ordering::SeqCst);
+
+        let lagging = self.slots.iter().any(|s| {
+            let state = s.load(Ordering::SeqCst);
+            state != VACANT && state != pinned(global)
+        });
+
+        if lagging {
+            return global;
+        }
+
+        match self.epoch.compare_exchange(
+            global,
+            global.wrapping_add(1),
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        ) {
+            Ok(_) => global.wrapping_add(1),
+            Err(current) => current,
+        }
+    }
+
+    /// Tries to advance the global epoch and drops every deferred value that no guard can
+    /// still reach.
+    pub fn collect(&self) {
+        let global = self.try_advance();
+        let mut current = self.garbage.swap(ptr::null_mut(), Ordering::Acquire);
+
+        let mut kept: *mut Deferred = ptr::null_mut();
+        let mut kept_tail: *mut Deferred = ptr::null_mut();
+
+        while !current.is_null() {
+            let next = unsafe { (*current).next };
+
+            if global.wrapping_sub(unsafe { (*current).epoch }) >= 2 {
+                let deferred = unsafe { Box::from_raw(current) };
+                unsafe { (deferred.drop)(deferred.data) };
+            } else {
+                unsafe { (*current).next = kept };
+                if kept.is_null() {
+                    kept_tail = current;
+                }
+                kept = current;
+            }
+
+            current = next;
+        }
+
+        if !kept.is_null() {
+            self.push_chain(kept, kept_tail);
+        }
+    }
+
+    /// Pushes the chain `head..=tail` of deferred values on the garbage stack.
+    fn push_chain(&self, head: *mut Deferred, tail: *mut Deferred) {
+        let mut top = self.garbage.load(Ordering::Relaxed);
+
+        loop {
+            unsafe { (*tail).next = top };
+
+            match self.garbage.compare_exchange_weak(
+                top,
+                head,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => top = current,
+            }
+        }
+    }
+}
+
+/// Proof that the current thread is pinned, keeping loaded pointers valid while alive.
+///
+/// # Fields
+///
+/// - `collector`: The pinned collector
+/// - `slot`: Index of the participant slot held by the guard
+pub struct Guard<'a> {
+    collector: &'a Collector,
+    slot: usize,
+}
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        self.collector.slots[self.slot].store(VACANT, Ordering::Release);
+    }
+}
+
+impl<'a> Guard<'a> {
+    /// Returns the collector this guard pins.
+    #[inline]
+    pub fn collector(&self) -> &'a Collector {
+        self.collector
+    }
+
+    /// Drops the boxed value at `ptr` once no guard can still reach it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must come from `Box::into_raw`, must already be unreachable for threads pinning
+    /// the collector from now on, and must not be deferred twice.
+    pub unsafe fn defer_drop<T: Send>(&self, ptr: *mut T) {
+        let deferred = Box::into_raw(Box::new(Deferred {
+            epoch: self.collector.epoch.load(Ordering::SeqCst),
+            data: ptr as *mut (),
+            drop: drop_box::<T>,
+            next: ptr::null_mut(),
+        }));
+
+        self.collector.push_chain(deferred, deferred);
+
+        let count = self.collector.deferred.fetch_add(1, Ordering::Relaxed);
+        if count % COLLECT_EVERY == COLLECT_EVERY - 1 {
+            self.collector.collect();
+        }
+    }
+
+    /// Runs a collection immediately.
+    pub fn flush(&self) {
+        self.collector.collect();
+    }
+}
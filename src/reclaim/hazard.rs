@@ -0,0 +1,321 @@
+//! Hazard-pointer memory reclamation.
+//!
+//! This module provides a [`HazardDomain`], which protects individual pointers instead of
+//! whole critical sections, bounding the memory held back by a stalled reader.
+//!
+//! # Overview
+//!
+//! A thread acquires a [`HazardGuard`] with [`HazardDomain::pin`], claiming one of [`RECORDS`]
+//! records holding [`HAZARDS`] hazard slots. Before dereferencing a shared pointer it publishes
+//! the pointer in a slot with [`HazardGuard::protect`], which reloads the source until the
+//! published value is still current: from then on the pointed value cannot be dropped.
+//!
+//! Values unlinked from the structure are handed to [`HazardGuard::defer_drop`]. Every
+//! [`COLLECT_EVERY`] deferrals, the retired values are scanned against the published hazards
+//! and the unprotected ones are dropped. Values still pending when the domain is dropped are
+//! dropped with it.
+//!
+//! Hazards are compared with the two lowest address bits cleared, so a pointer carrying a tag
+//! in those bits (such as a deletion mark) still protects the value it points to. The only
+//! side effect is that an unrelated value may occasionally be kept until the next scan.
+//!
+//! Unlike an epoch [`Collector`](super::epoch::Collector), a reader stalled while holding a
+//! guard only delays the values it protects, at the cost of a store and a reload per pointer.
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::reclaim::hazard::HazardDomain;
+//! use std::sync::atomic::{AtomicPtr, Ordering};
+//!
+//! static DOMAIN: HazardDomain = HazardDomain::new();
+//! let shared = AtomicPtr::new(Box::into_raw(Box::new(1u64)));
+//!
+//! let guard = DOMAIN.pin();
+//! let current = guard.protect(0, &shared);
+//! assert_eq!(unsafe { *current }, 1);
+//! ```
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use core::hint;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering, fence};
+
+/// Number of records, bounding the number of guards alive at the same time.
+pub const RECORDS: usize = 64;
+
+/// Number of hazard slots held by each guard.
+pub const HAZARDS: usize = 4;
+
+/// Number of deferred values after which a scan is attempted.
+pub const COLLECT_EVERY: usize = 64;
+
+/// Address bits ignored when matching a retired value against the hazards.
+const TAG_MASK: usize = 0b11;
+
+/// A value waiting for every hazard protecting it to be cleared before being dropped.
+struct Retired {
+    data: *mut (),
+    drop: unsafe fn(*mut ()),
+    next: *mut Retired,
+}
+
+unsafe fn drop_box<T>(data: *mut ()) {
+    drop(unsafe { Box::from_raw(data as *mut T) });
+}
+
+/// Hazard slots claimed by a guard.
+///
+/// # Fields
+///
+/// - `active`: `true` while a guard holds the record
+/// - `hazards`: Pointers published by the guard, null when unused
+struct Record {
+    active: AtomicBool,
+    hazards: [AtomicPtr<()>; HAZARDS],
+}
+
+impl Record {
+    const fn new() -> Self {
+        Self {
+            active: AtomicBool::new(false),
+            hazards: [const { AtomicPtr::new(ptr::null_mut()) }; HAZARDS],
+        }
+    }
+}
+
+/// Hazard-pointer reclamation domain.
+///
+/// # Fields
+///
+/// - `records`: Hazard records claimed by guards
+/// - `retired`: Lock-free stack of deferred values
+/// - `deferred`: Number of deferrals, used to trigger scans
+pub struct HazardDomain {
+    records: [Record; RECORDS],
+    retired: AtomicPtr<Retired>,
+    deferred: AtomicUsize,
+}
+
+// SAFETY: deferred values are only accepted when `Send`, and dropped by a single scanning
+// thread after unlinking them from the stack.
+unsafe impl Send for HazardDomain {}
+unsafe impl Sync for HazardDomain {}
+
+impl Default for HazardDomain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for HazardDomain {
+    fn drop(&mut self) {
+        // No guard can be alive, so every pending value is unreachable.
+        let mut current = *self.retired.get_mut();
+
+        while !current.is_null() {
+            let retired = unsafe { Box::from_raw(current) };
+            unsafe { (retired.drop)(retired.data) };
+            current = retired.next;
+        }
+    }
+}
+
+impl HazardDomain {
+    /// Creates a domain with no active guard and no pending value.
+    pub const fn new() -> Self {
+        Self {
+            records: [const { Record::new() }; RECORDS],
+            retired: AtomicPtr::new(ptr::null_mut()),
+            deferred: AtomicUsize::new(0),
+        }
+    }
+
+    /// Claims a record of hazard slots, spinning while every record is taken.
+    pub fn pin(&self) -> HazardGuard<'_> {
+        loop {
+            for record in &self.records {
+                if !record.active.load(Ordering::Relaxed)
+                    && record
+                        .active
+                        .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                        .is_ok()
+                {
+                    return HazardGuard {
+                        record,
+                        domain: self,
+                    };
+                }
+            }
+
+            hint::spin_loop();
+        }
+    }
+
+    /// Returns the number of guards currently alive.
+    pub fn pinned_count(&self) -> usize {
+        self.records
+            .iter()
+            .filter(|r| r.active.load(Ordering::Relaxed))
+            .count()
+    }
+
+    /// Drops every deferred value that no hazard protects.
+    pub fn collect(&self) {
+        let mut current = self.retired.swap(ptr::null_mut(), Ordering::Acquire);
+
+        if current.is_null() {
+            return;
+        }
+
+        // Order the unlinking of the retired values before reading the hazards.
+        fence(Ordering::SeqCst);
+
+        let mut protected: Vec<usize> = self
+            .records
+            .iter()
+            .flat_map(|r| r.hazards.iter())
+            .map(|h| h.load(Ordering::SeqCst).addr() & !TAG_MASK)
+            .filter(|&addr| addr != 0)
+            .collect();
+        protected.sort_unstable();
+
+        let mut kept: *mut Retired = ptr::null_mut();
+        let mut kept_tail: *mut Retired = ptr::null_mut();
+
+        while !current.is_null() {
+            let next = unsafe { (*current).next };
+            let addr = unsafe { (*current).data }.addr() & !TAG_MASK;
+
+            if protected.binary_search(&addr).is_err() {
+                let retired = unsafe { Box::from_raw(current) };
+                unsafe { (retired.drop)(retired.data) };
+            } else {
+                unsafe { (*current).next = kept };
+                if kept.is_null() {
+                    kept_tail = current;
+                }
+                kept = current;
+            }
+
+            current = next;
+        }
+
+        if !kept.is_null() {
+            self.push_chain(kept, kept_tail);
+        }
+    }
+
+    /// Pushes the chain `head..=tail` of deferred values on the retired stack.
+    fn push_chain(&self, head: *mut Retired, tail: *mut Retired) {
+        let mut top = self.retired.load(Ordering::Relaxed);
+
+        loop {
+            unsafe { (*tail).next = top };
+
+            match self.retired.compare_exchange_weak(
+                top,
+                head,
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => top = current,
+            }
+        }
+    }
+}
+
+/// Record of [`HAZARDS`] hazard slots, cleared and released when dropped.
+///
+/// # Fields
+///
+/// - `record`: The claimed record
+/// - `domain`: The domain the record belongs to
+pub struct HazardGuard<'a> {
+    record: &'a Record,
+    domain: &'a HazardDomain,
+}
+
+impl Drop for HazardGuard<'_> {
+    fn drop(&mut self) {
+        for hazard in &self.record.hazards {
+            hazard.store(ptr::null_mut(), Ordering::Release);
+        }
+        self.record.active.store(false, Ordering::Release);
+    }
+}
+
+impl<'a> HazardGuard<'a> {
+    /// Returns the domain this guard belongs to.
+    #[inline]
+    pub fn domain(&self) -> &'a HazardDomain {
+        self.domain
+    }
+
+    /// Loads `source` and publishes the result in hazard slot `index`, replacing the pointer
+    /// it protected before.
+    ///
+    /// The returned pointer stays valid until the slot is overwritten or cleared, or the guard
+    /// is dropped, provided values removed from `source` are deferred through this domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not lower than [`HAZARDS`].
+    pub fn protect<T>(&self, index: usize, source: &AtomicPtr<T>) -> *mut T {
+        let hazard = &self.record.hazards[index];
+        let mut current = source.load(Ordering::Acquire);
+
+        loop {
+            hazard.store(current as *mut (), Ordering::SeqCst);
+            fence(Ordering::SeqCst);
+
+            // The value may have been retired before the hazard became visible.
+            let reloaded = source.load(Ordering::SeqCst);
+            if reloaded == current {
+                return current;
+            }
+            current = reloaded;
+        }
+    }
+
+    /// Clears hazard slot `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not lower than [`HAZARDS`].
+    pub fn clear(&self, index: usize) {
+        self.record.hazards[index].store(ptr::null_mut(), Ordering::Release);
+    }
+
+    /// Drops the boxed value at `ptr` once no hazard protects it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must come from `Box::into_raw`, must already be unreachable for threads
+    /// protecting pointers from now on, and must not be deferred twice.
+    pub unsafe fn defer_drop<T: Send>(&self, ptr: *mut T) {
+        let retired = Box::into_raw(Box::new(Retired {
+            data: ptr as *mut (),
+            drop: drop_box::<T>,
+            next: ptr::null_mut(),
+        }));
+
+        self.domain.push_chain(retired, retired);
+
+        let count = self.domain.deferred.fetch_add(1, Ordering::Relaxed);
+        if count % COLLECT_EVERY == COLLECT_EVERY - 1 {
+            self.domain.collect();
+        }
+    }
+
+    /// Runs a scan immediately.
+    pub fn flush(&self) {
+        self.domain.collect();
+    }
+}
@@ -0,0 +1,21 @@
+//! Memory reclamation for lock-free data structures.
+//!
+//! Lock-free structures cannot free a removed node right away, since concurrent readers may
+//! still hold a pointer to it. This module delays destruction until that can no longer happen.
+//! - `epoch`: Epoch-based reclamation with guards pinning a shared `Collector`
+//! - `hazard`: Hazard pointers protecting individual pointers in a `HazardDomain`
+//! - `traits`: `Reclaim` and `ReclaimGuard`, implemented by both schemes
+//!
+//! Both schemes claim guard slots from a fixed array instead of registering threads, so they
+//! work without thread-local storage and can live in `static` items.
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+
+pub mod epoch;
+pub mod hazard;
+pub mod traits;
+
+pub use epoch::{Collector, Guard};
+pub use hazard::{HazardDomain, HazardGuard};
+pub use traits::{Reclaim, ReclaimGuard};
@@ -0,0 +1,140 @@
+//! Common interface shared by the reclamation schemes.
+//!
+//! This module defines traits implemented by both the epoch
+//! [`Collector`](super::epoch::Collector) and the [`HazardDomain`](super::hazard::HazardDomain),
+//! so a concurrent container can switch between schemes through a type parameter.
+//!
+//! # Traits
+//!
+//! - [`Reclaim`]: A scheme handing out guards
+//! - [`ReclaimGuard`]: Pointer protection and deferred destruction while a guard is alive
+//!
+//! Containers written against these traits protect every shared pointer with
+//! [`ReclaimGuard::protect`] before dereferencing it, and use at most
+//! [`ReclaimGuard::SLOTS`] slots per guard. Epoch guards protect every pointer loaded while
+//! they are alive, so they accept any slot index; hazard guards protect one pointer per slot.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::reclaim::{Reclaim, ReclaimGuard};
+//! use std::sync::atomic::{AtomicPtr, Ordering};
+//!
+//! fn replace<R: Reclaim>(domain: &R, shared: &AtomicPtr<u64>, value: u64) -> u64 {
+//!     let guard = domain.pin();
+//!     let old = shared.swap(Box::into_raw(Box::new(value)), Ordering::AcqRel);
+//!     let previous = unsafe { *old };
+//!
+//!     unsafe { guard.defer_drop(old) };
+//!     previous
+//! }
+//! ```
+
+use super::epoch::{Collector, Guard};
+use super::hazard::{self, HazardDomain, HazardGuard};
+
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// A memory reclamation scheme.
+///
+/// # Safety
+///
+/// A value deferred through a guard must not be dropped while a pointer to it obtained through
+/// [`ReclaimGuard::protect`] is still protected, as described by the guard implementation.
+pub unsafe trait Reclaim: Sync {
+    /// Guard keeping protected pointers valid while alive.
+    type Guard<'a>: ReclaimGuard
+    where
+        Self: 'a;
+
+    /// Acquires a guard, spinning while the scheme has no guard left to hand out.
+    fn pin(&self) -> Self::Guard<'_>;
+
+    /// Drops every deferred value that can no longer be reached.
+    fn collect(&self);
+}
+
+/// Operations available while a [`Reclaim`] guard is alive.
+///
+/// # Safety
+///
+/// See [`Reclaim`].
+pub unsafe trait ReclaimGuard {
+    /// Number of protection slots available to `protect`.
+    const SLOTS: usize;
+
+    /// Loads `source` and protects the result in slot `index` until the slot is reused or the
+    /// guard is dropped.
+    ///
+    /// # Panics
+    ///
+    /// May panic if `index` is not lower than [`SLOTS`](Self::SLOTS).
+    fn protect<T>(&self, index: usize, source: &AtomicPtr<T>) -> *mut T;
+
+    /// Drops the boxed value at `ptr` once no guard can still reach it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must come from `Box::into_raw`, must already be unreachable for pointers
+    /// protected from now on, and must not be deferred twice.
+    unsafe fn defer_drop<T: Send>(&self, ptr: *mut T);
+
+    /// Runs a collection immediately.
+    fn flush(&self);
+}
+
+unsafe impl Reclaim for Collector {
+    type Guard<'a> = Guard<'a>;
+
+    fn pin(&self) -> Guard<'_> {
+        Collector::pin(self)
+    }
+
+    fn collect(&self) {
+        Collector::collect(self)
+    }
+}
+
+unsafe impl ReclaimGuard for Guard<'_> {
+    const SLOTS: usize = usize::MAX;
+
+    fn protect<T>(&self, _index: usize, source: &AtomicPtr<T>) -> *mut T {
+        source.load(Ordering::Acquire)
+    }
+
+    unsafe fn defer_drop<T: Send>(&self, ptr: *mut T) {
+        unsafe { Guard::defer_drop(self, ptr) }
+    }
+
+    fn flush(&self) {
+        Guard::flush(self)
+    }
+}
+
+unsafe impl Reclaim for HazardDomain {
+    type Guard<'a> = HazardGuard<'a>;
+
+    fn pin(&self) -> HazardGuard<'_> {
+        HazardDomain::pin(self)
+    }
+
+    fn collect(&self) {
+        HazardDomain::collect(self)
+    }
+}
+
+unsafe impl ReclaimGuard for HazardGuard<'_> {
+    const SLOTS: usize = hazard::HAZARDS;
+
+    fn protect<T>(&self, index: usize, source: &AtomicPtr<T>) -> *mut T {
+        HazardGuard::protect(self, index, source)
+    }
+
+    unsafe fn defer_drop<T: Send>(&self, ptr: *mut T) {
+        unsafe { HazardGuard::defer_drop(self, ptr) }
+    }
+
+    fn flush(&self) {
+        HazardGuard::flush(self)
+    }
+}
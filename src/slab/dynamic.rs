@@ -0,0 +1,301 @@
+//! Growable slab implementation with generational keys.
+//!
+//! This module provides a slab allocator with unlimited capacity using heap allocation.
+//! Unlike the sized variant, this slab grows when every slot is occupied and is designed for
+//! std environments where the number of live values is not known at compile time.
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+//!
+//! # Overview
+//!
+//! Slots are stored in a `Vec`. Vacant slots are chained together in a free list so that
+//! `insert` and `remove` are O(1). Each slot keeps a generation that is bumped on removal,
+//! which lets the slab reject stale [`SlabKey`]s.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::slab::Slab;
+//!
+//! let mut slab: Slab<u32> = Slab::new();
+//! let key = slab.insert(7);
+//!
+//! assert_eq!(slab.remove(key), Some(7));
+//! assert!(!slab.contains(key));
+//! ```
+
+use super::sized::SlabKey;
+
+use alloc::vec::Vec;
+
+/// A single slot of the slab.
+enum Entry<T> {
+    Occupied {
+        value: T,
+        generation: u32,
+    },
+    Vacant {
+        next_free: Option<usize>,
+        generation: u32,
+    },
+}
+
+/// A growable slab handing out generational keys.
+///
+/// # Fields
+///
+/// - `entries`: Backing storage of occupied and vacant slots
+/// - `free`: Index of the first vacant slot (if any)
+/// - `len`: Current number of values in the slab
+pub struct Slab<T> {
+    entries: Vec<Entry<T>>,
+    free: Option<usize>,
+    len: usize,
+}
+
+impl<T> Default for Slab<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Slab<T> {
+    /// Creates an empty slab.
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            free: None,
+            len: 0,
+        }
+    }
+
+    /// Creates an empty slab able to hold `capacity` values without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+            free: None,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values currently stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the slab contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of values the slab can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    /// Returns the number of allocated slots that are currently free for reuse.
+    #[inline]
+    pub fn vacant(&self) -> usize {
+        self.entries.len() - self.len
+    }
+
+    /// Stores a value, reusing a vacant slot when possible, and returns its key.
+    pub fn insert(&mut self, value: T) -> SlabKey {
+        self.len += 1;
+
+        match self.free {
+            Some(index) => {
+                let (next_free, generation) = match self.entries[index] {
+                    Entry::Vacant {
+                        next_free,
+                        generation,
+                    } => (next_free, generation),
+                    Entry::Occupied { .. } => unreachable!("free list points to an occupied slot"),
+                };
+
+                self.free = next_free;
+                self.entries[index] = Entry::Occupied { value, generation };
+
+                SlabKey::new(index, generation)
+            }
+            None => {
+                let index = self.entries.len();
+                self.entries.push(Entry::Occupied {
+                    value,
+                    generation: 0,
+                });
+
+                SlabKey::new(index, 0)
+            }
+        }
+    }
+
+    /// Returns `true` if `key` still refers to a value of the slab.
+    pub fn contains(&self, key: SlabKey) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the value behind `key`, or `None` if the key is stale.
+    pub fn get(&self, key: SlabKey) -> Option<&T> {
+        match self.entries.get(key.index()) {
+            Some(Entry::Occupied { value, generation }) if *generation == key.generation() => {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value behind `key`, or `None` if the key is stale.
+    pub fn get_mut(&mut self, key: SlabKey) -> Option<&mut T> {
+        match self.entries.get_mut(key.index()) {
+            Some(Entry::Occupied { value, generation }) if *generation == key.generation() => {
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Removes and returns the value behind `key`, or `None` if the key is stale.
+    ///
+    /// The slot generation is bumped so that every copy of `key` becomes stale.
+    pub fn remove(&mut self, key: SlabKey) -> Option<T> {
+        if !self.contains(key) {
+            return None;
+        }
+
+        let vacant = Entry::Vacant {
+            next_free: self.free,
+            generation: key.generation().wrapping_add(1),
+        };
+
+        match core::mem::replace(&mut self.entries[key.index()], vacant) {
+            Entry::Occupied { value, .. } => {
+                self.free = Some(key.index());
+                self.len -= 1;
+
+                Some(value)
+            }
+            Entry::Vacant { .. } => unreachable!("key was checked to be live"),
+        }
+    }
+
+    /// Removes every value, invalidating all outstanding keys.
+    ///
+    /// The allocated slots are kept and chained into the free list.
+    pub fn clear(&mut self) {
+        let mut free = None;
+
+        for (index, entry) in self.entries.iter_mut().enumerate().rev() {
+            let generation = match entry {
+                Entry::Occupied { generation, .. } => generation.wrapping_add(1),
+                Entry::Vacant { generation, .. } => *generation,
+            };
+
+            *entry = Entry::Vacant {
+                next_free: free,
+                generation,
+            };
+            free = Some(index);
+        }
+
+        self.free = free;
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the keys and values, in slot order.
+    pub fn iter(&self) -> impl Iterator<Item = (SlabKey, &T)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| match entry {
+                Entry::Occupied { value, generation } => {
+                    Some((SlabKey::new(index, *generation), value))
+                }
+                Entry::Vacant { .. } => None,
+            })
+    }
+
+    /// Returns an iterator over the keys and mutable values, in slot order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (SlabKey, &mut T)> {
+        self.entries
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, entry)| match entry {
+                Entry::Occupied { value, generation } => {
+                    Some((SlabKey::new(index, *generation), value))
+                }
+                Entry::Vacant { .. } => None,
+            })
+    }
+}
+
+/// Serializes the slab as a sequence of `(generation, value)` slots, `value` being `None` for a
+/// vacant slot.
+///
+/// Keeping the generations of vacant slots means stale keys stay stale once the slab is
+/// deserialized.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Slab<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.entries.iter().map(|entry| match entry {
+            Entry::Occupied { value, generation } => (*generation, Some(value)),
+            Entry::Vacant { generation, .. } => (*generation, None),
+        }))
+    }
+}
+
+/// Deserializes a sequence of `(generation, value)` slots, so that every key handed out before
+/// serialization refers to the same value.
+///
+/// The free list is rebuilt in slot order: the next insertions fill the lowest vacant slots
+/// first.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Slab<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+        use serde::de::{SeqAccess, Visitor};
+
+        struct SlabVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>> Visitor<'de> for SlabVisitor<T> {
+            type Value = Slab<T>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("a sequence of slots")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut slab = Slab::with_capacity(seq.size_hint().unwrap_or(0));
+
+                while let Some((generation, value)) = seq.next_element::<(u32, Option<T>)>()? {
+                    slab.entries.push(match value {
+                        Some(value) => {
+                            slab.len += 1;
+                            Entry::Occupied { value, generation }
+                        }
+                        None => Entry::Vacant {
+                            next_free: None,
+                            generation,
+                        },
+                    });
+                }
+
+                // Chain the vacant slots from the last one, so the list starts at the lowest.
+                for (index, entry) in slab.entries.iter_mut().enumerate().rev() {
+                    if let Entry::Vacant { next_free, .. } = entry {
+                        *next_free = slab.free;
+                        slab.free = Some(index);
+                    }
+                }
+
+                Ok(slab)
+            }
+        }
+
+        deserializer.deserialize_seq(SlabVisitor(PhantomData))
+    }
+}
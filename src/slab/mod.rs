@@ -0,0 +1,19 @@
+//! Slab storage with generational keys.
+//!
+//! Provides both fixed-size and dynamic slab implementations.
+//! - `sized`: Fixed-size slab with compile-time capacity constraints (stack allocation)
+//! - `dynamic`: Growable slab with heap allocation for unlimited capacity (std or alloc)
+//!
+//! Every `insert` hands out a [`SlabKey`] carrying the slot index and the slot generation.
+//! Removing a value bumps the generation of its slot, so keys that outlive their value are
+//! detected as stale instead of silently aliasing the next occupant.
+
+pub mod sized;
+
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+
+pub use sized::{SizedSlab, SlabKey};
+
+#[cfg(feature = "alloc")]
+pub use dynamic::Slab;
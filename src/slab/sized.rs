@@ -0,0 +1,398 @@
+//! Fixed-size slab implementation with generational keys.
+//!
+//! This module provides a slab allocator with a compile-time fixed capacity constraint.
+//! Values live in an array of uninitialized slots and the occupied slots are tracked through a
+//! bitmask (`used`), reusing the slot-allocation strategy of
+//! [`SizedDoubleLinkedList`](crate::double_linked_list::SizedDoubleLinkedList).
+//! Valid capacities range from 0 to 63, enforced via the `ValidK` trait.
+//!
+//! # Overview
+//!
+//! `insert` stores a value in the first free slot and returns a [`SlabKey`] made of the slot
+//! index and the slot generation. `remove` bumps the generation, so any key handed out for the
+//! removed value no longer matches and is reported as stale by `get`, `get_mut`, `remove` and
+//! `contains`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::slab::SizedSlab;
+//!
+//! let mut slab: SizedSlab<&str, 8> = SizedSlab::new();
+//! let key = slab.insert("conn-1").unwrap();
+//!
+//! assert_eq!(slab.get(key), Some(&"conn-1"));
+//! assert_eq!(slab.remove(key), Some("conn-1"));
+//! assert_eq!(slab.get(key), None);
+//! ```
+
+use crate::double_linked_list::sized::ValidK;
+use crate::{CapacityError, Const};
+
+use core::mem::MaybeUninit;
+
+/// Generational handle to a value stored in a slab.
+///
+/// A key is only valid while the slot it points to still carries the same generation.
+/// Once the value is removed, the key becomes stale and lookups return `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlabKey {
+    index: usize,
+    generation: u32,
+}
+
+impl SlabKey {
+    pub(crate) fn new(index: usize, generation: u32) -> Self {
+        Self { index, generation }
+    }
+
+    /// Returns the slot index this key points to.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the generation of the slot at the time the key was handed out.
+    #[inline]
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+/// A fixed-size slab handing out generational keys.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of values stored in the slab
+/// - `N`: Compile-time capacity (0-63), enforced via the `ValidK` trait
+///
+/// # Fields
+///
+/// - `slots`: Array of uninitialized value slots
+/// - `generations`: Current generation of each slot
+/// - `used`: Bitmask indicating which slots contain valid values
+/// - `len`: Current number of values in the slab
+pub struct SizedSlab<T, const N: usize>
+where
+    Const<N>: ValidK,
+{
+    slots: [MaybeUninit<T>; N],
+    generations: [u32; N],
+    used: u64,
+    len: usize,
+}
+
+impl<T, const N: usize> Default for SizedSlab<T, N>
+where
+    Const<N>: ValidK,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for SizedSlab<T, N>
+where
+    Const<N>: ValidK,
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> SizedSlab<T, N>
+where
+    Const<N>: ValidK,
+{
+    /// Creates an empty slab with capacity `N`.
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { MaybeUninit::uninit() }; N],
+            generations: [0; N],
+            used: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of values currently stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the slab contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if every slot is occupied.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the maximum number of values the slab can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of free slots.
+    #[inline]
+    pub fn vacant(&self) -> usize {
+        N - self.len
+    }
+
+    /// Returns `true` if the slot at `index` holds a value.
+    #[inline]
+    fn is_used(&self, index: usize) -> bool {
+        self.used & (1 << index) != 0
+    }
+
+    /// Finds the index of the first unused slot using bit manipulation.
+    #[inline]
+    fn first_free(&self) -> usize {
+        (!self.used).trailing_zeros() as usize
+    }
+
+    /// Returns `true` if `key` points to a live value of this slab.
+    #[inline]
+    fn is_live(&self, key: SlabKey) -> bool {
+        key.index < N && self.is_used(key.index) && self.generations[key.index] == key.generation
+    }
+
+    /// Stores a value in the first free slot and returns its key.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if every slot is occupied.
+    pub fn insert(&mut self, value: T) -> Result<SlabKey, CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(value));
+        }
+
+        let index = self.first_free();
+
+        self.slots[index].write(value);
+        self.used |= 1 << index;
+        self.len += 1;
+
+        Ok(SlabKey::new(index, self.generations[index]))
+    }
+
+    /// Returns `true` if `key` still refers to a value of the slab.
+    pub fn contains(&self, key: SlabKey) -> bool {
+        self.is_live(key)
+    }
+
+    /// Returns a reference to the value behind `key`, or `None` if the key is stale.
+    pub fn get(&self, key: SlabKey) -> Option<&T> {
+        if !self.is_live(key) {
+            return None;
+        }
+
+        Some(unsafe { self.slots[key.index].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the value behind `key`, or `None` if the key is stale.
+    pub fn get_mut(&mut self, key: SlabKey) -> Option<&mut T> {
+        if !self.is_live(key) {
+            return None;
+        }
+
+        Some(unsafe { self.slots[key.index].assume_init_mut() })
+    }
+
+    /// Removes and returns the value behind `key`, or `None` if the key is stale.
+    ///
+    /// The slot generation is bumped so that every copy of `key` becomes stale.
+    pub fn remove(&mut self, key: SlabKey) -> Option<T> {
+        if !self.is_live(key) {
+            return None;
+        }
+
+        let value = unsafe { self.slots[key.index].assume_init_read() };
+
+        self.used &= !(1 << key.index);
+        self.generations[key.index] = self.generations[key.index].wrapping_add(1);
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    /// Removes every value, invalidating all outstanding keys.
+    pub fn clear(&mut self) {
+        let mut used = self.used;
+
+        while used != 0 {
+            let index = used.trailing_zeros() as usize;
+            used &= used - 1;
+
+            unsafe { self.slots[index].assume_init_drop() };
+            self.generations[index] = self.generations[index].wrapping_add(1);
+        }
+
+        self.used = 0;
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the keys and values, in slot order.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            slab: self,
+            remaining: self.used,
+        }
+    }
+
+    /// Returns an iterator over the keys and mutable values, in slot order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            slots: self.slots.iter_mut().enumerate(),
+            generations: &self.generations,
+            used: self.used,
+        }
+    }
+}
+
+/// Iterator over the values of a [`SizedSlab`], created by [`SizedSlab::iter`].
+pub struct Iter<'a, T, const N: usize>
+where
+    Const<N>: ValidK,
+{
+    slab: &'a SizedSlab<T, N>,
+    remaining: u64,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N>
+where
+    Const<N>: ValidK,
+{
+    type Item = (SlabKey, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let index = self.remaining.trailing_zeros() as usize;
+        self.remaining &= self.remaining - 1;
+
+        let key = SlabKey::new(index, self.slab.generations[index]);
+        let value = unsafe { self.slab.slots[index].assume_init_ref() };
+
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining.count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+/// Mutable iterator over the values of a [`SizedSlab`], created by [`SizedSlab::iter_mut`].
+pub struct IterMut<'a, T> {
+    slots: core::iter::Enumerate<core::slice::IterMut<'a, MaybeUninit<T>>>,
+    generations: &'a [u32],
+    used: u64,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = (SlabKey, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.slots.by_ref() {
+            if self.used & (1 << index) == 0 {
+                continue;
+            }
+
+            let key = SlabKey::new(index, self.generations[index]);
+            return Some((key, unsafe { slot.assume_init_mut() }));
+        }
+
+        None
+    }
+}
+
+/// Serializes the slab as a sequence of `(generation, value)` slots, `value` being `None` for a
+/// vacant slot.
+///
+/// Trailing slots that never held a value are left out. Keeping the generations of vacant slots
+/// means stale keys stay stale once the slab is deserialized.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for SizedSlab<T, N>
+where
+    Const<N>: ValidK,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let end = (0..N)
+            .rposition(|index| self.is_used(index) || self.generations[index] != 0)
+            .map_or(0, |index| index + 1);
+
+        serializer.collect_seq((0..end).map(|index| {
+            let value = self
+                .is_used(index)
+                .then(|| unsafe { self.slots[index].assume_init_ref() });
+
+            (self.generations[index], value)
+        }))
+    }
+}
+
+/// Deserializes a sequence of at most `N` `(generation, value)` slots, so that every key handed
+/// out before serialization refers to the same value.
+///
+/// A longer sequence is rejected with an `invalid_length` error instead of being truncated.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for SizedSlab<T, N>
+where
+    Const<N>: ValidK,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct SlabVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> Visitor<'de> for SlabVisitor<T, N>
+        where
+            Const<N>: ValidK,
+        {
+            type Value = SizedSlab<T, N>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a sequence of at most {N} slots")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                if let Some(hint) = seq.size_hint()
+                    && hint > N
+                {
+                    return Err(A::Error::invalid_length(hint, &self));
+                }
+
+                let mut slab = SizedSlab::new();
+                let mut index = 0;
+
+                while let Some((generation, value)) = seq.next_element::<(u32, Option<T>)>()? {
+                    if index == N {
+                        return Err(A::Error::invalid_length(N + 1, &self));
+                    }
+
+                    slab.generations[index] = generation;
+                    if let Some(value) = value {
+                        slab.slots[index].write(value);
+                        slab.used |= 1 << index;
+                        slab.len += 1;
+                    }
+
+                    index += 1;
+                }
+
+                Ok(slab)
+            }
+        }
+
+        deserializer.deserialize_seq(SlabVisitor(PhantomData))
+    }
+}
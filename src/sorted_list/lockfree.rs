@@ -0,0 +1,454 @@
+//! Lock-free sorted linked list.
+//!
+//! This module provides [`LockFreeSortedList`], a concurrent ordered set following the
+//! Harris-Michael algorithm. It suits read-mostly registries, such as subscription tables,
+//! looked up far more often than they are updated by many threads at once.
+//!
+//! # Overview
+//!
+//! Nodes are kept sorted in a singly linked list. Removing a node takes two steps:
+//! 1. The lowest bit of its `next` pointer is set (the node is *marked*), which logically
+//!    removes it and forbids any insertion after it.
+//! 2. The predecessor is swung past the node with a CAS (the node is *unlinked*).
+//!
+//! Any thread traversing the list unlinks the marked nodes it meets, so a remover preempted
+//! between both steps never blocks others. Lookups only write to the list to unlink such nodes.
+//!
+//! Unlinked nodes are handed to the [`Reclaim`] scheme owned by the list and dropped once no
+//! thread can still reach them. The scheme is a type parameter defaulting to the epoch
+//! [`Collector`]; a [`HazardDomain`](crate::reclaim::HazardDomain) bounds the memory held back
+//! by a stalled thread instead. Traversals protect the node owning the followed link, the
+//! current node and its successor, so they use three slots of a guard.
+//!
+//! Iteration is weakly consistent: an iterator borrows a [`Guard`] obtained from
+//! [`pin`](LockFreeSortedList::pin) and yields every element present for the whole traversal,
+//! in order, and may or may not yield elements inserted or removed concurrently. Since a
+//! hazard guard only protects a few nodes at a time, borrowing elements with `iter` and `get`
+//! requires the epoch collector; [`snapshot`](LockFreeSortedList::snapshot) works with any
+//! scheme.
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::sorted_list::LockFreeSortedList;
+//!
+//! static SUBSCRIBERS: LockFreeSortedList<(u32, u64)> = LockFreeSortedList::new();
+//!
+//! SUBSCRIBERS.insert((7, 42));
+//! assert!(SUBSCRIBERS.contains(&(7, 42)));
+//!
+//! let guard = SUBSCRIBERS.pin();
+//! for (topic, client) in SUBSCRIBERS.iter(&guard) {
+//!     println!("{topic}: {client}");
+//! }
+//! ```
+
+use crate::reclaim::epoch::Guard;
+use crate::reclaim::{Collector, Reclaim, ReclaimGuard};
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use core::borrow::Borrow;
+use core::cmp::Ordering as CmpOrdering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+/// Bit of a `next` pointer marking its node as logically removed.
+const MARK: usize = 1;
+
+/// A list node, aligned so the lowest bit of a pointer to it is free for the mark.
+#[repr(align(2))]
+struct Node<T> {
+    value: T,
+    next: AtomicPtr<Node<T>>,
+}
+
+fn is_marked<T>(ptr: *mut Node<T>) -> bool {
+    ptr.addr() & MARK != 0
+}
+
+fn marked<T>(ptr: *mut Node<T>) -> *mut Node<T> {
+    ptr.map_addr(|addr| addr | MARK)
+}
+
+fn unmarked<T>(ptr: *mut Node<T>) -> *mut Node<T> {
+    ptr.map_addr(|addr| addr & !MARK)
+}
+
+/// Position found by a search: the link pointing at `current`, which is the first node not
+/// lower than the searched value.
+struct Position<'a, T> {
+    prev: &'a AtomicPtr<Node<T>>,
+    current: *mut Node<T>,
+    found: bool,
+}
+
+/// A lock-free sorted set of unique values.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of elements, ordered by `Ord`
+/// - `R`: Reclamation scheme deferring the destruction of unlinked nodes
+///
+/// # Fields
+///
+/// - `head`: Link to the first node, never marked
+/// - `len`: Number of elements, updated after each successful insertion or removal
+/// - `reclaim`: Reclamation scheme of the unlinked nodes
+pub struct LockFreeSortedList<T, R: Reclaim = Collector> {
+    head: AtomicPtr<Node<T>>,
+    len: AtomicUsize,
+    reclaim: R,
+    _owns: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: values are shared between threads through `&T` and dropped by whichever thread
+// unlinks or collects their node.
+unsafe impl<T: Send + Sync, R: Reclaim + Send> Send for LockFreeSortedList<T, R> {}
+unsafe impl<T: Send + Sync, R: Reclaim> Sync for LockFreeSortedList<T, R> {}
+
+impl<T, R: Reclaim + Default> Default for LockFreeSortedList<T, R> {
+    fn default() -> Self {
+        Self::with_reclaim(R::default())
+    }
+}
+
+impl<T, R: Reclaim> Drop for LockFreeSortedList<T, R> {
+    fn drop(&mut self) {
+        // Unlinked nodes belong to the reclamation scheme; only the reachable ones are freed here.
+        let mut current = unmarked(*self.head.get_mut());
+
+        while !current.is_null() {
+            let mut node = unsafe { Box::from_raw(current) };
+            current = unmarked(*node.next.get_mut());
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for LockFreeSortedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let guard = self.pin();
+        f.debug_set().entries(self.iter(&guard)).finish()
+    }
+}
+
+impl<T> LockFreeSortedList<T> {
+    /// Creates an empty list reclaiming nodes through its own epoch collector.
+    pub const fn new() -> Self {
+        Self::with_reclaim(Collector::new())
+    }
+
+    /// Pins the collector of the list, keeping references returned by `iter` and `get` valid
+    /// while the guard is alive.
+    pub fn pin(&self) -> Guard<'_> {
+        self.reclaim.pin()
+    }
+
+    /// Returns an iterator over the elements in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `guard` was not obtained from [`pin`](Self::pin) on this list.
+    pub fn iter<'g>(&'g self, guard: &'g Guard<'_>) -> Iter<'g, T> {
+        self.check_guard(guard);
+
+        Iter {
+            current: self.head.load(Ordering::Acquire),
+            _guard: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the element equal to `value`, valid while `guard` is alive.
+    ///
+    /// Unlike [`contains`](Self::contains), the lookup skips marked nodes without unlinking
+    /// them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `guard` was not obtained from [`pin`](Self::pin) on this list.
+    pub fn get<'g, Q>(&'g self, value: &Q, guard: &'g Guard<'_>) -> Option<&'g T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.check_guard(guard);
+
+        let mut current = self.head.load(Ordering::Acquire);
+
+        while !current.is_null() {
+            // SAFETY: `current` was reachable after the guard was pinned.
+            let node = unsafe { &*current };
+            let next = node.next.load(Ordering::Acquire);
+
+            match node.value.borrow().cmp(value) {
+                CmpOrdering::Less => current = unmarked(next),
+                CmpOrdering::Equal if !is_marked(next) => return Some(&node.value),
+                _ => return None,
+            }
+        }
+
+        None
+    }
+
+    fn check_guard(&self, guard: &Guard<'_>) {
+        assert!(
+            ptr::eq(guard.collector(), &self.reclaim),
+            "guard pins the collector of another list"
+        );
+    }
+}
+
+impl<T, R: Reclaim> LockFreeSortedList<T, R> {
+    /// Creates an empty list reclaiming nodes through `reclaim`.
+    pub const fn with_reclaim(reclaim: R) -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+            len: AtomicUsize::new(0),
+            reclaim,
+            _owns: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements.
+    ///
+    /// The count is exact when no operation runs concurrently, approximate otherwise.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the list holds no element.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        unmarked(self.head.load(Ordering::Acquire)).is_null()
+    }
+}
+
+impl<T: Ord + Send, R: Reclaim> LockFreeSortedList<T, R> {
+    /// Searches the first node not lower than `value`, unlinking the marked nodes met on the way.
+    fn find<'a, Q>(&'a self, value: &Q, guard: &'a R::Guard<'_>) -> Position<'a, T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.walk(guard, |element| match element.borrow().cmp(value) {
+            CmpOrdering::Less => None,
+            ordering => Some(ordering == CmpOrdering::Equal),
+        })
+    }
+
+    /// Walks the list from the head, unlinking the marked nodes met on the way, and stops at
+    /// the first node for which `stop` returns `Some(found)`.
+    ///
+    /// A walk starts over from the head whenever the list changed under it, so `stop` may see
+    /// the same element more than once.
+    fn walk<'a>(
+        &'a self,
+        guard: &'a R::Guard<'_>,
+        mut stop: impl FnMut(&T) -> Option<bool>,
+    ) -> Position<'a, T> {
+        'retry: loop {
+            // Slots protecting the node owning `prev`, `current` and the successor of `current`.
+            let (mut prev_slot, mut current_slot, mut next_slot) = (0, 1, 2);
+            let mut prev = &self.head;
+            let mut current = guard.protect(current_slot, prev);
+
+            loop {
+                if current.is_null() {
+                    return Position {
+                        prev,
+                        current,
+                        found: false,
+                    };
+                }
+
+                // SAFETY: `current` is protected and was linked when its link was validated.
+                let node = unsafe { &*current };
+                let next = guard.protect(next_slot, &node.next);
+
+                if is_marked(next) {
+                    let successor = unmarked(next);
+
+                    match prev.compare_exchange(
+                        current,
+                        successor,
+                        Ordering::AcqRel,
+                        Ordering::Acquire,
+                    ) {
+                        Ok(_) => {
+                            // SAFETY: the successful CAS made this thread the only unlinker.
+                            // The successor, protected before the CAS, was still linked then.
+                            unsafe { guard.defer_drop(current) };
+                            current = successor;
+                            mem::swap(&mut current_slot, &mut next_slot);
+                            continue;
+                        }
+                        // The predecessor changed or got marked itself.
+                        Err(_) => continue 'retry,
+                    }
+                }
+
+                match stop(&node.value) {
+                    None => {
+                        prev = &node.next;
+                        current = next;
+                        (prev_slot, current_slot, next_slot) = (current_slot, next_slot, prev_slot);
+                    }
+                    Some(found) => {
+                        return Position {
+                            prev,
+                            current,
+                            found,
+                        };
+                    }
+                }
+            }
+        }
+    }
+
+    /// Inserts a value, keeping the list sorted.
+    ///
+    /// Returns `false`, dropping `value`, if an equal element is already present.
+    pub fn insert(&self, value: T) -> bool {
+        let guard = self.reclaim.pin();
+        let node = Box::into_raw(Box::new(Node {
+            value,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }));
+
+        loop {
+            // SAFETY: `node` is not published yet, this thread owns it.
+            let position = self.find(unsafe { &(*node).value }, &guard);
+
+            if position.found {
+                drop(unsafe { Box::from_raw(node) });
+                return false;
+            }
+
+            unsafe { (*node).next.store(position.current, Ordering::Relaxed) };
+
+            if position
+                .prev
+                .compare_exchange(position.current, node, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                self.len.fetch_add(1, Ordering::Relaxed);
+                return true;
+            }
+        }
+    }
+
+    /// Removes the element equal to `value`.
+    ///
+    /// Returns `false` if no such element is present.
+    pub fn remove<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let guard = self.reclaim.pin();
+
+        loop {
+            let position = self.find(value, &guard);
+
+            if !position.found {
+                return false;
+            }
+
+            // SAFETY: the search left `current` protected.
+            let node = unsafe { &*position.current };
+            let next = node.next.load(Ordering::Acquire);
+
+            if is_marked(next)
+                || node
+                    .next
+                    .compare_exchange(next, marked(next), Ordering::AcqRel, Ordering::Acquire)
+                    .is_err()
+            {
+                // Another thread removed the node or inserted after it; search again.
+                continue;
+            }
+
+            self.len.fetch_sub(1, Ordering::Relaxed);
+
+            if position
+                .prev
+                .compare_exchange(position.current, next, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                // SAFETY: the successful CAS made this thread the only unlinker.
+                unsafe { guard.defer_drop(position.current) };
+            } else {
+                // Let a new search unlink the marked node.
+                self.find(value, &guard);
+            }
+
+            return true;
+        }
+    }
+
+    /// Returns `true` if an element equal to `value` is present.
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let guard = self.reclaim.pin();
+        self.find(value, &guard).found
+    }
+
+    /// Clones the elements into a vector, in ascending order.
+    pub fn snapshot(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let guard = self.reclaim.pin();
+        let mut values: Vec<T> = Vec::new();
+
+        // A restarted walk visits the head again; the elements already cloned are skipped.
+        self.walk(&guard, |value| {
+            if values.last().is_none_or(|last| value > last) {
+                values.push(value.clone());
+            }
+            None
+        });
+
+        values
+    }
+}
+
+/// Iterator over the elements of a [`LockFreeSortedList`], in ascending order.
+///
+/// # Fields
+///
+/// - `current`: Next node to visit
+pub struct Iter<'g, T> {
+    current: *mut Node<T>,
+    _guard: PhantomData<&'g T>,
+}
+
+impl<'g, T> Iterator for Iter<'g, T> {
+    type Item = &'g T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.current.is_null() {
+            // SAFETY: the guard borrowed for `'g` keeps every node reached from the head alive.
+            let node: &'g Node<T> = unsafe { &*self.current };
+            let next = node.next.load(Ordering::Acquire);
+            self.current = unmarked(next);
+
+            if !is_marked(next) {
+                return Some(&node.value);
+            }
+        }
+
+        None
+    }
+}
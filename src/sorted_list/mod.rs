@@ -0,0 +1,12 @@
+//! Sorted linked lists for concurrent sets.
+//!
+//! Provides ordered lists keeping their elements unique and sorted.
+//! - `lockfree`: Harris-Michael lock-free list reclaiming nodes through an epoch `Collector`
+//!   by default, or any other `Reclaim` scheme
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+
+pub mod lockfree;
+
+pub use lockfree::{Iter, LockFreeSortedList};
@@ -0,0 +1,718 @@
+//! Unbounded ordered map made of sorted leaves.
+//!
+//! This module provides [`SortedMap`], the growable counterpart of
+//! [`SizedSortedMap`](super::sized::SizedSortedMap).
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+//!
+//! # Overview
+//!
+//! The map is laid out like the leaf level of a B+ tree: a vector of boxed leaves, each a
+//! `SizedSortedMap` of up to `LEAF` entries, with every key of a leaf lower than the keys of
+//! the next one. A lookup binary searches the leaves by their last key, then the keys of the
+//! leaf, so insertion and removal only shift the entries of one leaf:
+//! - A full leaf is split in two halves before an insertion.
+//! - A leaf emptied by a removal is dropped, and a leaf falling under a quarter of its
+//!   capacity is folded into a neighbour when both fit in one leaf.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::sorted_map::SortedMap;
+//!
+//! let mut routes: SortedMap<u32, &str> = SortedMap::new();
+//! routes.insert(0x0a00_0000, "lan");
+//! routes.insert(0xc0a8_0000, "home");
+//!
+//! assert_eq!(routes.floor(&0x0a00_0042), Some((&0x0a00_0000, &"lan")));
+//! assert_eq!(routes.ceiling(&0x0b00_0000), Some((&0xc0a8_0000, &"home")));
+//! ```
+
+use super::sized::{self, SizedSortedMap};
+
+use alloc::boxed::Box;
+use alloc::vec::{self, Vec};
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem;
+use core::ops::{Index, RangeBounds};
+use core::slice;
+
+/// Capacity of each leaf.
+const LEAF: usize = 32;
+
+/// A leaf of the map, holding a contiguous run of keys.
+type Leaf<K, V> = SizedSortedMap<K, V, LEAF>;
+
+/// An unbounded map keeping its entries sorted by key.
+///
+/// # Type Parameters
+///
+/// - `K`: The type of keys, ordered by `Ord`
+/// - `V`: The type of values
+///
+/// # Fields
+///
+/// - `leaves`: Non-empty leaves, in ascending key order
+/// - `len`: Total number of entries
+pub struct SortedMap<K, V> {
+    leaves: Vec<Box<Leaf<K, V>>>,
+    len: usize,
+}
+
+impl<K, V> Default for SortedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for SortedMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            leaves: self.leaves.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for SortedMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Two maps are equal if they hold the same entries, however they are split in leaves.
+impl<K: PartialEq, V: PartialEq> PartialEq for SortedMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq> Eq for SortedMap<K, V> {}
+
+impl<K, Q, V> Index<&Q> for SortedMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in SortedMap")
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SortedMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for SortedMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a SortedMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<K, V> IntoIterator for SortedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter {
+            leaves: self.leaves.into_iter(),
+            front: Leaf::new().into_iter(),
+            back: Leaf::new().into_iter(),
+            len: self.len,
+        }
+    }
+}
+
+impl<K, V> SortedMap<K, V> {
+    /// Creates an empty map. No leaf is allocated until the first insertion.
+    pub const fn new() -> Self {
+        Self {
+            leaves: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes and drops every entry.
+    pub fn clear(&mut self) {
+        self.leaves.clear();
+        self.len = 0;
+    }
+
+    /// Returns the entry with the lowest key, or `None` if the map is empty.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.leaves.first()?.first()
+    }
+
+    /// Returns the entry with the highest key, or `None` if the map is empty.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.leaves.last()?.last()
+    }
+
+    /// Returns an iterator over the entries, in ascending key order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            front: sized::Iter::default(),
+            leaves: self.leaves.iter(),
+            back: sized::Iter::default(),
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator over the keys, in ascending order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values, in ascending order of their keys.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<K: Ord, V> SortedMap<K, V> {
+    /// Returns the position of the first leaf whose last key is not lower than `key`, or the
+    /// number of leaves if every key is lower.
+    fn leaf_of<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.leaves
+            .partition_point(|leaf| leaf.keys().last().is_some_and(|last| last.borrow() < key))
+    }
+
+    /// Drops the leaf at `index` if it is empty, or folds it into a neighbour once it falls
+    /// under a quarter of its capacity and both fit in one leaf.
+    fn rebalance(&mut self, index: usize) {
+        if self.leaves[index].is_empty() {
+            self.leaves.remove(index);
+            return;
+        }
+
+        if self.leaves[index].len() >= LEAF / 4 {
+            return;
+        }
+
+        let left = if index + 1 < self.leaves.len() {
+            index
+        } else if index > 0 {
+            index - 1
+        } else {
+            return;
+        };
+
+        let (head, tail) = self.leaves.split_at_mut(left + 1);
+        if head[left].merge(&mut tail[0]).is_ok() {
+            self.leaves.remove(left + 1);
+        }
+    }
+
+    /// Inserts a key-value pair, returning the previous value of the key if present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.leaves.is_empty() {
+            self.leaves.push(Box::default());
+        }
+
+        let mut index = self.leaf_of(&key).min(self.leaves.len() - 1);
+        let mut position = match self.leaves[index].search(&key) {
+            Ok(position) => {
+                let current = &mut self.leaves[index].values_mut()[position];
+                return Some(mem::replace(current, value));
+            }
+            Err(position) => position,
+        };
+
+        if self.leaves[index].is_full() {
+            let right = self.leaves[index].split_off(LEAF / 2);
+            self.leaves.insert(index + 1, Box::new(right));
+
+            if position > LEAF / 2 {
+                index += 1;
+                position -= LEAF / 2;
+            }
+        }
+
+        self.leaves[index].insert_at(position, key, value);
+        self.len += 1;
+
+        None
+    }
+
+    /// Returns a reference to the value of `key`, if present.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.leaves.get(self.leaf_of(key))?.get(key)
+    }
+
+    /// Returns the stored key and the value of `key`, if present.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.leaves.get(self.leaf_of(key))?.get_key_value(key)
+    }
+
+    /// Returns a mutable reference to the value of `key`, if present.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.leaf_of(key);
+        self.leaves.get_mut(index)?.get_mut(key)
+    }
+
+    /// Returns `true` if the map contains `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Removes `key` and returns its value, if present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes `key` and returns the stored key and its value, if present.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.leaf_of(key);
+        let entry = self.leaves.get_mut(index)?.remove_entry(key)?;
+
+        self.len -= 1;
+        self.rebalance(index);
+
+        Some(entry)
+    }
+
+    /// Removes and returns the entry with the lowest key, or `None` if the map is empty.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        let entry = self.leaves.first_mut()?.pop_first()?;
+
+        self.len -= 1;
+        self.rebalance(0);
+
+        Some(entry)
+    }
+
+    /// Removes and returns the entry with the highest key, or `None` if the map is empty.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        let entry = self.leaves.last_mut()?.pop_last()?;
+
+        self.len -= 1;
+        self.rebalance(self.leaves.len() - 1);
+
+        Some(entry)
+    }
+
+    /// Returns the entry with the greatest key less than or equal to `key`.
+    pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let count = self
+            .leaves
+            .partition_point(|leaf| leaf.keys().first().is_some_and(|k| k.borrow() <= key));
+
+        self.leaves[..count].last()?.floor(key)
+    }
+
+    /// Returns the entry with the least key greater than or equal to `key`.
+    pub fn ceiling<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.leaves.get(self.leaf_of(key))?.ceiling(key)
+    }
+
+    /// Returns an iterator over the entries whose keys fall in `range`, in ascending order.
+    ///
+    /// A range whose start lies after its end yields nothing.
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let (start, end) = (range.start_bound(), range.end_bound());
+        let count = self.leaves.len();
+
+        // Positions are (leaf, entry) pairs, `(count, 0)` standing for the end of the map.
+        let lower = self
+            .leaves
+            .partition_point(|leaf| leaf.lower_index(start) == leaf.len());
+        let first = match self.leaves.get(lower) {
+            Some(leaf) => (lower, leaf.lower_index(start)),
+            None => (count, 0),
+        };
+
+        let upper = self
+            .leaves
+            .partition_point(|leaf| leaf.upper_index(end) == leaf.len());
+        let last = match self.leaves.get(upper) {
+            Some(leaf) => (upper, leaf.upper_index(end)),
+            None => (count, 0),
+        };
+
+        if first >= last {
+            return Iter::default();
+        }
+
+        if first.0 == last.0 {
+            let front = self.leaves[first.0].iter_between(first.1, last.1);
+
+            return Iter {
+                len: front.len(),
+                front,
+                leaves: [].iter(),
+                back: sized::Iter::default(),
+            };
+        }
+
+        let front = self.leaves[first.0].iter_between(first.1, self.leaves[first.0].len());
+        let leaves = self.leaves[first.0 + 1..last.0].iter();
+        let back = match self.leaves.get(last.0) {
+            Some(leaf) => leaf.iter_between(0, last.1),
+            None => sized::Iter::default(),
+        };
+
+        Iter {
+            len: front.len() + leaves.clone().map(|l| l.len()).sum::<usize>() + back.len(),
+            front,
+            leaves,
+            back,
+        }
+    }
+
+    /// Splits the map in two at `key`: entries with keys greater than or equal to `key` are
+    /// moved into the returned map, the lower ones stay in `self`.
+    pub fn split_at_key<Q>(&mut self, key: &Q) -> Self
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.leaf_of(key);
+        if index == self.leaves.len() {
+            return Self::new();
+        }
+
+        let mut leaves = self.leaves.split_off(index + 1);
+        let right = self.leaves[index].split_at_key(key);
+
+        if !right.is_empty() {
+            leaves.insert(0, Box::new(right));
+        }
+        if self.leaves[index].is_empty() {
+            self.leaves.pop();
+        }
+
+        let len = leaves.iter().map(|leaf| leaf.len()).sum();
+        self.len -= len;
+
+        Self { leaves, len }
+    }
+
+    /// Moves every entry of `other` into this map, leaving `other` empty.
+    ///
+    /// When both maps hold a key, the key of `self` is kept with the value of `other`. When
+    /// the keys of `other` all sort after or before those of `self`, its leaves are moved as
+    /// a whole, without touching the entries.
+    pub fn merge(&mut self, other: &mut Self) {
+        let mut other = mem::take(other);
+
+        let after = match (self.last(), other.first()) {
+            (Some((last, _)), Some((first, _))) => last < first,
+            _ => true,
+        };
+        let before = match (self.first(), other.last()) {
+            (Some((first, _)), Some((last, _))) => last < first,
+            _ => true,
+        };
+
+        if after {
+            self.leaves.append(&mut other.leaves);
+        } else if before {
+            other.leaves.append(&mut self.leaves);
+            self.leaves = mem::take(&mut other.leaves);
+        } else {
+            self.extend(other);
+            return;
+        }
+
+        self.len += other.len;
+    }
+
+    /// Keeps only the entries for which `keep` returns `true`, preserving their order.
+    ///
+    /// Leaves left sparse are coalesced with their neighbours afterwards.
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &mut V) -> bool) {
+        for leaf in &mut self.leaves {
+            leaf.retain(&mut keep);
+        }
+
+        self.leaves.retain(|leaf| !leaf.is_empty());
+        self.len = self.leaves.iter().map(|leaf| leaf.len()).sum();
+
+        let mut index = 0;
+        while index + 1 < self.leaves.len() {
+            let (head, tail) = self.leaves.split_at_mut(index + 1);
+
+            if head[index].merge(&mut tail[0]).is_ok() {
+                self.leaves.remove(index + 1);
+            } else {
+                index += 1;
+            }
+        }
+    }
+}
+
+/// Iterator over the entries of a [`SortedMap`], in ascending key order.
+///
+/// # Fields
+///
+/// - `front`: Entries of the leaf being walked from the front
+/// - `leaves`: Leaves not walked yet
+/// - `back`: Entries of the leaf being walked from the back
+/// - `len`: Number of entries not yielded yet
+pub struct Iter<'a, K, V> {
+    front: sized::Iter<'a, K, V>,
+    leaves: slice::Iter<'a, Box<Leaf<K, V>>>,
+    back: sized::Iter<'a, K, V>,
+    len: usize,
+}
+
+impl<K, V> Default for Iter<'_, K, V> {
+    fn default() -> Self {
+        Self {
+            front: sized::Iter::default(),
+            leaves: [].iter(),
+            back: sized::Iter::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            leaves: self.leaves.clone(),
+            back: self.back.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.front.next() {
+                self.len -= 1;
+                return Some(entry);
+            }
+
+            match self.leaves.next() {
+                Some(leaf) => self.front = leaf.iter(),
+                None => {
+                    let entry = self.back.next()?;
+                    self.len -= 1;
+                    return Some(entry);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.back.next_back() {
+                self.len -= 1;
+                return Some(entry);
+            }
+
+            match self.leaves.next_back() {
+                Some(leaf) => self.back = leaf.iter(),
+                None => {
+                    let entry = self.front.next_back()?;
+                    self.len -= 1;
+                    return Some(entry);
+                }
+            }
+        }
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// Owning iterator over the entries of a [`SortedMap`], in ascending key order.
+///
+/// # Fields
+///
+/// - `leaves`: Leaves not walked yet
+/// - `front`: Entries of the leaf being walked from the front
+/// - `back`: Entries of the leaf being walked from the back
+/// - `len`: Number of entries not yielded yet
+pub struct IntoIter<K, V> {
+    leaves: vec::IntoIter<Box<Leaf<K, V>>>,
+    front: sized::IntoIter<K, V, LEAF>,
+    back: sized::IntoIter<K, V, LEAF>,
+    len: usize,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        loop {
+            if let Some(entry) = self.front.next() {
+                self.len -= 1;
+                return Some(entry);
+            }
+
+            match self.leaves.next() {
+                Some(leaf) => self.front = (*leaf).into_iter(),
+                None => {
+                    let entry = self.back.next()?;
+                    self.len -= 1;
+                    return Some(entry);
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IntoIter<K, V> {
+    fn next_back(&mut self) -> Option<(K, V)> {
+        loop {
+            if let Some(entry) = self.back.next_back() {
+                self.len -= 1;
+                return Some(entry);
+            }
+
+            match self.leaves.next_back() {
+                Some(leaf) => self.back = (*leaf).into_iter(),
+                None => {
+                    let entry = self.front.next_back()?;
+                    self.len -= 1;
+                    return Some(entry);
+                }
+            }
+        }
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K, V> FusedIterator for IntoIter<K, V> {}
+
+/// Serializes the entries as a map, in ascending key order.
+#[cfg(feature = "serde")]
+impl<K: serde::Serialize, V: serde::Serialize> serde::Serialize for SortedMap<K, V> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+/// Deserializes a map in any order. When a key appears twice, the last value wins.
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::Deserialize<'de> for SortedMap<K, V>
+where
+    K: serde::Deserialize<'de> + Ord,
+    V: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+        use serde::de::{MapAccess, Visitor};
+
+        struct MapVisitor<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K, V> Visitor<'de> for MapVisitor<K, V>
+        where
+            K: serde::Deserialize<'de> + Ord,
+            V: serde::Deserialize<'de>,
+        {
+            type Value = SortedMap<K, V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut map = SortedMap::new();
+
+                while let Some((key, value)) = access.next_entry()? {
+                    map.insert(key, value);
+                }
+
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,18 @@
+//! Ordered maps.
+//!
+//! Provides both fixed-size and dynamic maps iterating over their entries in key order.
+//! - `sized`: Fixed-size map backed by sorted arrays (no allocation)
+//! - `dynamic`: Unbounded map made of sorted leaves, like the leaf level of a B+ tree (std or alloc)
+//!
+//! Both maps offer `range` queries, `first`/`last`, `floor`/`ceiling` lookups, `split_at_key`
+//! and `merge`, for instance to keep routing or timer tables ordered.
+
+pub mod sized;
+
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+
+pub use sized::SizedSortedMap;
+
+#[cfg(feature = "alloc")]
+pub use dynamic::SortedMap;
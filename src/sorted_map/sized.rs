@@ -0,0 +1,818 @@
+//! Fixed-capacity ordered map backed by sorted arrays.
+//!
+//! This module provides [`SizedSortedMap`], an associative container storing up to `N`
+//! entries inline, without allocation, and iterating over them in key order.
+//!
+//! # Overview
+//!
+//! Keys and values live in two parallel arrays, both kept sorted by key:
+//! - Lookups binary search the keys, in O(log N).
+//! - Insertion and removal shift the entries after the position by one slot, in O(N). With
+//!   the small capacities of routing or timer tables, the shift is a short `memmove`.
+//! - Ordered queries ([`range`](SizedSortedMap::range), [`floor`](SizedSortedMap::floor),
+//!   [`ceiling`](SizedSortedMap::ceiling), [`first`](SizedSortedMap::first)) resolve to
+//!   positions in the arrays, so the keys and values in a range are contiguous slices.
+//!
+//! [`merge`](SizedSortedMap::merge) and [`merge_keep_lowest`](SizedSortedMap::merge_keep_lowest)
+//! combine two maps with the two-cursor merge of
+//! [`keep_lowest_array_by`](crate::array::core::keep_lowest_array_by), in O(N).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::sorted_map::SizedSortedMap;
+//!
+//! let mut timers: SizedSortedMap<u64, &str, 8> = SizedSortedMap::new();
+//! timers.insert(250, "retransmit").unwrap();
+//! timers.insert(100, "keepalive").unwrap();
+//! timers.insert(900, "expire").unwrap();
+//!
+//! assert_eq!(timers.first(), Some((&100, &"keepalive")));
+//! assert_eq!(timers.floor(&300), Some((&250, &"retransmit")));
+//! assert_eq!(timers.range(..=250).count(), 2);
+//! ```
+
+use crate::CapacityError;
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::ops::{Bound, Index, RangeBounds};
+use core::ptr;
+use core::slice;
+
+/// A fixed-capacity map keeping its entries sorted by key.
+///
+/// # Type Parameters
+///
+/// - `K`: The type of keys, ordered by `Ord`
+/// - `V`: The type of values
+/// - `N`: Compile-time capacity
+///
+/// # Fields
+///
+/// - `keys`: Array of keys, the first `len` initialized in ascending order
+/// - `values`: Array of values, the value of `keys[i]` in `values[i]`
+/// - `len`: Current number of entries
+pub struct SizedSortedMap<K, V, const N: usize> {
+    keys: [MaybeUninit<K>; N],
+    values: [MaybeUninit<V>; N],
+    len: usize,
+}
+
+impl<K, V, const N: usize> Default for SizedSortedMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const N: usize> Drop for SizedSortedMap<K, V, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<K: Clone, V: Clone, const N: usize> Clone for SizedSortedMap<K, V, N> {
+    fn clone(&self) -> Self {
+        let mut map = Self::new();
+
+        for (key, value) in self {
+            map.push_unchecked(key.clone(), value.clone());
+        }
+
+        map
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for SizedSortedMap<K, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq, const N: usize> PartialEq for SizedSortedMap<K, V, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys() == other.keys() && self.values() == other.values()
+    }
+}
+
+impl<K: Eq, V: Eq, const N: usize> Eq for SizedSortedMap<K, V, N> {}
+
+impl<K, Q, V, const N: usize> Index<&Q> for SizedSortedMap<K, V, N>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found in SizedSortedMap")
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a SizedSortedMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, const N: usize> IntoIterator for &'a mut SizedSortedMap<K, V, N> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, const N: usize> IntoIterator for SizedSortedMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N>;
+
+    fn into_iter(self) -> IntoIter<K, V, N> {
+        let map = ManuallyDrop::new(self);
+
+        // SAFETY: the map is never dropped, so the entries are moved into the iterator once.
+        IntoIter {
+            keys: unsafe { ptr::read(&map.keys) },
+            values: unsafe { ptr::read(&map.values) },
+            front: 0,
+            back: map.len,
+        }
+    }
+}
+
+impl<K, V, const N: usize> SizedSortedMap<K, V, N> {
+    /// Creates an empty map with capacity `N`.
+    pub const fn new() -> Self {
+        Self {
+            keys: [const { MaybeUninit::uninit() }; N],
+            values: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the number of entries currently stored.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the map holds `N` entries.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the maximum number of entries the map can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the keys in ascending order.
+    #[inline]
+    pub fn keys(&self) -> &[K] {
+        unsafe { slice::from_raw_parts(self.keys.as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the values, ordered by their keys.
+    #[inline]
+    pub fn values(&self) -> &[V] {
+        unsafe { slice::from_raw_parts(self.values.as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the values as a mutable slice, ordered by their keys.
+    #[inline]
+    pub fn values_mut(&mut self) -> &mut [V] {
+        unsafe { slice::from_raw_parts_mut(self.values.as_mut_ptr().cast(), self.len) }
+    }
+
+    /// Removes and drops every entry.
+    pub fn clear(&mut self) {
+        let len = mem::replace(&mut self.len, 0);
+
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.keys.as_mut_ptr().cast::<K>(),
+                len,
+            ));
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.values.as_mut_ptr().cast::<V>(),
+                len,
+            ));
+        }
+    }
+
+    /// Returns the entry with the lowest key, or `None` if the map is empty.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.iter().next()
+    }
+
+    /// Returns the entry with the highest key, or `None` if the map is empty.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.iter().next_back()
+    }
+
+    /// Removes and returns the entry with the lowest key, or `None` if the map is empty.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        (!self.is_empty()).then(|| self.remove_at(0))
+    }
+
+    /// Removes and returns the entry with the highest key, or `None` if the map is empty.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        (!self.is_empty()).then(|| self.remove_at(self.len - 1))
+    }
+
+    /// Returns an iterator over the entries, in ascending key order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.iter_between(0, self.len)
+    }
+
+    /// Returns an iterator over the entries with mutable values, in ascending key order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let keys = unsafe { slice::from_raw_parts(self.keys.as_ptr().cast(), self.len) };
+
+        IterMut {
+            keys: keys.iter(),
+            values: self.values_mut().iter_mut(),
+        }
+    }
+
+    /// Keeps only the entries for which `keep` returns `true`, preserving their order.
+    ///
+    /// Rejected entries are dropped and the kept ones compacted in a single pass.
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &mut V) -> bool) {
+        // Entries are only leaked, never dropped twice, if `keep` panics.
+        let len = mem::replace(&mut self.len, 0);
+        let mut kept = 0;
+
+        for index in 0..len {
+            let key = self.keys[index].as_mut_ptr();
+            let value = self.values[index].as_mut_ptr();
+
+            if keep(unsafe { &*key }, unsafe { &mut *value }) {
+                unsafe {
+                    ptr::copy(key, self.keys[kept].as_mut_ptr(), 1);
+                    ptr::copy(value, self.values[kept].as_mut_ptr(), 1);
+                }
+                kept += 1;
+            } else {
+                unsafe {
+                    ptr::drop_in_place(key);
+                    ptr::drop_in_place(value);
+                }
+            }
+        }
+
+        self.len = kept;
+    }
+
+    /// Returns an iterator over the entries at positions `start..end`.
+    pub(crate) fn iter_between(&self, start: usize, end: usize) -> Iter<'_, K, V> {
+        Iter {
+            keys: self.keys()[start..end].iter(),
+            values: self.values()[start..end].iter(),
+        }
+    }
+
+    /// Moves the entries at positions `at..` into a new map.
+    pub(crate) fn split_off(&mut self, at: usize) -> Self {
+        let mut other = Self::new();
+        let count = self.len - at;
+
+        unsafe {
+            ptr::copy_nonoverlapping(self.keys[at..].as_ptr(), other.keys.as_mut_ptr(), count);
+            ptr::copy_nonoverlapping(self.values[at..].as_ptr(), other.values.as_mut_ptr(), count);
+        }
+
+        self.len = at;
+        other.len = count;
+
+        other
+    }
+
+    /// Stores an entry at `index`, shifting the following entries up by one slot.
+    ///
+    /// The map must not be full, and the key must sort between its neighbours.
+    pub(crate) fn insert_at(&mut self, index: usize, key: K, value: V) {
+        unsafe {
+            let keys = self.keys.as_mut_ptr().add(index);
+            let values = self.values.as_mut_ptr().add(index);
+
+            ptr::copy(keys, keys.add(1), self.len - index);
+            ptr::copy(values, values.add(1), self.len - index);
+
+            (*keys).write(key);
+            (*values).write(value);
+        }
+
+        self.len += 1;
+    }
+
+    /// Removes the entry at `index`, shifting the following entries down by one slot.
+    fn remove_at(&mut self, index: usize) -> (K, V) {
+        self.len -= 1;
+
+        unsafe {
+            let keys = self.keys.as_mut_ptr().add(index);
+            let values = self.values.as_mut_ptr().add(index);
+            let entry = ((*keys).assume_init_read(), (*values).assume_init_read());
+
+            ptr::copy(keys.add(1), keys, self.len - index);
+            ptr::copy(values.add(1), values, self.len - index);
+
+            entry
+        }
+    }
+
+    /// Appends an entry after the last one. The map must not be full, and the key must be
+    /// greater than every stored key.
+    fn push_unchecked(&mut self, key: K, value: V) {
+        self.keys[self.len].write(key);
+        self.values[self.len].write(value);
+        self.len += 1;
+    }
+}
+
+impl<K: Ord, V, const N: usize> SizedSortedMap<K, V, N> {
+    /// Binary searches `key`, returning its position if present, or the position where it
+    /// would be inserted.
+    pub(crate) fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.keys()
+            .binary_search_by(|probe| probe.borrow().cmp(key))
+    }
+
+    /// Returns the position of the first entry within the lower bound `start`.
+    pub(crate) fn lower_index<Q>(&self, start: Bound<&Q>) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match start {
+            Bound::Included(key) => self.keys().partition_point(|k| k.borrow() < key),
+            Bound::Excluded(key) => self.keys().partition_point(|k| k.borrow() <= key),
+            Bound::Unbounded => 0,
+        }
+    }
+
+    /// Returns the position right after the last entry within the upper bound `end`.
+    pub(crate) fn upper_index<Q>(&self, end: Bound<&Q>) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match end {
+            Bound::Included(key) => self.keys().partition_point(|k| k.borrow() <= key),
+            Bound::Excluded(key) => self.keys().partition_point(|k| k.borrow() < key),
+            Bound::Unbounded => self.len,
+        }
+    }
+
+    /// Inserts a key-value pair, returning the previous value of the key if present.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding the pair if the key is absent and the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+        match self.search(&key) {
+            Ok(index) => Ok(Some(mem::replace(
+                unsafe { self.values[index].assume_init_mut() },
+                value,
+            ))),
+            Err(_) if self.is_full() => Err(CapacityError::new((key, value))),
+            Err(index) => {
+                self.insert_at(index, key, value);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns a reference to the value of `key`, if present.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Returns the stored key and the value of `key`, if present.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some((&self.keys()[index], &self.values()[index]))
+    }
+
+    /// Returns a mutable reference to the value of `key`, if present.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(&mut self.values_mut()[index])
+    }
+
+    /// Returns `true` if the map contains `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(key).is_ok()
+    }
+
+    /// Removes `key` and returns its value, if present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes `key` and returns the stored key and its value, if present.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(self.remove_at(index))
+    }
+
+    /// Returns the entry with the greatest key less than or equal to `key`.
+    pub fn floor<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = match self.search(key) {
+            Ok(index) => index,
+            Err(index) => index.checked_sub(1)?,
+        };
+
+        Some((&self.keys()[index], &self.values()[index]))
+    }
+
+    /// Returns the entry with the least key greater than or equal to `key`.
+    pub fn ceiling<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (Ok(index) | Err(index)) = self.search(key);
+
+        self.iter_between(index, self.len).next()
+    }
+
+    /// Returns an iterator over the entries whose keys fall in `range`, in ascending order.
+    ///
+    /// A range whose start lies after its end yields nothing.
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let start = self.lower_index(range.start_bound());
+        let end = self.upper_index(range.end_bound()).max(start);
+
+        self.iter_between(start, end)
+    }
+
+    /// Splits the map in two at `key`: entries with keys greater than or equal to `key` are
+    /// moved into the returned map, the lower ones stay in `self`.
+    pub fn split_at_key<Q>(&mut self, key: &Q) -> Self
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let at = self.lower_index(Bound::Included(key));
+        self.split_off(at)
+    }
+
+    /// Moves every entry of `other` into this map, leaving `other` empty.
+    ///
+    /// When both maps hold a key, the key of `self` is kept with the value of `other`, as
+    /// repeated calls to [`insert`](Self::insert) would do.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` if the union of both maps holds more than `N` keys.
+    /// Both maps are left untouched in that case.
+    pub fn merge(&mut self, other: &mut Self) -> Result<(), CapacityError<()>> {
+        if self.union_len(other) > N {
+            return Err(CapacityError::new(()));
+        }
+
+        self.merge_with(other, true);
+        Ok(())
+    }
+
+    /// Merges the entries of both maps, keeping the `N` lowest keys in `self` and moving the
+    /// remaining ones into `other`.
+    ///
+    /// This is [`keep_lowest_array_by`](crate::array::core::keep_lowest_array_by) applied to
+    /// maps: both maps are walked with one cursor each and, on equal keys, the entry of `self`
+    /// wins and the one of `other` is dropped. No entry is lost to the capacity: the union
+    /// holds at most `2 * N` keys, so the highest ones always fit in `other`.
+    pub fn merge_keep_lowest(&mut self, other: &mut Self) {
+        self.merge_with(other, false);
+    }
+
+    /// Returns the number of distinct keys in `self` and `other`.
+    fn union_len(&self, other: &Self) -> usize {
+        let (left, right) = (self.keys(), other.keys());
+        let (mut i, mut j, mut count) = (0, 0, 0);
+
+        while i < left.len() && j < right.len() {
+            match left[i].cmp(&right[j]) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    i += 1;
+                    j += 1;
+                }
+            }
+
+            count += 1;
+        }
+
+        count + (left.len() - i) + (right.len() - j)
+    }
+
+    /// Two-cursor merge of both maps, filling `self` with the lowest keys and spilling the
+    /// rest into `other`. On equal keys the value of `other` replaces the one of `self` when
+    /// `overwrite` is set, and is dropped otherwise.
+    fn merge_with(&mut self, other: &mut Self, overwrite: bool) {
+        let mut left = mem::take(self).into_iter();
+        let mut right = mem::take(other).into_iter();
+
+        loop {
+            let order = match (left.front_key(), right.front_key()) {
+                (Some(a), Some(b)) => a.cmp(b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+
+            let entry = match order {
+                Ordering::Less => left.next(),
+                Ordering::Greater => right.next(),
+                Ordering::Equal => left.next().zip(right.next()).map(|(kept, replacing)| {
+                    let value = if overwrite { replacing.1 } else { kept.1 };
+                    (kept.0, value)
+                }),
+            };
+
+            if let Some((key, value)) = entry {
+                let target = if self.is_full() {
+                    &mut *other
+                } else {
+                    &mut *self
+                };
+                target.push_unchecked(key, value);
+            }
+        }
+    }
+}
+
+/// Iterator over the entries of a [`SizedSortedMap`], in ascending key order.
+///
+/// # Fields
+///
+/// - `keys`: Iterator over the remaining keys
+/// - `values`: Iterator over the remaining values
+pub struct Iter<'a, K, V> {
+    keys: slice::Iter<'a, K>,
+    values: slice::Iter<'a, V>,
+}
+
+impl<K, V> Default for Iter<'_, K, V> {
+    fn default() -> Self {
+        Self {
+            keys: [].iter(),
+            values: [].iter(),
+        }
+    }
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.keys.next()?, self.values.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some((self.keys.next_back()?, self.values.next_back()?))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// Iterator over the entries of a [`SizedSortedMap`] with mutable values.
+///
+/// # Fields
+///
+/// - `keys`: Iterator over the remaining keys
+/// - `values`: Iterator over the remaining values
+pub struct IterMut<'a, K, V> {
+    keys: slice::Iter<'a, K>,
+    values: slice::IterMut<'a, V>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.keys.next()?, self.values.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some((self.keys.next_back()?, self.values.next_back()?))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+/// Owning iterator over the entries of a [`SizedSortedMap`], in ascending key order.
+///
+/// # Fields
+///
+/// - `keys`: Keys moved out of the map
+/// - `values`: Values moved out of the map
+/// - `front`: Position of the next entry yielded by `next`
+/// - `back`: Position right after the next entry yielded by `next_back`
+pub struct IntoIter<K, V, const N: usize> {
+    keys: [MaybeUninit<K>; N],
+    values: [MaybeUninit<V>; N],
+    front: usize,
+    back: usize,
+}
+
+impl<K, V, const N: usize> IntoIter<K, V, N> {
+    /// Returns the key of the next entry yielded by `next`.
+    fn front_key(&self) -> Option<&K> {
+        (self.front < self.back).then(|| unsafe { self.keys[self.front].assume_init_ref() })
+    }
+}
+
+impl<K, V, const N: usize> Iterator for IntoIter<K, V, N> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let index = self.front;
+        self.front += 1;
+
+        Some(unsafe {
+            (
+                self.keys[index].assume_init_read(),
+                self.values[index].assume_init_read(),
+            )
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<K, V, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    fn next_back(&mut self) -> Option<(K, V)> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(unsafe {
+            (
+                self.keys[self.back].assume_init_read(),
+                self.values[self.back].assume_init_read(),
+            )
+        })
+    }
+}
+
+impl<K, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {}
+
+impl<K, V, const N: usize> FusedIterator for IntoIter<K, V, N> {}
+
+impl<K, V, const N: usize> Drop for IntoIter<K, V, N> {
+    fn drop(&mut self) {
+        for index in self.front..self.back {
+            unsafe {
+                self.keys[index].assume_init_drop();
+                self.values[index].assume_init_drop();
+            }
+        }
+    }
+}
+
+/// Serializes the entries as a map, in ascending key order.
+#[cfg(feature = "serde")]
+impl<K: serde::Serialize, V: serde::Serialize, const N: usize> serde::Serialize
+    for SizedSortedMap<K, V, N>
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+/// Deserializes a map of at most `N` distinct keys, in any order.
+///
+/// A map holding more keys is rejected with an `invalid_length` error instead of being
+/// truncated. When a key appears twice, the last value wins.
+#[cfg(feature = "serde")]
+impl<'de, K, V, const N: usize> serde::Deserialize<'de> for SizedSortedMap<K, V, N>
+where
+    K: serde::Deserialize<'de> + Ord,
+    V: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+        use serde::de::{Error, MapAccess, Visitor};
+
+        struct MapVisitor<K, V, const N: usize>(PhantomData<(K, V)>);
+
+        impl<'de, K, V, const N: usize> Visitor<'de> for MapVisitor<K, V, N>
+        where
+            K: serde::Deserialize<'de> + Ord,
+            V: serde::Deserialize<'de>,
+        {
+            type Value = SizedSortedMap<K, V, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a map of at most {N} entries")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+                let mut map = SizedSortedMap::new();
+
+                while let Some((key, value)) = access.next_entry()? {
+                    if map.insert(key, value).is_err() {
+                        return Err(A::Error::invalid_length(N + 1, &self));
+                    }
+                }
+
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor(PhantomData))
+    }
+}
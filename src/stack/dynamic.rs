@@ -0,0 +1,252 @@
+//! Unbounded lock-free stack with heap-allocated nodes.
+//!
+//! This module provides [`LockFreeStack`], the growable counterpart of
+//! [`SizedLockFreeStack`](super::sized::SizedLockFreeStack).
+//!
+//! # Overview
+//!
+//! Each push allocates a node and publishes it with a CAS on the head pointer. A popping
+//! thread protects the head through a [`Reclaim`] guard before reading its link, and hands
+//! the popped node to the guard instead of freeing it. Since a node cannot be freed and
+//! reallocated at the same address while a thread still protects it, a successful CAS always
+//! swings the head past the node the thread read, without any tag (ABA protection).
+//!
+//! The reclamation scheme is a type parameter defaulting to the epoch
+//! [`Collector`](crate::reclaim::Collector); a [`HazardDomain`](crate::reclaim::HazardDomain)
+//! bounds the memory held back by a stalled thread instead.
+//!
+//! **Note**: This module requires heap allocation: it is available with the `std` feature,
+//! or without it when the `alloc` feature is enabled.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::reclaim::HazardDomain;
+//! use datastructures::stack::LockFreeStack;
+//!
+//! let stack: LockFreeStack<u32, HazardDomain> = LockFreeStack::with_reclaim(HazardDomain::new());
+//! stack.push(1);
+//! stack.push(2);
+//!
+//! assert_eq!(stack.pop_all().collect::<Vec<_>>(), vec![2, 1]);
+//! ```
+
+use crate::reclaim::{Collector, Reclaim, ReclaimGuard};
+
+use alloc::boxed::Box;
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+/// A stack node. The value is moved out by the popping thread before the node is deferred.
+struct Node<T> {
+    value: ManuallyDrop<T>,
+    next: *mut Node<T>,
+}
+
+// SAFETY: the value is moved out before the node is handed to another thread for dropping.
+unsafe impl<T: Send> Send for Node<T> {}
+
+/// An unbounded lock-free LIFO stack.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of values stored in the stack
+/// - `R`: Reclamation scheme deferring the destruction of popped nodes
+///
+/// # Fields
+///
+/// - `head`: Top node, null when empty
+/// - `len`: Number of values currently in the stack
+/// - `reclaim`: Reclamation scheme of the popped nodes
+pub struct LockFreeStack<T, R: Reclaim = Collector> {
+    head: AtomicPtr<Node<T>>,
+    len: AtomicUsize,
+    reclaim: R,
+    _owns: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: values are moved in and out by single threads, nodes are shared through atomics.
+unsafe impl<T: Send, R: Reclaim + Send> Send for LockFreeStack<T, R> {}
+unsafe impl<T: Send, R: Reclaim> Sync for LockFreeStack<T, R> {}
+
+impl<T, R: Reclaim + Default> Default for LockFreeStack<T, R> {
+    fn default() -> Self {
+        Self::with_reclaim(R::default())
+    }
+}
+
+impl<T, R: Reclaim> Drop for LockFreeStack<T, R> {
+    fn drop(&mut self) {
+        let mut current = *self.head.get_mut();
+
+        while !current.is_null() {
+            let mut node = unsafe { Box::from_raw(current) };
+            unsafe { ManuallyDrop::drop(&mut node.value) };
+            current = node.next;
+        }
+    }
+}
+
+impl<T, R: Reclaim> fmt::Debug for LockFreeStack<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LockFreeStack")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T> LockFreeStack<T> {
+    /// Creates an empty stack reclaiming nodes through its own epoch collector.
+    pub const fn new() -> Self {
+        Self::with_reclaim(Collector::new())
+    }
+}
+
+impl<T, R: Reclaim> LockFreeStack<T, R> {
+    /// Creates an empty stack reclaiming nodes through `reclaim`.
+    pub const fn with_reclaim(reclaim: R) -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+            len: AtomicUsize::new(0),
+            reclaim,
+            _owns: PhantomData,
+        }
+    }
+
+    /// Returns the number of values.
+    ///
+    /// The value is a snapshot and may be outdated as soon as it is returned.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if the stack holds no value.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.head.load(Ordering::Acquire).is_null()
+    }
+
+    /// Pushes a value on top of the stack.
+    pub fn push(&self, value: T) {
+        let node = Box::into_raw(Box::new(Node {
+            value: ManuallyDrop::new(value),
+            next: ptr::null_mut(),
+        }));
+        let mut head = self.head.load(Ordering::Relaxed);
+
+        self.len.fetch_add(1, Ordering::AcqRel);
+
+        loop {
+            // SAFETY: the node is owned until the CAS publishes it.
+            unsafe { (*node).next = head };
+
+            match self
+                .head
+                .compare_exchange_weak(head, node, Ordering::Release, Ordering::Relaxed)
+            {
+                Ok(_) => return,
+                Err(current) => head = current,
+            }
+        }
+    }
+}
+
+impl<T: Send, R: Reclaim> LockFreeStack<T, R> {
+    /// Removes and returns the top value, or `None` if the stack is empty.
+    pub fn pop(&self) -> Option<T> {
+        let guard = self.reclaim.pin();
+
+        loop {
+            let head = guard.protect(0, &self.head);
+
+            if head.is_null() {
+                return None;
+            }
+
+            // SAFETY: the head is protected, so it cannot be freed while read.
+            let next = unsafe { (*head).next };
+
+            if self
+                .head
+                .compare_exchange(head, next, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                self.len.fetch_sub(1, Ordering::AcqRel);
+
+                // SAFETY: the successful CAS gave this thread ownership of the value; other
+                // threads may only still read the link.
+                let value = unsafe { ManuallyDrop::take(&mut (*head).value) };
+                unsafe { guard.defer_drop(head) };
+
+                return Some(value);
+            }
+        }
+    }
+
+    /// Detaches every value at once and returns an iterator over them, from top to bottom.
+    ///
+    /// The detached values stop counting in [`len`](Self::len) at once. Values pushed after the
+    /// call are not yielded. Values not consumed by the iterator are dropped with it.
+    pub fn pop_all(&self) -> PopAll<'_, T, R> {
+        let current = self.head.swap(ptr::null_mut(), Ordering::AcqRel);
+        let mut count = 0;
+        let mut node = current;
+
+        // SAFETY: the detached chain is owned by this thread and its links never change.
+        while !node.is_null() {
+            count += 1;
+            node = unsafe { (*node).next };
+        }
+
+        self.len.fetch_sub(count, Ordering::AcqRel);
+
+        PopAll {
+            stack: self,
+            current,
+        }
+    }
+}
+
+/// Iterator over the values detached by [`LockFreeStack::pop_all`].
+///
+/// # Fields
+///
+/// - `stack`: The stack the values are taken from
+/// - `current`: Next node to yield
+pub struct PopAll<'a, T: Send, R: Reclaim> {
+    stack: &'a LockFreeStack<T, R>,
+    current: *mut Node<T>,
+}
+
+impl<T: Send, R: Reclaim> Iterator for PopAll<'_, T, R> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.current.is_null() {
+            return None;
+        }
+
+        let node = self.current;
+        // SAFETY: the detached chain is owned by the iterator, though concurrent `pop` calls
+        // may still read the links, so nodes are deferred rather than freed.
+        let value = unsafe {
+            self.current = (*node).next;
+            ManuallyDrop::take(&mut (*node).value)
+        };
+
+        unsafe { self.stack.reclaim.pin().defer_drop(node) };
+
+        Some(value)
+    }
+}
+
+impl<T: Send, R: Reclaim> Drop for PopAll<'_, T, R> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
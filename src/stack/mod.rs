@@ -0,0 +1,18 @@
+//! Lock-free LIFO stacks.
+//!
+//! Provides both fixed-size and dynamic Treiber stacks shared between threads by reference.
+//! - `sized`: Fixed-size stack with tagged indices for ABA protection (no allocation)
+//! - `dynamic`: Unbounded stack reclaiming popped nodes through a `Reclaim` scheme (std or alloc)
+//!
+//! Both stacks offer `push`, `pop` and `pop_all`, which detaches every value with a single
+//! atomic operation, for instance to drain a batch of events.
+
+pub mod sized;
+
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+
+pub use sized::SizedLockFreeStack;
+
+#[cfg(feature = "alloc")]
+pub use dynamic::LockFreeStack;
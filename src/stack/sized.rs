@@ -0,0 +1,297 @@
+//! Fixed-capacity lock-free stack.
+//!
+//! This module provides [`SizedLockFreeStack`], a Treiber stack storing its values inline,
+//! usable without the standard library or an allocator.
+//!
+//! # Overview
+//!
+//! Values live in `N` slots linked by a side array of atomic indices, forming two stacks: the
+//! values, and the free slots. Both heads pack a modification tag in their upper 32 bits and
+//! a slot index in their lower 32 bits, following the packed state of
+//! [`SizedWorkStealingPool`](crate::workstealing::SizedWorkStealingPool). Every successful CAS
+//! bumps the tag, so a thread that read a head, got preempted while the same slot was popped
+//! and pushed back, then retries its CAS fails instead of corrupting the stack (ABA
+//! protection).
+//!
+//! Slots are never freed, so stale reads of a link are harmless and no reclamation scheme is
+//! needed. Slots that were never used are handed out through a watermark, keeping `new` const.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::stack::SizedLockFreeStack;
+//!
+//! static EVENTS: SizedLockFreeStack<u32, 128> = SizedLockFreeStack::new();
+//!
+//! EVENTS.push(7).unwrap();
+//! std::thread::spawn(|| assert_eq!(EVENTS.pop(), Some(7)));
+//! ```
+
+use crate::CapacityError;
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+
+/// Index marking the end of a stack.
+const NIL: u32 = u32::MAX;
+
+const fn pack(tag: u32, index: u32) -> u64 {
+    ((tag as u64) << 32) | (index as u64)
+}
+
+fn unpack(value: u64) -> (u32, u32) {
+    let tag = (value >> 32) as u32;
+    let index = (value & 0xFFFF_FFFF) as u32;
+
+    (tag, index)
+}
+
+/// A lock-free LIFO stack holding at most `N` values.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of values stored in the stack
+/// - `N`: Capacity of the stack (less than `u32::MAX`)
+///
+/// # Fields
+///
+/// - `slots`: Inline value storage
+/// - `next`: Link of each slot, in the value stack or in the free stack
+/// - `head`: Packed tag and index of the top value
+/// - `free`: Packed tag and index of the first free slot
+/// - `watermark`: Number of slots that have been handed out at least once
+/// - `len`: Number of values currently in the stack
+pub struct SizedLockFreeStack<T, const N: usize> {
+    slots: [UnsafeCell<MaybeUninit<T>>; N],
+    next: [AtomicU32; N],
+    head: AtomicU64,
+    free: AtomicU64,
+    watermark: AtomicUsize,
+    len: AtomicUsize,
+}
+
+// SAFETY: a slot is only accessed by the thread that took it from one of the stacks.
+unsafe impl<T: Send, const N: usize> Sync for SizedLockFreeStack<T, N> {}
+
+impl<T, const N: usize> Default for SizedLockFreeStack<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for SizedLockFreeStack<T, N> {
+    fn drop(&mut self) {
+        let (_, mut index) = unpack(*self.head.get_mut());
+
+        while index != NIL {
+            unsafe { self.slots[index as usize].get_mut().assume_init_drop() };
+            index = *self.next[index as usize].get_mut();
+        }
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for SizedLockFreeStack<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SizedLockFreeStack")
+            .field("len", &self.len())
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+impl<T, const N: usize> SizedLockFreeStack<T, N> {
+    /// Creates an empty stack.
+    pub const fn new() -> Self {
+        const { assert!(N < NIL as usize, "too many slots for 32-bit indices") };
+
+        Self {
+            slots: [const { UnsafeCell::new(MaybeUninit::uninit()) }; N],
+            next: [const { AtomicU32::new(NIL) }; N],
+            head: AtomicU64::new(pack(0, NIL)),
+            free: AtomicU64::new(pack(0, NIL)),
+            watermark: AtomicUsize::new(0),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the maximum number of values.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of values.
+    ///
+    /// The value is a snapshot and may be outdated as soon as it is returned.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if the stack holds no value.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        unpack(self.head.load(Ordering::Acquire)).1 == NIL
+    }
+
+    /// Pops the top slot of the stack headed by `head`.
+    fn take(&self, head: &AtomicU64) -> Option<u32> {
+        loop {
+            let state_old = head.load(Ordering::Acquire);
+            let (tag, index) = unpack(state_old);
+
+            if index == NIL {
+                return None;
+            }
+
+            let next = self.next[index as usize].load(Ordering::Acquire);
+            let state_new = pack(tag.wrapping_add(1), next);
+
+            if head
+                .compare_exchange(state_old, state_new, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return Some(index);
+            }
+        }
+    }
+
+    /// Pushes the chain of slots `first..=last` on the stack headed by `head`.
+    fn give(&self, head: &AtomicU64, first: u32, last: u32) {
+        loop {
+            let state_old = head.load(Ordering::Acquire);
+            let (tag, top) = unpack(state_old);
+
+            self.next[last as usize].store(top, Ordering::Release);
+            let state_new = pack(tag.wrapping_add(1), first);
+
+            if head
+                .compare_exchange(state_old, state_new, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    /// Takes a free slot, or bumps the watermark.
+    fn take_free(&self) -> Option<u32> {
+        self.take(&self.free).or_else(|| {
+            self.watermark
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |w| {
+                    (w < N).then_some(w + 1)
+                })
+                .ok()
+                .map(|w| w as u32)
+        })
+    }
+
+    /// Moves the value out of `index` and returns the slot to the free stack.
+    fn release(&self, index: u32) -> T {
+        // SAFETY: the slot was taken from the value stack, so it is initialized and owned.
+        let value = unsafe { (*self.slots[index as usize].get()).assume_init_read() };
+        self.give(&self.free, index, index);
+
+        value
+    }
+
+    /// Pushes a value on top of the stack.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the stack is full.
+    pub fn push(&self, value: T) -> Result<(), CapacityError<T>> {
+        let Some(index) = self.take_free() else {
+            return Err(CapacityError::new(value));
+        };
+
+        // SAFETY: the free slot is owned until it is published on the value stack.
+        unsafe { (*self.slots[index as usize].get()).write(value) };
+        self.len.fetch_add(1, Ordering::AcqRel);
+        self.give(&self.head, index, index);
+
+        Ok(())
+    }
+
+    /// Removes and returns the top value, or `None` if the stack is empty.
+    pub fn pop(&self) -> Option<T> {
+        let index = self.take(&self.head)?;
+        self.len.fetch_sub(1, Ordering::AcqRel);
+
+        Some(self.release(index))
+    }
+
+    /// Detaches every value at once and returns an iterator over them, from top to bottom.
+    ///
+    /// The detached values stop counting in [`len`](Self::len) at once. Values pushed after the
+    /// call are not yielded. Values not consumed by the iterator are dropped with it.
+    pub fn pop_all(&self) -> PopAll<'_, T, N> {
+        let mut state_old = self.head.load(Ordering::Acquire);
+
+        loop {
+            let (tag, _) = unpack(state_old);
+
+            match self.head.compare_exchange_weak(
+                state_old,
+                pack(tag.wrapping_add(1), NIL),
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(current) => state_old = current,
+            }
+        }
+
+        let first = unpack(state_old).1;
+        let mut count = 0;
+        let mut index = first;
+
+        // The detached chain is only reachable from this thread, so its links are stable.
+        while index != NIL {
+            count += 1;
+            index = self.next[index as usize].load(Ordering::Acquire);
+        }
+
+        self.len.fetch_sub(count, Ordering::AcqRel);
+
+        PopAll {
+            stack: self,
+            current: first,
+        }
+    }
+}
+
+/// Iterator over the values detached by [`SizedLockFreeStack::pop_all`].
+///
+/// # Fields
+///
+/// - `stack`: The stack the values are taken from
+/// - `current`: Index of the next slot to yield
+pub struct PopAll<'a, T, const N: usize> {
+    stack: &'a SizedLockFreeStack<T, N>,
+    current: u32,
+}
+
+impl<T, const N: usize> Iterator for PopAll<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.current == NIL {
+            return None;
+        }
+
+        let index = self.current;
+        // The detached chain is only reachable from this iterator.
+        self.current = self.stack.next[index as usize].load(Ordering::Acquire);
+
+        Some(self.stack.release(index))
+    }
+}
+
+impl<T, const N: usize> Drop for PopAll<'_, T, N> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
@@ -0,0 +1,149 @@
+//! Raw lock entering a platform-provided critical section.
+//!
+//! This module provides the [`CriticalSection`] trait, implemented by the application for its
+//! platform, and [`RawCriticalSection`], a raw lock for [`Locked`](super::Locked) built on it.
+//!
+//! # Overview
+//!
+//! On a single-core microcontroller, masking interrupts is enough to get exclusive access to
+//! shared data, and it is the only sound way to share a container between thread mode and an
+//! interrupt handler: a spin lock taken by the interrupted code would never be released.
+//! On multi-core targets the implementation must additionally take a global lock.
+//!
+//! [`CriticalSection::acquire`] returns a state, typically whether interrupts were enabled,
+//! which is stored in the lock and handed back to [`CriticalSection::release`], so nested
+//! critical sections on different locks restore the state they found.
+//!
+//! Locking the same `RawCriticalSection` twice from one context is a bug and panics.
+//!
+//! Guards of different locks may be dropped out of nesting order, and their states are then
+//! restored out of order too: dropping the outer guard first re-enables interrupts while the
+//! inner lock is still held. The inner lock stays exclusive through its own flag, so other
+//! contexts fail to take it until it is unlocked, but it no longer masks interrupts. Drop
+//! guards in reverse order of locking to keep the critical section for their whole scope.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::DoubleLinkedList::SizedDoubleLinkedList;
+//! use datastructures::sync::{CriticalSection, Locked, RawCriticalSection};
+//!
+//! struct Interrupts;
+//!
+//! unsafe impl CriticalSection for Interrupts {
+//!     type State = bool;
+//!
+//!     fn acquire() -> bool {
+//!         let enabled = cpu::interrupts_enabled();
+//!         cpu::disable_interrupts();
+//!         enabled
+//!     }
+//!
+//!     unsafe fn release(enabled: bool) {
+//!         if enabled {
+//!             cpu::enable_interrupts();
+//!         }
+//!     }
+//! }
+//!
+//! static RX: Locked<SizedDoubleLinkedList<u8, 32>, RawCriticalSection<Interrupts>> =
+//!     Locked::new(SizedDoubleLinkedList::new());
+//! ```
+
+use super::locked::RawLock;
+
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Platform hook entering and leaving a critical section.
+///
+/// # Safety
+///
+/// While a critical section is active, no other execution context (thread, interrupt handler
+/// or core) may enter one, and entering must synchronize with the previous exit.
+pub unsafe trait CriticalSection {
+    /// State saved on entry and restored on exit.
+    type State: Copy;
+
+    /// Enters a critical section and returns the state to restore on exit.
+    fn acquire() -> Self::State;
+
+    /// Leaves the critical section entered by the `acquire` call that returned `state`.
+    ///
+    /// Nested sections are usually released in reverse order of acquisition, but guards of
+    /// different locks can be dropped in any order from safe code. Implementations must stay
+    /// sound when an outer section is released before an inner one; restoring `state` as is,
+    /// such as re-enabling interrupts while the inner section is held, is acceptable.
+    ///
+    /// # Safety
+    ///
+    /// Must be called exactly once per `acquire`, with the state it returned.
+    unsafe fn release(state: Self::State);
+}
+
+/// Raw lock holding the critical section `CS` while locked.
+///
+/// # Fields
+///
+/// - `locked`: `true` while the lock is held, used to detect re-entrant locking and to keep
+///   the lock exclusive when critical sections are released out of order
+/// - `state`: State returned by `CS::acquire`, restored on unlock
+pub struct RawCriticalSection<CS: CriticalSection> {
+    locked: AtomicBool,
+    state: UnsafeCell<MaybeUninit<CS::State>>,
+    _cs: PhantomData<CS>,
+}
+
+// SAFETY: `state` is only accessed by the holder of the critical section.
+unsafe impl<CS: CriticalSection> Sync for RawCriticalSection<CS> {}
+
+impl<CS: CriticalSection> RawCriticalSection<CS> {
+    /// Records the state of a freshly entered critical section, failing if already locked.
+    fn enter(&self, state: CS::State) -> bool {
+        // Acquire pairs with the release in `unlock`, which may run outside any critical
+        // section after an out-of-order release.
+        if self.locked.swap(true, Ordering::Acquire) {
+            // SAFETY: the critical section was entered by the caller.
+            unsafe { CS::release(state) };
+            return false;
+        }
+
+        // SAFETY: the critical section is held, nobody else reads or writes `state`.
+        unsafe { (*self.state.get()).write(state) };
+        true
+    }
+}
+
+unsafe impl<CS: CriticalSection> RawLock for RawCriticalSection<CS> {
+    const INIT: Self = Self {
+        locked: AtomicBool::new(false),
+        state: UnsafeCell::new(MaybeUninit::uninit()),
+        _cs: PhantomData,
+    };
+
+    fn lock(&self) {
+        assert!(
+            self.enter(CS::acquire()),
+            "critical-section lock acquired twice from the same context"
+        );
+    }
+
+    fn try_lock(&self) -> bool {
+        self.enter(CS::acquire())
+    }
+
+    unsafe fn unlock(&self) {
+        // SAFETY: the lock is held, so `state` was written by `enter`.
+        let state = unsafe { (*self.state.get()).assume_init() };
+
+        self.locked.store(false, Ordering::Release);
+        // SAFETY: leaves the critical section entered by `lock` or `try_lock`.
+        unsafe { CS::release(state) };
+    }
+
+    fn is_locked(&self) -> bool {
+        self.locked.load(Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,221 @@
+//! Generic lock wrapper over a pluggable raw lock.
+//!
+//! This module provides [`Locked`], which pairs a container with a [`RawLock`] and hands out
+//! [`LockedGuard`]s granting exclusive access to it. The raw lock decides how exclusion is
+//! achieved: spinning on an atomic flag ([`RawSpinLock`](super::spin::RawSpinLock)), serving
+//! waiters in arrival order ([`TicketLock`](super::ticket::TicketLock)) or entering a
+//! platform critical section ([`RawCriticalSection`](super::critical::RawCriticalSection)).
+//!
+//! # Overview
+//!
+//! Raw locks are constructed from the [`RawLock::INIT`] constant, so [`Locked::new`] is a
+//! `const fn` and a locked container can be placed in a `static` item. Containers implementing
+//! [`Deque`] additionally get the one-shot [`Locked::push_back`] and [`Locked::pop_front`]
+//! operations, which only hold the lock for the duration of the call.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::DoubleLinkedList::SizedDoubleLinkedList;
+//! use datastructures::sync::Locked;
+//!
+//! static JOBS: Locked<SizedDoubleLinkedList<u32, 32>> = Locked::new(SizedDoubleLinkedList::new());
+//!
+//! JOBS.push_back(7).unwrap();
+//! assert_eq!(JOBS.pop_front(), Some(7));
+//! ```
+
+use super::ticket::TicketLock;
+use crate::CapacityError;
+use crate::double_linked_list::Deque;
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+/// Mutual exclusion primitive guarding a [`Locked`] value.
+///
+/// # Safety
+///
+/// Implementations must guarantee that between a successful `lock` or `try_lock` and the
+/// matching `unlock`, no other call acquires the lock, and that acquiring synchronizes with
+/// the previous release.
+pub unsafe trait RawLock {
+    /// An unlocked instance.
+    const INIT: Self;
+
+    /// Acquires the lock, waiting until it becomes available.
+    fn lock(&self);
+
+    /// Acquires the lock if it is free, without waiting. Returns `true` on success.
+    fn try_lock(&self) -> bool;
+
+    /// Releases the lock.
+    ///
+    /// # Safety
+    ///
+    /// The lock must be held by the caller.
+    unsafe fn unlock(&self);
+
+    /// Returns `true` if the lock is currently held.
+    ///
+    /// The answer may be outdated as soon as it is returned.
+    fn is_locked(&self) -> bool;
+}
+
+/// A value of type `C` protected by the raw lock `R`.
+///
+/// # Type Parameters
+///
+/// - `C`: The type of the protected value, usually a container
+/// - `R`: The raw lock providing mutual exclusion
+///
+/// # Fields
+///
+/// - `raw`: The raw lock
+/// - `value`: The protected value
+pub struct Locked<C, R: RawLock = TicketLock> {
+    raw: R,
+    value: UnsafeCell<C>,
+}
+
+// SAFETY: the value is only reachable through a guard, and the raw lock guarantees at most one
+// guard exists at a time.
+unsafe impl<C: Send, R: RawLock + Sync> Sync for Locked<C, R> {}
+
+impl<C: Default, R: RawLock> Default for Locked<C, R> {
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<C, R: RawLock> Locked<C, R> {
+    /// Creates an unlocked wrapper protecting `value`.
+    pub const fn new(value: C) -> Self {
+        Self {
+            raw: R::INIT,
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Acquires the lock, waiting until it becomes available.
+    pub fn lock(&self) -> LockedGuard<'_, C, R> {
+        self.raw.lock();
+
+        LockedGuard {
+            lock: self,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Acquires the lock if it is free, without waiting.
+    ///
+    /// Returns `None` if another guard is alive.
+    pub fn try_lock(&self) -> Option<LockedGuard<'_, C, R>> {
+        self.raw.try_lock().then(|| LockedGuard {
+            lock: self,
+            _not_send: PhantomData,
+        })
+    }
+
+    /// Runs `f` with exclusive access to the value and returns its result.
+    pub fn with<T>(&self, f: impl FnOnce(&mut C) -> T) -> T {
+        f(&mut self.lock())
+    }
+
+    /// Returns `true` if a guard is currently alive.
+    ///
+    /// The answer may be outdated as soon as it is returned.
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        self.raw.is_locked()
+    }
+
+    /// Returns a mutable reference to the value.
+    ///
+    /// No locking is needed since the exclusive borrow guarantees no guard is alive.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut C {
+        self.value.get_mut()
+    }
+
+    /// Consumes the wrapper and returns the value.
+    pub fn into_inner(self) -> C {
+        self.value.into_inner()
+    }
+
+    /// Appends a value at the back of the container, holding the lock for the call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the container is at capacity.
+    pub fn push_back<T>(&self, value: T) -> Result<(), CapacityError<T>>
+    where
+        C: Deque<T>,
+    {
+        self.lock().push_back(value)
+    }
+
+    /// Removes and returns the front value of the container, holding the lock for the call only.
+    pub fn pop_front<T>(&self) -> Option<T>
+    where
+        C: Deque<T>,
+    {
+        self.lock().pop_front()
+    }
+}
+
+impl<C: fmt::Debug, R: RawLock> fmt::Debug for Locked<C, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Locked");
+
+        match self.try_lock() {
+            Some(guard) => debug.field("value", &&*guard),
+            None => debug.field("value", &format_args!("<locked>")),
+        };
+
+        debug.finish()
+    }
+}
+
+/// Exclusive access to the value of a [`Locked`], releasing the lock on drop.
+///
+/// Guards are not `Send`: raw locks such as critical sections must be released by the context
+/// that acquired them.
+pub struct LockedGuard<'a, C, R: RawLock> {
+    lock: &'a Locked<C, R>,
+    _not_send: PhantomData<*const ()>,
+}
+
+// SAFETY: sharing the guard only hands out `&C`.
+unsafe impl<C: Sync, R: RawLock + Sync> Sync for LockedGuard<'_, C, R> {}
+
+impl<C, R: RawLock> Deref for LockedGuard<'_, C, R> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        // SAFETY: the guard holds the lock.
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<C, R: RawLock> DerefMut for LockedGuard<'_, C, R> {
+    fn deref_mut(&mut self) -> &mut C {
+        // SAFETY: the guard holds the lock.
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<C, R: RawLock> Drop for LockedGuard<'_, C, R> {
+    fn drop(&mut self) {
+        // SAFETY: the guard holds the lock.
+        unsafe { self.lock.raw.unlock() };
+    }
+}
+
+impl<C: fmt::Debug, R: RawLock> fmt::Debug for LockedGuard<'_, C, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
@@ -0,0 +1,28 @@
+//! Synchronization primitives for sharing fixed-capacity containers.
+//!
+//! Provides wrappers adding interior mutability to the containers of this crate so they can
+//! be placed in `static` items and shared between threads or interrupt contexts.
+//! - `locked`: Generic `Locked` wrapper over a pluggable `RawLock`
+//! - `spin`: Test-and-test-and-set spin lock usable without the standard library
+//! - `ticket`: Fair ticket spin lock, the default raw lock of `Locked`
+//! - `critical`: Raw lock entering a platform-provided critical section
+//! - `mutex`: Wrapper backed by `std::sync::Mutex` (std only)
+//!
+//! Every fixed-capacity container exposes a `const fn new`, so a locked container is built at
+//! compile time and needs no runtime initialization.
+
+pub mod critical;
+pub mod locked;
+pub mod spin;
+pub mod ticket;
+
+#[cfg(feature = "std")]
+pub mod mutex;
+
+pub use critical::{CriticalSection, RawCriticalSection};
+pub use locked::{Locked, LockedGuard, RawLock};
+pub use spin::{RawSpinLock, SpinLock, SpinLockGuard};
+pub use ticket::TicketLock;
+
+#[cfg(feature = "std")]
+pub use mutex::MutexLocked;
@@ -0,0 +1,90 @@
+//! Lock wrapper backed by the standard library mutex.
+//!
+//! This module provides [`MutexLocked`], the std counterpart of [`Locked`](super::Locked).
+//! Waiting threads are parked by the operating system instead of spinning, which suits hosted
+//! applications where the lock may be held for long periods.
+//!
+//! **Note**: This module requires the `std` feature.
+//!
+//! A panic while a guard is alive poisons a `std::sync::Mutex`. `MutexLocked` ignores
+//! poisoning and keeps handing out the value, so it behaves like the no-std
+//! [`Locked`](super::Locked) wrapper, which has no notion of poisoning.
+
+use crate::CapacityError;
+use crate::double_linked_list::Deque;
+
+use std::sync::{Mutex, MutexGuard, PoisonError, TryLockError};
+
+/// A value of type `C` protected by a `std::sync::Mutex`.
+///
+/// # Type Parameters
+///
+/// - `C`: The type of the protected value, usually a container
+#[derive(Debug, Default)]
+pub struct MutexLocked<C> {
+    inner: Mutex<C>,
+}
+
+impl<C> MutexLocked<C> {
+    /// Creates an unlocked wrapper protecting `value`.
+    pub const fn new(value: C) -> Self {
+        Self {
+            inner: Mutex::new(value),
+        }
+    }
+
+    /// Acquires the lock, blocking the current thread until it becomes available.
+    pub fn lock(&self) -> MutexGuard<'_, C> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Acquires the lock if it is free, without blocking.
+    ///
+    /// Returns `None` if another guard is alive.
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, C>> {
+        match self.inner.try_lock() {
+            Ok(guard) => Some(guard),
+            Err(TryLockError::Poisoned(error)) => Some(error.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        }
+    }
+
+    /// Runs `f` with exclusive access to the value and returns its result.
+    pub fn with<T>(&self, f: impl FnOnce(&mut C) -> T) -> T {
+        f(&mut self.lock())
+    }
+
+    /// Returns a mutable reference to the value.
+    ///
+    /// No locking is needed since the exclusive borrow guarantees no guard is alive.
+    pub fn get_mut(&mut self) -> &mut C {
+        self.inner.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Consumes the wrapper and returns the value.
+    pub fn into_inner(self) -> C {
+        self.inner
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Appends a value at the back of the container, holding the lock for the call only.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the container is at capacity.
+    pub fn push_back<T>(&self, value: T) -> Result<(), CapacityError<T>>
+    where
+        C: Deque<T>,
+    {
+        self.lock().push_back(value)
+    }
+
+    /// Removes and returns the front value of the container, holding the lock for the call only.
+    pub fn pop_front<T>(&self) -> Option<T>
+    where
+        C: Deque<T>,
+    {
+        self.lock().pop_front()
+    }
+}
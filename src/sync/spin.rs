@@ -0,0 +1,83 @@
+//! Spin lock granting exclusive access to a value.
+//!
+//! This module provides [`RawSpinLock`], a test-and-test-and-set lock built on a single
+//! `AtomicBool`, and the [`SpinLock`] alias pairing it with a value. It has no dependency on
+//! the operating system, which makes it usable on bare-metal targets, and its constructor is a
+//! `const fn` so a locked container can live in a `static` item.
+//!
+//! # Overview
+//!
+//! [`SpinLock::lock`](super::Locked::lock) busy-waits until the lock is free and returns a
+//! guard dereferencing to the protected value; the lock is released when the guard is dropped.
+//! Waiters only read the flag while it is held, so they do not bounce the cache line between
+//! cores with failed writes. The lock is not fair: under contention a waiter may be overtaken
+//! indefinitely, which [`TicketLock`](super::ticket::TicketLock) prevents.
+//!
+//! An interrupt handler preempting the holder on the same core would spin forever with
+//! `lock`. Handlers should use [`SpinLock::try_lock`](super::Locked::try_lock) and defer the
+//! work when the lock is taken, or the holder should mask interrupts for the duration of the
+//! guard, which [`RawCriticalSection`](super::critical::RawCriticalSection) does.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use datastructures::DoubleLinkedList::SizedDoubleLinkedList;
+//! use datastructures::sync::SpinLock;
+//!
+//! static EVENTS: SpinLock<SizedDoubleLinkedList<u32, 16>> =
+//!     SpinLock::new(SizedDoubleLinkedList::new());
+//!
+//! fn on_interrupt(code: u32) {
+//!     if let Some(mut events) = EVENTS.try_lock() {
+//!         let _ = events.insert_tail(code);
+//!     }
+//! }
+//! ```
+
+use super::locked::{Locked, LockedGuard, RawLock};
+
+use core::hint;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// A value of type `T` protected by a [`RawSpinLock`].
+pub type SpinLock<T> = Locked<T, RawSpinLock>;
+
+/// Exclusive access to the value of a [`SpinLock`], releasing the lock on drop.
+pub type SpinLockGuard<'a, T> = LockedGuard<'a, T, RawSpinLock>;
+
+/// Test-and-test-and-set spin lock.
+///
+/// # Fields
+///
+/// - `locked`: `true` while the lock is held
+pub struct RawSpinLock {
+    locked: AtomicBool,
+}
+
+unsafe impl RawLock for RawSpinLock {
+    const INIT: Self = Self {
+        locked: AtomicBool::new(false),
+    };
+
+    fn lock(&self) {
+        while !self.try_lock() {
+            while self.locked.load(Ordering::Relaxed) {
+                hint::spin_loop();
+            }
+        }
+    }
+
+    fn try_lock(&self) -> bool {
+        self.locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    unsafe fn unlock(&self) {
+        self.locked.store(false, Ordering::Release);
+    }
+
+    fn is_locked(&self) -> bool {
+        self.locked.load(Ordering::Relaxed)
+    }
+}
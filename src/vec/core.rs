@@ -1,8 +1,10 @@
 use core::cmp::Ordering;
 use core::mem::MaybeUninit;
 
-#[cfg(not(feature = "no-std"))]
-use std::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 /// Merges two sorted vectors, keeping the lowest elements in place in `v1`.
 ///
@@ -26,13 +28,13 @@ use std::vec::Vec;
 /// keep_lowest_vec(&mut a, b);
 /// assert_eq!(a, vec![1, 2, 3, 4, 5]);
 /// ```
-#[cfg(not(feature = "no-std"))]
+#[cfg(feature = "alloc")]
 pub fn keep_lowest_vec<T: Ord + Clone>(v1: &mut Vec<T>, v2: Vec<T>) {
     keep_lowest_vec_by(v1, v2, |a, b| a.cmp(b));
 }
 
 /// Same as [`keep_lowest_vec`] but with a custom comparator and consuming `v2`.
-#[cfg(not(feature = "no-std"))]
+#[cfg(feature = "alloc")]
 pub fn keep_lowest_vec_by<T: Clone, F>(v1: &mut Vec<T>, v2: Vec<T>, compare: F)
 where
     F: Fn(&T, &T) -> Ordering,
@@ -83,7 +85,7 @@ where
 /// Converts a `MaybeUninit` slice to a `Vec<Option<T>>`.
 ///
 /// Only the first `size` elements are converted; the rest are set to `None`.
-#[cfg(not(feature = "no-std"))]
+#[cfg(feature = "alloc")]
 pub fn swap_maybeuninit_to_option_vec<T: Copy>(
     arr: &[MaybeUninit<T>],
     size: usize,
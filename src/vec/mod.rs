@@ -1,7 +1,7 @@
-//! Vector utilities (std-only).
+//! Vector utilities (std or alloc).
 //!
 //! Helpers around `Vec` for keeping the smallest elements when merging. These
 //! utilities consume the second vector to avoid extra cloning and are only
-//! available when the `std` feature is enabled.
+//! available with the standard library or the `alloc` feature.
 
 pub mod core;
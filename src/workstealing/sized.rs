@@ -1,13 +1,9 @@
+use crate::CapacityError;
+use crate::codec::binary::{self, CodecError, Decode, Encode, SnapshotKind};
+
 use core::mem::MaybeUninit;
 use core::sync::atomic::{AtomicU64, Ordering};
 
-/// Errors returned by `SizedWorkStealingPool` operations.
-#[derive(Debug, PartialEq, Eq)]
-pub enum SizedWorkStealingPoolError {
-    IsFull,
-    IsEmpty,
-}
-
 /// Bounded work-stealing pool inspired by the Chase-Lev deque.
 ///
 /// Provides lock-free `insert`/`take` for the owner and `steal` for workers
@@ -30,17 +26,19 @@ fn unpack(value: u64) -> (u32, u32) {
 
 impl<T, const N: usize> SizedWorkStealingPool<T, N> {
     /// Creates an empty pool with capacity `N`.
-    pub fn new() -> Self {
-        let queue: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
-
+    pub const fn new() -> Self {
         Self {
-            queue,
+            queue: [const { MaybeUninit::uninit() }; N],
             state: AtomicU64::new(0),
         }
     }
 
-    /// Pushes a value at the bottom of the deque. Fails if the pool is full.
-    pub fn insert(&mut self, value: T) -> Result<(), SizedWorkStealingPoolError>
+    /// Pushes a value at the bottom of the deque.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CapacityError` holding `value` if the pool is full.
+    pub fn insert(&mut self, value: T) -> Result<(), CapacityError<T>>
     where
         T: Copy,
     {
@@ -49,7 +47,7 @@ impl<T, const N: usize> SizedWorkStealingPool<T, N> {
             let (top, bot) = unpack(state_old);
 
             if bot - top == (N as u32) {
-                return Err(SizedWorkStealingPoolError::IsFull);
+                return Err(CapacityError::new(value));
             }
 
             let new_bot = bot + 1;
@@ -134,8 +132,102 @@ impl<T, const N: usize> SizedWorkStealingPool<T, N> {
     }
 }
 
+impl<T: Copy, const N: usize> SizedWorkStealingPool<T, N> {
+    /// Returns the pending values, from the oldest to the most recent, without claiming them.
+    fn pending(&self) -> impl Iterator<Item = &T> + Clone {
+        let (top, bot) = unpack(self.state.load(Ordering::Acquire));
+
+        (top..bot).map(|i| unsafe { self.queue[i as usize % N].assume_init_ref() })
+    }
+}
+
 impl<T: Sized, const N: usize> Default for SizedWorkStealingPool<T, N> {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// Writes the pending values as a binary snapshot, from the oldest to the most recent.
+///
+/// The snapshot reads the pool without claiming values, so it should be taken while no worker
+/// is stealing.
+impl<T: Encode + Copy, const N: usize> Encode for SizedWorkStealingPool<T, N> {
+    fn encoded_len(&self) -> usize {
+        binary::snapshot_len(self.pending())
+    }
+
+    fn encode(&self, out: &mut [u8]) -> Result<usize, CodecError> {
+        binary::write_snapshot(SnapshotKind::WorkStealingPool, N, self.pending(), out)
+    }
+}
+
+/// Reads a pool from a binary snapshot, restoring the pending values in their original order.
+impl<T: Decode + Copy, const N: usize> Decode for SizedWorkStealingPool<T, N> {
+    fn decode(input: &[u8]) -> Result<(Self, usize), CodecError> {
+        let (header, payload, read) =
+            binary::read_snapshot(SnapshotKind::WorkStealingPool, N, input)?;
+
+        let mut pool = Self::new();
+        binary::read_elements(&header, payload, |value| {
+            pool.insert(value)
+                .map_err(|_| CodecError::LengthExceedsCapacity)
+        })?;
+
+        Ok((pool, read))
+    }
+}
+
+/// Serializes the pending values as a sequence, from the oldest to the most recent.
+///
+/// Like the binary snapshot, this reads the pool without claiming values, so it should be taken
+/// while no worker is stealing.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize + Copy, const N: usize> serde::Serialize for SizedWorkStealingPool<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.pending())
+    }
+}
+
+/// Deserializes a sequence of at most `N` values, restoring them as pending in their original
+/// order.
+///
+/// A longer sequence is rejected with an `invalid_length` error instead of being truncated.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + Copy, const N: usize> serde::Deserialize<'de>
+    for SizedWorkStealingPool<T, N>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::marker::PhantomData;
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct PoolVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de> + Copy, const N: usize> Visitor<'de> for PoolVisitor<T, N> {
+            type Value = SizedWorkStealingPool<T, N>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "a sequence of at most {N} elements")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                if let Some(hint) = seq.size_hint()
+                    && hint > N
+                {
+                    return Err(A::Error::invalid_length(hint, &self));
+                }
+
+                let mut pool = SizedWorkStealingPool::new();
+
+                while let Some(value) = seq.next_element()? {
+                    if pool.insert(value).is_err() {
+                        return Err(A::Error::invalid_length(N + 1, &self));
+                    }
+                }
+
+                Ok(pool)
+            }
+        }
+
+        deserializer.deserialize_seq(PoolVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::buffer::ChunkedBuffer;
+
+    #[test]
+    fn test_append_across_chunks() {
+        let mut buffer: ChunkedBuffer<4> = ChunkedBuffer::new();
+
+        buffer.append(b"hello world");
+
+        assert_eq!(buffer.len(), 11);
+        assert_eq!(buffer.chunk_count(), 3);
+        assert_eq!(buffer.remaining_capacity(), 1);
+    }
+
+    #[test]
+    fn test_get_by_offset() {
+        let mut buffer: ChunkedBuffer<3> = ChunkedBuffer::new();
+
+        buffer.append(b"abcdefg");
+
+        assert_eq!(buffer.get(0), Some(b'a'));
+        assert_eq!(buffer.get(3), Some(b'd'));
+        assert_eq!(buffer.get(6), Some(b'g'));
+        assert_eq!(buffer.get(7), None);
+    }
+
+    #[test]
+    fn test_read_at_across_boundaries() {
+        let mut buffer: ChunkedBuffer<4> = ChunkedBuffer::new();
+
+        buffer.append(b"0123");
+        buffer.append(b"4567");
+        buffer.append(b"89");
+
+        let mut out = [0u8; 6];
+        assert_eq!(buffer.read_at(2, &mut out), 6);
+        assert_eq!(&out, b"234567");
+
+        let mut tail = [0u8; 5];
+        assert_eq!(buffer.read_at(7, &mut tail), 3);
+        assert_eq!(&tail[..3], b"789");
+    }
+
+    #[test]
+    fn test_consume_releases_prefix_chunks() {
+        let mut buffer: ChunkedBuffer<4> = ChunkedBuffer::new();
+
+        buffer.append(b"abcdefghij");
+        assert_eq!(buffer.chunk_count(), 3);
+
+        assert_eq!(buffer.consume(5), 5);
+        assert_eq!(buffer.chunk_count(), 2);
+        assert_eq!(buffer.len(), 5);
+        assert_eq!(buffer.get(0), Some(b'f'));
+
+        assert_eq!(buffer.consume(100), 5);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.chunk_count(), 1);
+        assert_eq!(buffer.remaining_capacity(), 4);
+    }
+
+    #[test]
+    fn test_addresses_stay_stable_while_growing() {
+        let mut buffer: ChunkedBuffer<8> = ChunkedBuffer::new();
+
+        buffer.append(b"header");
+        let before = buffer.contiguous_at(0).as_ptr();
+
+        for _ in 0..100 {
+            buffer.append(b"payload");
+        }
+
+        assert_eq!(buffer.contiguous_at(0).as_ptr(), before);
+    }
+
+    #[test]
+    fn test_chunks_iterator() {
+        let mut buffer: ChunkedBuffer<4> = ChunkedBuffer::new();
+
+        buffer.append(b"abcdefghij");
+        buffer.consume(1);
+
+        let slices: Vec<&[u8]> = buffer.chunks().collect();
+        assert_eq!(slices, vec![&b"bcd"[..], &b"efgh"[..], &b"ij"[..]]);
+
+        let joined: Vec<u8> = buffer.chunks().flatten().copied().collect();
+        assert_eq!(joined, b"bcdefghij");
+    }
+
+    #[test]
+    fn test_interleaved_append_and_consume() {
+        let mut buffer: ChunkedBuffer<5> = ChunkedBuffer::new();
+        let mut expected = Vec::new();
+
+        for round in 0..20u8 {
+            let frame = [round; 7];
+            buffer.append(&frame);
+            expected.extend_from_slice(&frame);
+
+            let consumed = buffer.consume(3);
+            expected.drain(..consumed);
+        }
+
+        let mut out = vec![0u8; buffer.len()];
+        assert_eq!(buffer.read_at(0, &mut out), expected.len());
+        assert_eq!(out, expected);
+
+        buffer.clear();
+        assert_eq!(buffer.chunk_count(), 0);
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::CapacityError;
+    use datastructures::buffer::{OverflowPolicy, SizedCircularBuffer};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_push_and_pop_both_ends() {
+        let mut buffer: SizedCircularBuffer<i32, 4> =
+            SizedCircularBuffer::new(OverflowPolicy::Reject);
+
+        assert!(matches!(buffer.push_back(2), Ok(None)));
+        assert!(matches!(buffer.push_back(3), Ok(None)));
+        assert!(matches!(buffer.push_front(1), Ok(None)));
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.front(), Some(&1));
+        assert_eq!(buffer.back(), Some(&3));
+
+        assert_eq!(buffer.pop_front(), Some(1));
+        assert_eq!(buffer.pop_back(), Some(3));
+        assert_eq!(buffer.pop_back(), Some(2));
+        assert_eq!(buffer.pop_front(), None);
+    }
+
+    #[test]
+    fn test_reject_policy() {
+        let mut buffer: SizedCircularBuffer<i32, 2> =
+            SizedCircularBuffer::new(OverflowPolicy::Reject);
+
+        buffer.push_back(1).unwrap();
+        buffer.push_back(2).unwrap();
+
+        assert!(buffer.is_full());
+        assert_eq!(buffer.push_back(3), Err(CapacityError::new(3)));
+        assert_eq!(buffer.push_front(0), Err(CapacityError::new(0)));
+        assert_eq!(buffer[0], 1);
+        assert_eq!(buffer[1], 2);
+    }
+
+    #[test]
+    fn test_overwrite_policy_evicts_opposite_end() {
+        let mut buffer: SizedCircularBuffer<i32, 3> =
+            SizedCircularBuffer::new(OverflowPolicy::Overwrite);
+
+        for value in 1..=3 {
+            buffer.push_back(value).unwrap();
+        }
+
+        assert!(matches!(buffer.push_back(4), Ok(Some(1))));
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        assert!(matches!(buffer.push_front(0), Ok(Some(4))));
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_sliding_window_sum() {
+        let mut window: SizedCircularBuffer<u32, 4> =
+            SizedCircularBuffer::new(OverflowPolicy::Overwrite);
+
+        let mut sums = Vec::new();
+        for sample in [5, 1, 4, 2, 8, 3] {
+            window.push_back(sample).unwrap();
+            sums.push(window.iter().sum::<u32>());
+        }
+
+        assert_eq!(sums, vec![5, 6, 10, 12, 15, 17]);
+    }
+
+    #[test]
+    fn test_as_slices_wraps() {
+        let mut buffer: SizedCircularBuffer<i32, 4> =
+            SizedCircularBuffer::new(OverflowPolicy::Reject);
+
+        buffer.push_back(1).unwrap();
+        buffer.push_back(2).unwrap();
+        buffer.push_back(3).unwrap();
+        buffer.pop_front();
+        buffer.pop_front();
+        buffer.push_back(4).unwrap();
+        buffer.push_back(5).unwrap();
+
+        let (first, second) = buffer.as_slices();
+        assert_eq!(first, &[3, 4]);
+        assert_eq!(second, &[5]);
+
+        let (first, second) = buffer.as_mut_slices();
+        first[0] = 30;
+        second[0] = 50;
+        assert_eq!(
+            buffer.iter().rev().copied().collect::<Vec<_>>(),
+            vec![50, 4, 30]
+        );
+    }
+
+    #[test]
+    fn test_index_and_iter_mut() {
+        let mut buffer: SizedCircularBuffer<i32, 3> =
+            SizedCircularBuffer::new(OverflowPolicy::Reject);
+
+        buffer.push_back(1).unwrap();
+        buffer.push_back(2).unwrap();
+        buffer[1] = 20;
+
+        for value in buffer.iter_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(buffer[0], 2);
+        assert_eq!(buffer[1], 21);
+        assert_eq!(buffer.get(2), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_range_panics() {
+        let buffer: SizedCircularBuffer<i32, 3> = SizedCircularBuffer::new(OverflowPolicy::Reject);
+
+        let _ = buffer[0];
+    }
+
+    #[test]
+    fn test_clone_and_drop() {
+        let tracker = Rc::new(());
+        let mut buffer: SizedCircularBuffer<Rc<()>, 2> =
+            SizedCircularBuffer::new(OverflowPolicy::Overwrite);
+
+        buffer.push_back(tracker.clone()).unwrap();
+        buffer.push_back(tracker.clone()).unwrap();
+        let evicted = buffer.push_back(tracker.clone()).unwrap();
+        drop(evicted);
+
+        let cloned = buffer.clone();
+        assert_eq!(Rc::strong_count(&tracker), 5);
+
+        drop(buffer);
+        drop(cloned);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+}
@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::DoubleLinkedList::SizedDoubleLinkedList;
+    use datastructures::codec::binary::{CHECKSUM_LEN, FORMAT_VERSION, HEADER_LEN, crc32};
+    use datastructures::codec::{CodecError, Decode, Encode, SnapshotHeader, SnapshotKind};
+    use datastructures::workstealing::SizedWorkStealingPool;
+
+    fn sample() -> SizedDoubleLinkedList<u16, 8> {
+        let mut list: SizedDoubleLinkedList<u16, 8> = Default::default();
+        for value in [10, 20, 30, 40] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+        assert!(list.remove(0).is_ok());
+        assert!(list.insert_head(5).is_ok());
+        list
+    }
+
+    #[test]
+    fn test_crc32_reference_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn test_list_round_trip() {
+        let list = sample();
+        let mut buffer = [0u8; 64];
+
+        let written = list.encode(&mut buffer).unwrap();
+        assert_eq!(written, list.encoded_len());
+        assert_eq!(written, HEADER_LEN + 4 * 2 + CHECKSUM_LEN);
+
+        let (decoded, read) = SizedDoubleLinkedList::<u16, 8>::decode(&buffer).unwrap();
+        assert_eq!(read, written);
+        assert_eq!(decoded, list);
+    }
+
+    #[test]
+    fn test_header_records_capacity_and_length() {
+        let mut buffer = [0u8; 64];
+        sample().encode(&mut buffer).unwrap();
+
+        let header = SnapshotHeader::read(&buffer).unwrap();
+        assert_eq!(header.kind, SnapshotKind::DoubleLinkedList);
+        assert_eq!(header.capacity, 8);
+        assert_eq!(header.length, 4);
+        assert_eq!(header.payload_len, 8);
+        assert_eq!(&buffer[..3], &[b'D', b'S', FORMAT_VERSION]);
+
+        // Elements are stored in list order, little-endian.
+        assert_eq!(&buffer[HEADER_LEN..HEADER_LEN + 4], &[5, 0, 20, 0]);
+    }
+
+    #[test]
+    fn test_encode_buffer_too_small() {
+        let list = sample();
+        let mut buffer = [0u8; 20];
+
+        assert_eq!(list.encode(&mut buffer), Err(CodecError::BufferTooSmall));
+    }
+
+    #[test]
+    fn test_decode_detects_corruption() {
+        let mut buffer = [0u8; 64];
+        let written = sample().encode(&mut buffer).unwrap();
+
+        buffer[HEADER_LEN + 1] ^= 0x40;
+
+        assert_eq!(
+            SizedDoubleLinkedList::<u16, 8>::decode(&buffer[..written]).map(|(l, _)| l.len()),
+            Err(CodecError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn test_decode_truncated() {
+        let mut buffer = [0u8; 64];
+        let written = sample().encode(&mut buffer).unwrap();
+
+        for len in [0, HEADER_LEN - 1, HEADER_LEN + 3, written - 1] {
+            assert_eq!(
+                SizedDoubleLinkedList::<u16, 8>::decode(&buffer[..len]).map(|(l, _)| l.len()),
+                Err(CodecError::UnexpectedEnd)
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_oversized_payload_length() {
+        let mut buffer = [0u8; 64];
+        sample().encode(&mut buffer).unwrap();
+
+        u32::MAX.encode(&mut buffer[12..]).unwrap();
+
+        assert_eq!(
+            SizedDoubleLinkedList::<u16, 8>::decode(&buffer).map(|(l, _)| l.len()),
+            Err(CodecError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn test_decode_into_other_capacity() {
+        let mut buffer = [0u8; 64];
+        sample().encode(&mut buffer).unwrap();
+
+        let (larger, _) = SizedDoubleLinkedList::<u16, 16>::decode(&buffer).unwrap();
+        assert_eq!(larger.len(), 4);
+        assert_eq!(*larger.get(0).unwrap(), 5);
+
+        assert_eq!(
+            SizedDoubleLinkedList::<u16, 3>::decode(&buffer).map(|(l, _)| l.len()),
+            Err(CodecError::LengthExceedsCapacity)
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_header() {
+        let mut buffer = [0u8; 64];
+        sample().encode(&mut buffer).unwrap();
+
+        let mut bad_magic = buffer;
+        bad_magic[0] = b'X';
+        assert_eq!(
+            SnapshotHeader::read(&bad_magic),
+            Err(CodecError::InvalidMagic)
+        );
+
+        let mut bad_version = buffer;
+        bad_version[2] = FORMAT_VERSION + 1;
+        assert_eq!(
+            SnapshotHeader::read(&bad_version),
+            Err(CodecError::UnsupportedVersion(FORMAT_VERSION + 1))
+        );
+
+        assert!(matches!(
+            SizedWorkStealingPool::<u16, 8>::decode(&buffer),
+            Err(CodecError::KindMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_decode_rejects_wrong_element_type() {
+        let mut buffer = [0u8; 64];
+        let written = sample().encode(&mut buffer).unwrap();
+
+        // Eight payload bytes only hold two of the four u32 the header announces.
+        assert_eq!(
+            SizedDoubleLinkedList::<u32, 8>::decode(&buffer[..written]).map(|(l, _)| l.len()),
+            Err(CodecError::UnexpectedEnd)
+        );
+        // Four u8 leave half of the payload unread.
+        assert_eq!(
+            SizedDoubleLinkedList::<u8, 8>::decode(&buffer[..written]).map(|(l, _)| l.len()),
+            Err(CodecError::TrailingBytes)
+        );
+    }
+
+    #[test]
+    fn test_work_stealing_pool_round_trip() {
+        let mut pool: SizedWorkStealingPool<u32, 4> = SizedWorkStealingPool::new();
+        for value in [1, 2, 3] {
+            assert!(pool.insert(value).is_ok());
+        }
+        assert_eq!(pool.take(), Some(1));
+
+        let mut buffer = [0u8; 64];
+        let written = pool.encode(&mut buffer).unwrap();
+        assert_eq!(written, HEADER_LEN + 8 + CHECKSUM_LEN);
+
+        let (mut decoded, read) = SizedWorkStealingPool::<u32, 4>::decode(&buffer).unwrap();
+        assert_eq!(read, written);
+        assert_eq!(decoded.take(), Some(2));
+        assert_eq!(decoded.steal(), Some(3));
+        assert_eq!(decoded.take(), None);
+    }
+
+    #[test]
+    fn test_composite_values() {
+        let mut list: SizedDoubleLinkedList<(Option<char>, bool), 4> = Default::default();
+        assert!(list.insert_tail((Some('é'), true)).is_ok());
+        assert!(list.insert_tail((None, false)).is_ok());
+
+        let mut buffer = [0u8; 64];
+        let written = list.encode(&mut buffer).unwrap();
+        let (decoded, _) =
+            SizedDoubleLinkedList::<(Option<char>, bool), 4>::decode(&buffer[..written]).unwrap();
+
+        assert_eq!(decoded, list);
+        assert_eq!(bool::decode(&[2]), Err(CodecError::InvalidValue));
+        assert_eq!(
+            char::decode(&[0, 0xD8, 0, 0]),
+            Err(CodecError::InvalidValue)
+        );
+        assert_eq!(<[u8; 3]>::decode(&[1, 2, 3, 4]), Ok(([1, 2, 3], 3)));
+    }
+}
@@ -0,0 +1,237 @@
+//! Conformance suite shared by every implementation of the list traits.
+//!
+//! Each check is written once against `LinkedList + Deque + Queue` and instantiated for every
+//! list type by the `conformance!` macro.
+
+#[cfg(test)]
+mod tests {
+    use datastructures::DoubleLinkedList::{Deque, LinkedList, Queue, SizedDoubleLinkedList};
+    use datastructures::{IndexError, LinkedListError};
+
+    trait List: LinkedList<i32> + Deque<i32> + Queue<i32> + Default {}
+
+    impl<L: LinkedList<i32> + Deque<i32> + Queue<i32> + Default> List for L {}
+
+    fn filled<L: List>(values: &[i32]) -> L {
+        let mut list = L::default();
+        for value in values {
+            assert!(list.insert_tail(*value).is_ok());
+        }
+        list
+    }
+
+    fn contents<L: List>(list: &L) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    fn check_empty<L: List>() {
+        let mut list = L::default();
+
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+        assert!(!list.is_full());
+        assert!(list.front().is_none());
+        assert!(list.back().is_none());
+        assert!(list.pop_front().is_none());
+        assert!(list.pop_back().is_none());
+        assert!(list.peek().is_none());
+        assert_eq!(list.iter().next(), None);
+        assert_eq!(list.get(0), Err(IndexError::new(0, 0)));
+        assert_eq!(list.remove(0), Err(IndexError::new(0, 0)));
+    }
+
+    fn check_insertions<L: List>() {
+        let mut list = L::default();
+
+        assert!(list.insert_before(0, 2).is_ok());
+        assert!(list.insert_head(0).is_ok());
+        assert!(list.insert_after(1, 4).is_ok());
+        assert!(list.insert_before(2, 3).is_ok());
+        assert!(list.insert_after(0, 1).is_ok());
+
+        assert_eq!(contents(&list), vec![0, 1, 2, 3, 4]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(*list.get(3).unwrap(), 3);
+        assert_eq!(
+            list.insert_after(5, 9),
+            Err(LinkedListError::IndexOutOfRange(IndexError::new(5, 5)))
+        );
+        assert_eq!(
+            list.insert_before(5, 9),
+            Err(LinkedListError::IndexOutOfRange(IndexError::new(5, 5)))
+        );
+    }
+
+    fn check_remove<L: List>() {
+        let mut list: L = filled(&[0, 1, 2, 3, 4]);
+
+        assert!(list.remove(4).is_ok());
+        assert!(list.remove(0).is_ok());
+        assert!(list.remove(1).is_ok());
+
+        assert_eq!(contents(&list), vec![1, 3]);
+        assert_eq!(list.remove(2), Err(IndexError::new(2, 2)));
+    }
+
+    fn check_iter<L: List>() {
+        let list: L = filled(&[1, 2, 3, 4]);
+
+        assert_eq!(list.iter().len(), 4);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            vec![4, 3, 2, 1]
+        );
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    fn check_deque<L: List>() {
+        let mut list = L::default();
+
+        assert!(list.push_back(2).is_ok());
+        assert!(list.push_front(1).is_ok());
+        assert!(list.push_back(3).is_ok());
+
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert!(list.is_empty());
+    }
+
+    fn check_queue<L: List>() {
+        let mut list = L::default();
+
+        for value in 0..5 {
+            assert!(list.enqueue(value).is_ok());
+        }
+
+        assert_eq!(list.peek(), Some(&0));
+        for value in 0..5 {
+            assert_eq!(list.dequeue(), Some(value));
+        }
+        assert_eq!(list.dequeue(), None);
+    }
+
+    fn check_predicates<L: List>() {
+        let mut list: L = filled(&[1, 2, 3, 2, 5, 6]);
+
+        assert_eq!(list.get_value_where(|v| *v > 2), Some(&3));
+        assert_eq!(list.position_where(|v| *v == 2), Some(1));
+        assert_eq!(list.position_from_back(|v| *v == 2), Some(3));
+        assert_eq!(list.count_where(|v| *v % 2 == 0), 3);
+
+        *list.get_value_where_mut(|v| *v == 5).unwrap() = 50;
+        list.iter_and_compute(|v| *v += 1);
+        assert_eq!(contents(&list), vec![2, 3, 4, 3, 51, 7]);
+
+        assert_eq!(list.remove_first_where(|v| *v == 3), Some(3));
+        list.retain(|v| *v != 51);
+        assert_eq!(contents(&list), vec![2, 4, 3, 7]);
+    }
+
+    fn check_sorting<L: List>() {
+        let mut list: L = filled(&[5, 1, 4, 1, 3]);
+
+        list.sort_by(|a, b| a.cmp(b));
+        assert_eq!(contents(&list), vec![1, 1, 3, 4, 5]);
+
+        assert_eq!(list.insert_sorted_by(2, |a, b| a.cmp(b)).unwrap(), 2);
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&4)), Ok(4));
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&6)), Err(6));
+        assert_eq!(contents(&list), vec![1, 1, 2, 3, 4, 5]);
+    }
+
+    fn check_capacity<L: List>() {
+        let mut list = L::default();
+
+        match list.capacity() {
+            Some(capacity) => {
+                for value in 0..capacity as i32 {
+                    assert!(list.push_back(value).is_ok());
+                }
+
+                assert!(list.is_full());
+                assert_eq!(list.push_front(-1).unwrap_err().into_inner(), -1);
+                assert_eq!(list.enqueue(-2).unwrap_err().into_inner(), -2);
+                assert_eq!(list.len(), capacity);
+            }
+            None => {
+                for value in 0..1000 {
+                    assert!(list.push_back(value).is_ok());
+                }
+
+                assert!(!list.is_full());
+                assert_eq!(list.len(), 1000);
+            }
+        }
+    }
+
+    macro_rules! conformance {
+        ($name:ident, $list:ty) => {
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn empty() {
+                    check_empty::<$list>();
+                }
+
+                #[test]
+                fn insertions() {
+                    check_insertions::<$list>();
+                }
+
+                #[test]
+                fn remove() {
+                    check_remove::<$list>();
+                }
+
+                #[test]
+                fn iter() {
+                    check_iter::<$list>();
+                }
+
+                #[test]
+                fn deque() {
+                    check_deque::<$list>();
+                }
+
+                #[test]
+                fn queue() {
+                    check_queue::<$list>();
+                }
+
+                #[test]
+                fn predicates() {
+                    check_predicates::<$list>();
+                }
+
+                #[test]
+                fn sorting() {
+                    check_sorting::<$list>();
+                }
+
+                #[test]
+                fn capacity() {
+                    check_capacity::<$list>();
+                }
+            }
+        };
+    }
+
+    conformance!(sized, SizedDoubleLinkedList<i32, 10>);
+
+    #[cfg(feature = "alloc")]
+    conformance!(
+        dynamic,
+        datastructures::DoubleLinkedList::DoubleLinkedList<i32>
+    );
+}
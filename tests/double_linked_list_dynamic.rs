@@ -1,9 +1,9 @@
-#![cfg(not(feature = "no-std"))]
+#![cfg(feature = "alloc")]
 
 #[cfg(test)]
 mod tests {
     use datastructures::DoubleLinkedList::DoubleLinkedList;
-    use datastructures::LinkedListError;
+    use datastructures::{IndexError, LinkedListError};
 
     #[test]
     fn test_insert_head_empty_list() {
@@ -69,7 +69,7 @@ mod tests {
 
         assert!(list.insert_head(1).is_ok());
         match list.insert_after(5, 2) {
-            Err(LinkedListError::IndexOutOfRange) => (),
+            Err(LinkedListError::IndexOutOfRange(IndexError { index: 5, len: 1 })) => (),
             _ => panic!("Expected IndexOutOfRange error"),
         }
     }
@@ -106,7 +106,7 @@ mod tests {
 
         assert!(list.insert_head(1).is_ok());
         match list.insert_before(5, 2) {
-            Err(LinkedListError::IndexOutOfRange) => (),
+            Err(LinkedListError::IndexOutOfRange(IndexError { index: 5, len: 1 })) => (),
             _ => panic!("Expected IndexOutOfRange error"),
         }
     }
@@ -131,7 +131,7 @@ mod tests {
         assert!(list.insert_tail(10).is_ok());
 
         match list.get(5) {
-            Err(LinkedListError::IndexOutOfRange) => (),
+            Err(IndexError { index: 5, len: 1 }) => (),
             _ => panic!("Expected IndexOutOfRange error"),
         }
     }
@@ -196,7 +196,7 @@ mod tests {
         assert!(list.insert_tail(1).is_ok());
 
         match list.remove(5) {
-            Err(LinkedListError::IndexOutOfRange) => (),
+            Err(IndexError { index: 5, len: 1 }) => (),
             _ => panic!("Expected IndexOutOfRange error"),
         }
     }
@@ -592,4 +592,539 @@ mod tests {
         // Original list untouched
         assert_eq!(list.len(), 200);
     }
+
+    fn collect(list: &DoubleLinkedList<i32>) -> Vec<i32> {
+        (0..list.len()).map(|i| *list.get(i).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_append_moves_all_nodes() {
+        let mut a: DoubleLinkedList<i32> = Default::default();
+        let mut b: DoubleLinkedList<i32> = Default::default();
+
+        for i in 0..3 {
+            assert!(a.insert_tail(i).is_ok());
+            assert!(b.insert_tail(i + 3).is_ok());
+        }
+
+        a.append(&mut b);
+
+        assert_eq!(collect(&a), vec![0, 1, 2, 3, 4, 5]);
+        assert!(b.is_empty());
+
+        // Both lists stay usable after the move.
+        assert!(b.insert_tail(9).is_ok());
+        assert!(a.insert_tail(6).is_ok());
+        assert_eq!(*a.get(6).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_append_into_empty() {
+        let mut a: DoubleLinkedList<i32> = Default::default();
+        let mut b: DoubleLinkedList<i32> = Default::default();
+
+        assert!(b.insert_tail(1).is_ok());
+        a.append(&mut b);
+        a.append(&mut b);
+
+        assert_eq!(collect(&a), vec![1]);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        for i in 0..6 {
+            assert!(list.insert_tail(i).is_ok());
+        }
+
+        let tail = list.split_off(4).unwrap();
+        assert_eq!(collect(&list), vec![0, 1, 2, 3]);
+        assert_eq!(collect(&tail), vec![4, 5]);
+
+        let all = list.split_off(0).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(collect(&all), vec![0, 1, 2, 3]);
+
+        assert!(list.split_off(0).unwrap().is_empty());
+        assert!(matches!(
+            list.split_off(1),
+            Err(IndexError { index: 1, len: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_splice_at() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        let mut other: DoubleLinkedList<i32> = Default::default();
+
+        for i in [1, 4] {
+            assert!(list.insert_tail(i).is_ok());
+        }
+        for i in [2, 3] {
+            assert!(other.insert_tail(i).is_ok());
+        }
+
+        assert!(list.splice_at(1, &mut other).is_ok());
+        assert_eq!(collect(&list), vec![1, 2, 3, 4]);
+        assert!(other.is_empty());
+
+        assert!(other.insert_tail(0).is_ok());
+        assert!(list.splice_at(0, &mut other).is_ok());
+        assert!(other.insert_tail(5).is_ok());
+        assert!(list.splice_at(5, &mut other).is_ok());
+        assert_eq!(collect(&list), vec![0, 1, 2, 3, 4, 5]);
+
+        assert!(matches!(
+            list.splice_at(7, &mut other),
+            Err(IndexError { index: 7, len: 6 })
+        ));
+    }
+
+    #[test]
+    fn test_sort_by_non_clone_values() {
+        struct Job {
+            priority: u32,
+        }
+
+        let mut list: DoubleLinkedList<Job> = Default::default();
+        for priority in [5, 3, 9, 1, 7] {
+            assert!(list.insert_tail(Job { priority }).is_ok());
+        }
+
+        list.sort_by(|a, b| a.priority.cmp(&b.priority));
+
+        let sorted: Vec<u32> = (0..list.len())
+            .map(|i| list.get(i).unwrap().priority)
+            .collect();
+        assert_eq!(sorted, vec![1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn test_sort_by_keeps_node_addresses() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        for value in [3, 1, 2] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        let before: Vec<*const i32> = (0..3).map(|i| list.get(i).unwrap() as *const i32).collect();
+        list.sort_by(|a, b| a.cmp(b));
+        let after: Vec<*const i32> = (0..3).map(|i| list.get(i).unwrap() as *const i32).collect();
+
+        assert_eq!(after, vec![before[1], before[2], before[0]]);
+    }
+
+    #[test]
+    fn test_sort_by_is_stable_and_relinks_both_directions() {
+        let mut list: DoubleLinkedList<(i32, char)> = Default::default();
+        for item in [
+            (2, 'a'),
+            (1, 'b'),
+            (2, 'c'),
+            (1, 'd'),
+            (0, 'e'),
+            (2, 'f'),
+            (1, 'g'),
+        ] {
+            assert!(list.insert_tail(item).is_ok());
+        }
+
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let forward: Vec<char> = (0..list.len()).map(|i| list.get(i).unwrap().1).collect();
+        assert_eq!(forward, vec!['e', 'b', 'd', 'g', 'a', 'c', 'f']);
+
+        // Indices past the midpoint are reached through `prev` links from the tail.
+        assert_eq!(list.get(6).unwrap().1, 'f');
+        assert_eq!(list.get(4).unwrap().1, 'a');
+        assert!(list.insert_tail((3, 'h')).is_ok());
+        assert!(list.insert_head((-1, 'i')).is_ok());
+        assert_eq!(list.get(8).unwrap().1, 'h');
+        assert_eq!(list.get(0).unwrap().1, 'i');
+    }
+
+    #[test]
+    fn test_sort_by_odd_lengths() {
+        for len in 0..40 {
+            let mut list: DoubleLinkedList<i32> = Default::default();
+            for i in 0..len {
+                assert!(list.insert_tail((i * 17 + 5) % 23).is_ok());
+            }
+
+            list.sort_unstable_by(|a, b| a.cmp(b));
+
+            assert_eq!(list.len(), len as usize);
+            assert!(list.is_sorted_by(|a, b| a.cmp(b)));
+        }
+    }
+
+    #[test]
+    fn test_sort_by_panicking_comparator_leaves_a_valid_list() {
+        let mut list: DoubleLinkedList<Box<i32>> = Default::default();
+        for value in [4, 3, 2, 1] {
+            assert!(list.insert_tail(Box::new(value)).is_ok());
+        }
+
+        let mut calls = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                if calls == 2 {
+                    panic!("comparator failure");
+                }
+                a.cmp(b)
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(list.len(), 4);
+
+        // Every element is kept, in an unspecified order, with consistent links.
+        let mut kept: Vec<i32> = list.iter().map(|v| **v).collect();
+        kept.sort_unstable();
+        assert_eq!(kept, vec![1, 2, 3, 4]);
+        assert_eq!(list.iter().rev().count(), 4);
+
+        list.sort_by(|a, b| a.cmp(b));
+        assert_eq!(
+            list.iter().map(|v| **v).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_sort_by_panicking_at_any_step_keeps_every_element() {
+        let values = [7, 3, 11, 0, 9, 4, 12, 1, 8, 5, 10, 2, 6];
+
+        for panic_at in 1..40 {
+            let mut list: DoubleLinkedList<Box<i32>> =
+                values.iter().map(|&v| Box::new(v)).collect();
+
+            let mut calls = 0;
+            let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                list.sort_by(|a, b| {
+                    calls += 1;
+                    if calls == panic_at {
+                        panic!("comparator failure");
+                    }
+                    a.cmp(b)
+                })
+            }));
+
+            let forward: Vec<i32> = list.iter().map(|v| **v).collect();
+            let mut backward: Vec<i32> = list.iter().rev().map(|v| **v).collect();
+            backward.reverse();
+
+            assert_eq!(list.len(), values.len());
+            assert_eq!(forward, backward);
+            assert_eq!(list.back().map(|v| **v), forward.last().copied());
+
+            let mut kept = forward;
+            kept.sort_unstable();
+            assert_eq!(kept, (0..13).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_sort_by_key_and_cached_key() {
+        let mut list: DoubleLinkedList<&str> = Default::default();
+        for word in ["ccc", "a", "bb", "dddd", "e"] {
+            assert!(list.insert_tail(word).is_ok());
+        }
+
+        list.sort_by_key(|w| w.len());
+        let by_len: Vec<&str> = (0..list.len()).map(|i| *list.get(i).unwrap()).collect();
+        assert_eq!(by_len, vec!["a", "e", "bb", "ccc", "dddd"]);
+
+        let mut calls = 0;
+        list.sort_by_cached_key(|w| {
+            calls += 1;
+            core::cmp::Reverse(w.to_string())
+        });
+        let reversed: Vec<&str> = (0..list.len()).map(|i| *list.get(i).unwrap()).collect();
+        assert_eq!(reversed, vec!["e", "dddd", "ccc", "bb", "a"]);
+        assert_eq!(calls, 5);
+        assert_eq!(*list.get(4).unwrap(), "a");
+    }
+
+    #[test]
+    fn test_is_sorted_by() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        assert!(list.is_sorted_by(|a, b| a.cmp(b)));
+
+        for value in [1, 2, 2, 3] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+        assert!(list.is_sorted_by(|a, b| a.cmp(b)));
+        assert!(!list.is_sorted_by(|a, b| b.cmp(a)));
+    }
+
+    #[test]
+    fn test_dedup_by() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        for value in [1, 1, 2, 3, 3, 3, 1, 4, 4] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        list.dedup_by(|a, b| a == b);
+
+        let values: Vec<i32> = (0..list.len()).map(|i| *list.get(i).unwrap()).collect();
+        assert_eq!(values, vec![1, 2, 3, 1, 4]);
+        assert_eq!(*list.get(4).unwrap(), 4);
+        assert!(list.insert_tail(5).is_ok());
+        assert_eq!(*list.get(5).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_insert_sorted_by() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+
+        assert_eq!(list.insert_sorted_by(5, |a, b| a.cmp(b)).unwrap(), 0);
+        assert_eq!(list.insert_sorted_by(1, |a, b| a.cmp(b)).unwrap(), 0);
+        assert_eq!(list.insert_sorted_by(9, |a, b| a.cmp(b)).unwrap(), 2);
+        assert_eq!(list.insert_sorted_by(7, |a, b| a.cmp(b)).unwrap(), 2);
+        assert_eq!(list.insert_sorted_by(3, |a, b| a.cmp(b)).unwrap(), 1);
+        assert_eq!(list.insert_sorted_by(7, |a, b| a.cmp(b)).unwrap(), 4);
+
+        assert_eq!(collect(&list), vec![1, 3, 5, 7, 7, 9]);
+        assert!(list.is_sorted_by(|a, b| a.cmp(b)));
+    }
+
+    #[test]
+    fn test_find_sorted_by() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&1)), Err(0));
+
+        for value in [10, 20, 30, 40, 50] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        for (i, value) in [10, 20, 30, 40, 50].iter().enumerate() {
+            assert_eq!(list.find_sorted_by(|v| v.cmp(value)), Ok(i));
+        }
+
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&0)), Err(0));
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&35)), Err(3));
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&99)), Err(5));
+    }
+
+    #[test]
+    fn test_merge_sorted_by() {
+        let mut a: DoubleLinkedList<i32> = Default::default();
+        let mut b: DoubleLinkedList<i32> = Default::default();
+
+        for value in [2, 4, 6] {
+            assert!(a.insert_tail(value).is_ok());
+        }
+        for value in [1, 4, 5, 9] {
+            assert!(b.insert_tail(value).is_ok());
+        }
+
+        a.merge_sorted_by(&mut b, |x, y| x.cmp(y));
+
+        assert_eq!(collect(&a), vec![1, 2, 4, 4, 5, 6, 9]);
+        assert_eq!(*a.get(6).unwrap(), 9);
+        assert!(b.is_empty());
+
+        let mut empty: DoubleLinkedList<i32> = Default::default();
+        empty.merge_sorted_by(&mut a, |x, y| x.cmp(y));
+        assert_eq!(empty.len(), 7);
+        assert_eq!(*empty.get(5).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_merge_sorted_by_panicking_comparator_keeps_both_lists_valid() {
+        let mut a = DoubleLinkedList::from([Box::new(2), Box::new(4), Box::new(6)]);
+        let mut b = DoubleLinkedList::from([Box::new(1), Box::new(3), Box::new(5)]);
+
+        let mut calls = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            a.merge_sorted_by(&mut b, |x, y| {
+                calls += 1;
+                if calls == 3 {
+                    panic!("comparator failure");
+                }
+                x.cmp(y)
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(a.len() + b.len(), 6);
+        assert_eq!(a.iter().count(), a.len());
+        assert_eq!(b.iter().rev().count(), b.len());
+        assert!(a.is_sorted_by(|x, y| x.cmp(y)));
+
+        a.merge_sorted_by(&mut b, |x, y| x.cmp(y));
+        assert_eq!(
+            a.iter().map(|v| **v).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        for value in 0..8 {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        list.retain(|v| v % 3 != 0);
+
+        assert_eq!(collect(&list), vec![1, 2, 4, 5, 7]);
+        assert_eq!(*list.get(4).unwrap(), 7);
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert!(list.insert_tail(1).is_ok());
+        assert_eq!(collect(&list), vec![1]);
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut list: DoubleLinkedList<String> = Default::default();
+        for value in ["keep", "drop", "keep", "drop"] {
+            assert!(list.insert_tail(value.to_string()).is_ok());
+        }
+
+        let removed: Vec<String> = list.extract_if(|v| v == "drop").collect();
+
+        assert_eq!(removed, vec!["drop".to_string(), "drop".to_string()]);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.count_where(|v| v == "keep"), 2);
+    }
+
+    #[test]
+    fn test_remove_first_where() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        for value in [5, 8, 3, 8] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        assert_eq!(list.remove_first_where(|v| *v == 8), Some(8));
+        assert_eq!(list.remove_first_where(|v| *v == 7), None);
+        assert_eq!(list.remove_first_where(|v| *v == 5), Some(5));
+        assert_eq!(collect(&list), vec![3, 8]);
+    }
+
+    #[test]
+    fn test_position_from_back_and_count_where() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        assert_eq!(list.position_from_back(|_| true), None);
+
+        for value in [1, 2, 1, 3, 1] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        assert_eq!(list.position_from_back(|v| *v == 1), Some(4));
+        assert_eq!(list.position_from_back(|v| *v == 2), Some(1));
+        assert_eq!(list.count_where(|v| *v == 1), 3);
+        assert_eq!(list.count_where(|v| *v > 5), 0);
+    }
+
+    #[test]
+    fn test_get_value_where_mut() {
+        let mut list: DoubleLinkedList<i32> = Default::default();
+        for value in [1, 2, 3] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        *list.get_value_where_mut(|v| *v == 3).unwrap() += 10;
+
+        assert!(list.get_value_where_mut(|v| *v == 9).is_none());
+        assert_eq!(collect(&list), vec![1, 2, 13]);
+    }
+
+    #[test]
+    fn test_eq_ord_hash() {
+        use std::collections::HashSet;
+
+        let a = DoubleLinkedList::from([1, 2, 3]);
+        let b = DoubleLinkedList::from(vec![1, 3]);
+
+        assert_eq!(a, a.clone());
+        assert_ne!(a, b);
+        assert!(a < b);
+
+        let mut set = HashSet::new();
+        assert!(set.insert(a.clone()));
+        assert!(!set.insert(a));
+        assert!(set.insert(b));
+    }
+
+    #[test]
+    fn test_debug_and_index() {
+        let mut list: DoubleLinkedList<i32> = (1..=3).collect();
+
+        list[0] = 10;
+        list[2] *= 3;
+
+        assert_eq!(list[1], 2);
+        assert_eq!(format!("{list:?}"), "[10, 2, 9]");
+        assert!(list.get_mut(3).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_index_out_of_range() {
+        let list: DoubleLinkedList<i32> = Default::default();
+
+        let _ = list[0];
+    }
+
+    #[test]
+    fn test_extend_and_into_iter() {
+        let mut list = DoubleLinkedList::from([1]);
+
+        list.extend(vec![2, 3]);
+        list.extend(&[4]);
+
+        assert_eq!((&list).into_iter().count(), 4);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_conversions_with_sized_list() {
+        use datastructures::DoubleLinkedList::SizedDoubleLinkedList;
+
+        let sized = SizedDoubleLinkedList::<String, 4>::from(["a".to_string(), "b".to_string()]);
+        let dynamic = DoubleLinkedList::from(sized);
+        assert_eq!(
+            dynamic,
+            DoubleLinkedList::from(["a".to_string(), "b".to_string()])
+        );
+
+        let back = SizedDoubleLinkedList::<String, 2>::try_from(dynamic).unwrap();
+        assert_eq!(back.len(), 2);
+
+        let too_long = DoubleLinkedList::from([1, 2, 3]);
+        let rejected = SizedDoubleLinkedList::<i32, 2>::try_from(too_long).unwrap_err();
+        assert_eq!(rejected.into_inner(), DoubleLinkedList::from([1, 2, 3]));
+
+        let rejected = SizedDoubleLinkedList::<i32, 2>::try_from(vec![1, 2, 3]).unwrap_err();
+        assert_eq!(rejected.into_inner(), vec![1, 2, 3]);
+        assert_eq!(
+            SizedDoubleLinkedList::<i32, 3>::try_from(vec![1, 2, 3]).unwrap(),
+            SizedDoubleLinkedList::from([1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_send_sync() {
+        let list = DoubleLinkedList::from([1, 2, 3]);
+        let shared = std::sync::Arc::new(list);
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let list = std::sync::Arc::clone(&shared);
+                std::thread::spawn(move || list.iter().sum::<i32>())
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 6);
+        }
+
+        let owned = DoubleLinkedList::from([4]);
+        assert_eq!(std::thread::spawn(move || owned[0]).join().unwrap(), 4);
+    }
 }
@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod tests {
+    use core::pin::pin;
+    use datastructures::DoubleLinkedList::IntrusiveList;
+    use datastructures::DoubleLinkedList::intrusive::{IntrusiveListError, Links};
+    use datastructures::intrusive_adapter;
+
+    struct Task {
+        id: u32,
+        run_queue: Links<Task>,
+        all_tasks: Links<Task>,
+    }
+
+    impl Task {
+        fn new(id: u32) -> Self {
+            Self {
+                id,
+                run_queue: Links::new(),
+                all_tasks: Links::new(),
+            }
+        }
+    }
+
+    intrusive_adapter!(RunQueue = Task { run_queue });
+    intrusive_adapter!(AllTasks = Task { all_tasks });
+
+    fn ids<'a>(list: &IntrusiveList<'a, RunQueue>) -> Vec<u32> {
+        list.iter().map(|t| t.id).collect()
+    }
+
+    #[test]
+    fn test_push_back_and_front() {
+        let a = pin!(Task::new(1));
+        let a = a.into_ref();
+        let b = pin!(Task::new(2));
+        let b = b.into_ref();
+        let c = pin!(Task::new(3));
+        let c = c.into_ref();
+
+        let mut list: IntrusiveList<RunQueue> = IntrusiveList::new();
+        assert!(list.push_back(b).is_ok());
+        assert!(list.push_back(c).is_ok());
+        assert!(list.push_front(a).is_ok());
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(ids(&list), vec![1, 2, 3]);
+        assert_eq!(list.front().unwrap().id, 1);
+        assert_eq!(list.back().unwrap().id, 3);
+    }
+
+    #[test]
+    fn test_reverse_iteration() {
+        let a = pin!(Task::new(1));
+        let a = a.into_ref();
+        let b = pin!(Task::new(2));
+        let b = b.into_ref();
+
+        let mut list: IntrusiveList<RunQueue> = IntrusiveList::new();
+        list.push_back(a).unwrap();
+        list.push_back(b).unwrap();
+
+        let reversed: Vec<u32> = list.iter().rev().map(|t| t.id).collect();
+        assert_eq!(reversed, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_remove_middle_in_place() {
+        let a = pin!(Task::new(1));
+        let a = a.into_ref();
+        let b = pin!(Task::new(2));
+        let b = b.into_ref();
+        let c = pin!(Task::new(3));
+        let c = c.into_ref();
+
+        let mut list: IntrusiveList<RunQueue> = IntrusiveList::new();
+        list.push_back(a).unwrap();
+        list.push_back(b).unwrap();
+        list.push_back(c).unwrap();
+
+        assert!(list.remove(b));
+        assert!(!b.run_queue.is_linked());
+        assert!(!list.remove(b));
+        assert_eq!(ids(&list), vec![1, 3]);
+
+        assert!(list.remove(a));
+        assert!(list.remove(c));
+        assert!(list.is_empty());
+        assert!(list.front().is_none());
+    }
+
+    #[test]
+    fn test_value_on_two_lists() {
+        let a = pin!(Task::new(1));
+        let a = a.into_ref();
+        let b = pin!(Task::new(2));
+        let b = b.into_ref();
+
+        let mut run_queue: IntrusiveList<RunQueue> = IntrusiveList::new();
+        let mut all_tasks: IntrusiveList<AllTasks> = IntrusiveList::new();
+
+        run_queue.push_back(a).unwrap();
+        all_tasks.push_back(a).unwrap();
+        all_tasks.push_back(b).unwrap();
+
+        assert!(run_queue.remove(a));
+        assert!(all_tasks.contains(a));
+        assert_eq!(all_tasks.len(), 2);
+    }
+
+    #[test]
+    fn test_already_linked_and_foreign_node() {
+        let a = pin!(Task::new(1));
+        let a = a.into_ref();
+
+        let mut first: IntrusiveList<RunQueue> = IntrusiveList::new();
+        let mut second: IntrusiveList<RunQueue> = IntrusiveList::new();
+
+        first.push_back(a).unwrap();
+        assert_eq!(first.push_back(a), Err(IntrusiveListError::AlreadyLinked));
+        assert_eq!(second.push_back(a), Err(IntrusiveListError::AlreadyLinked));
+
+        assert!(!second.contains(a));
+        assert!(!second.remove(a));
+        assert!(first.contains(a));
+    }
+
+    #[test]
+    fn test_insert_after_and_before() {
+        let a = pin!(Task::new(1));
+        let a = a.into_ref();
+        let b = pin!(Task::new(2));
+        let b = b.into_ref();
+        let c = pin!(Task::new(3));
+        let c = c.into_ref();
+        let d = pin!(Task::new(4));
+        let d = d.into_ref();
+
+        let mut list: IntrusiveList<RunQueue> = IntrusiveList::new();
+        assert_eq!(list.insert_after(a, b), Err(IntrusiveListError::NotLinked));
+
+        list.push_back(a).unwrap();
+        list.insert_after(a, c).unwrap();
+        list.insert_before(c, b).unwrap();
+        list.insert_after(c, d).unwrap();
+
+        assert_eq!(ids(&list), vec![1, 2, 3, 4]);
+        assert_eq!(list.back().unwrap().id, 4);
+    }
+
+    #[test]
+    fn test_pop_and_drop_unlinks() {
+        let a = pin!(Task::new(1));
+        let a = a.into_ref();
+        let b = pin!(Task::new(2));
+        let b = b.into_ref();
+        let c = pin!(Task::new(3));
+        let c = c.into_ref();
+
+        {
+            let mut list: IntrusiveList<RunQueue> = IntrusiveList::new();
+            list.push_back(a).unwrap();
+            list.push_back(b).unwrap();
+            list.push_back(c).unwrap();
+
+            assert_eq!(list.pop_front().unwrap().id, 1);
+            assert_eq!(list.pop_back().unwrap().id, 3);
+            assert!(!a.run_queue.is_linked());
+            assert!(b.run_queue.is_linked());
+        }
+
+        assert!(!b.run_queue.is_linked());
+
+        let mut list: IntrusiveList<RunQueue> = IntrusiveList::new();
+        assert!(list.push_back(b).is_ok());
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use datastructures::DoubleLinkedList::SizedDoubleLinkedList;
-    use datastructures::LinkedListError;
+    use datastructures::{CapacityError, IndexError, LinkedListError};
 
     #[test]
     fn test_insert_head_empty_list() {
@@ -67,7 +67,7 @@ mod tests {
 
         assert!(list.insert_head(1).is_ok());
         match list.insert_after(5, 2) {
-            Err(LinkedListError::IndexOutOfRange) => (),
+            Err(LinkedListError::IndexOutOfRange(IndexError { index: 5, len: 1 })) => (),
             _ => panic!("Expected IndexOutOfRange error"),
         }
     }
@@ -104,7 +104,7 @@ mod tests {
 
         assert!(list.insert_head(1).is_ok());
         match list.insert_before(5, 2) {
-            Err(LinkedListError::IndexOutOfRange) => (),
+            Err(LinkedListError::IndexOutOfRange(IndexError { index: 5, len: 1 })) => (),
             _ => panic!("Expected IndexOutOfRange error"),
         }
     }
@@ -129,7 +129,7 @@ mod tests {
         assert!(list.insert_tail(10).is_ok());
 
         match list.get(5) {
-            Err(LinkedListError::IndexOutOfRange) => (),
+            Err(IndexError { index: 5, len: 1 }) => (),
             _ => panic!("Expected IndexOutOfRange error"),
         }
     }
@@ -194,7 +194,7 @@ mod tests {
         assert!(list.insert_tail(1).is_ok());
 
         match list.remove(5) {
-            Err(LinkedListError::IndexOutOfRange) => (),
+            Err(IndexError { index: 5, len: 1 }) => (),
             _ => panic!("Expected IndexOutOfRange error"),
         }
     }
@@ -212,8 +212,8 @@ mod tests {
         assert!(list.is_full());
 
         match list.insert_tail(4) {
-            Err(LinkedListError::ListIsFull) => (),
-            _ => panic!("Expected ListIsFull error"),
+            Err(error) => assert_eq!(error.into_inner(), 4),
+            Ok(()) => panic!("Expected ListIsFull error"),
         }
     }
 
@@ -294,8 +294,8 @@ mod tests {
         assert!(list.is_full());
 
         match list.insert_tail(43) {
-            Err(LinkedListError::ListIsFull) => (),
-            _ => panic!("Expected ListIsFull error"),
+            Err(error) => assert_eq!(error.into_inner(), 43),
+            Ok(()) => panic!("Expected ListIsFull error"),
         }
 
         assert_eq!(*list.get(0).unwrap(), 42);
@@ -499,19 +499,8 @@ mod tests {
             assert!(list.insert_tail(v).is_ok());
         }
 
-        #[cfg(feature = "no-std")]
-        {
-            let arr = list.select_n_first_by::<2>(|a, b| a.cmp(b));
-            assert_eq!(arr[0], Some(1));
-            assert_eq!(arr[1], Some(2));
-        }
-
-        #[cfg(not(feature = "no-std"))]
-        {
-            let values = list.select_n_first_by::<2>(|a, b| a.cmp(b));
-            assert_eq!(values.len(), 2);
-            assert_eq!(values, vec![1, 2]);
-        }
+        let arr = list.select_n_first_by::<2>(|a, b| a.cmp(b));
+        assert_eq!(arr, [Some(1), Some(2)]);
 
         // original list untouched
         assert_eq!(list.len(), 5);
@@ -525,22 +514,42 @@ mod tests {
             assert!(list.insert_tail(v).is_ok());
         }
 
-        #[cfg(feature = "no-std")]
-        {
-            let arr = list.select_n_first_by::<5>(|a, b| a.cmp(b));
-            assert_eq!(arr[0], Some(2));
-            assert_eq!(arr[1], Some(7));
-            assert_eq!(arr[2], Some(9));
-            assert_eq!(arr[3], None);
-            assert_eq!(arr[4], None);
+        let arr = list.select_n_first_by::<5>(|a, b| a.cmp(b));
+        assert_eq!(arr[0], Some(2));
+        assert_eq!(arr[1], Some(7));
+        assert_eq!(arr[2], Some(9));
+        assert_eq!(arr[3], None);
+        assert_eq!(arr[4], None);
+    }
+
+    #[test]
+    fn test_select_n_first_by_clones_values() {
+        let mut list: SizedDoubleLinkedList<String, 10> = Default::default();
+
+        for v in ["pear", "fig", "apple", "banana"] {
+            assert!(list.insert_tail(v.to_string()).is_ok());
         }
 
-        #[cfg(not(feature = "no-std"))]
-        {
-            let values = list.select_n_first_by::<5>(|a, b| a.cmp(b));
-            assert_eq!(values.len(), 3);
-            assert_eq!(values, vec![2, 7, 9]);
+        let arr = list.select_n_first_by::<2>(|a, b| a.len().cmp(&b.len()));
+        assert_eq!(arr, [Some("fig".to_string()), Some("pear".to_string())]);
+    }
+
+    #[test]
+    fn test_sort_by_is_stable() {
+        let mut list: SizedDoubleLinkedList<(u8, usize), 63> = Default::default();
+
+        for i in 0..63 {
+            assert!(list.insert_tail(((i * 7 % 5) as u8, i)).is_ok());
         }
+
+        list.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let values: Vec<(u8, usize)> = list.iter().copied().collect();
+        assert!(
+            values
+                .windows(2)
+                .all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1))
+        );
     }
 
     #[test]
@@ -577,4 +586,485 @@ mod tests {
         assert_eq!(*list.get(1).unwrap(), 20);
         assert_eq!(*list.get(2).unwrap(), 30);
     }
+
+    fn collect<const K: usize>(list: &SizedDoubleLinkedList<i32, K>) -> Vec<i32>
+    where
+        datastructures::Const<K>: datastructures::DoubleLinkedList::sized::ValidK,
+    {
+        (0..list.len()).map(|i| *list.get(i).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_try_append_relocates_nodes() {
+        let mut a: SizedDoubleLinkedList<i32, 6> = Default::default();
+        let mut b: SizedDoubleLinkedList<i32, 6> = Default::default();
+
+        for i in 0..3 {
+            assert!(a.insert_tail(i).is_ok());
+            assert!(b.insert_tail(i + 3).is_ok());
+        }
+        assert!(a.remove(1).is_ok());
+
+        assert!(a.try_append(&mut b).is_ok());
+
+        assert_eq!(collect(&a), vec![0, 2, 3, 4, 5]);
+        assert!(b.is_empty());
+        assert!(b.insert_tail(7).is_ok());
+        assert!(a.insert_tail(6).is_ok());
+        assert!(a.is_full());
+    }
+
+    #[test]
+    fn test_try_append_full() {
+        let mut a: SizedDoubleLinkedList<i32, 3> = Default::default();
+        let mut b: SizedDoubleLinkedList<i32, 3> = Default::default();
+
+        for i in 0..2 {
+            assert!(a.insert_tail(i).is_ok());
+            assert!(b.insert_tail(i).is_ok());
+        }
+
+        match a.try_append(&mut b) {
+            Err(CapacityError { .. }) => (),
+            _ => panic!("Expected ListIsFull error"),
+        }
+
+        assert_eq!(a.len(), 2);
+        assert_eq!(b.len(), 2);
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list: SizedDoubleLinkedList<i32, 8> = Default::default();
+        for i in 0..6 {
+            assert!(list.insert_tail(i).is_ok());
+        }
+
+        let tail = list.split_off(2).unwrap();
+        assert_eq!(collect(&list), vec![0, 1]);
+        assert_eq!(collect(&tail), vec![2, 3, 4, 5]);
+
+        // Freed slots are reusable.
+        for i in 2..8 {
+            assert!(list.insert_tail(i).is_ok());
+        }
+        assert!(list.is_full());
+
+        let all = list.split_off(0).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(all.len(), 8);
+
+        match list.split_off(1) {
+            Err(IndexError { index: 1, len: 0 }) => (),
+            _ => panic!("Expected IndexOutOfRange error"),
+        }
+    }
+
+    #[test]
+    fn test_splice_at() {
+        let mut list: SizedDoubleLinkedList<i32, 6> = Default::default();
+        let mut other: SizedDoubleLinkedList<i32, 6> = Default::default();
+
+        for i in [0, 3] {
+            assert!(list.insert_tail(i).is_ok());
+        }
+        for i in [1, 2] {
+            assert!(other.insert_tail(i).is_ok());
+        }
+
+        assert!(list.splice_at(1, &mut other).is_ok());
+        assert_eq!(collect(&list), vec![0, 1, 2, 3]);
+        assert!(other.is_empty());
+
+        assert!(other.insert_tail(4).is_ok());
+        assert!(list.splice_at(4, &mut other).is_ok());
+        assert_eq!(collect(&list), vec![0, 1, 2, 3, 4]);
+
+        for i in 0..2 {
+            assert!(other.insert_tail(i).is_ok());
+        }
+        match list.splice_at(0, &mut other) {
+            Err(LinkedListError::ListIsFull(_)) => (),
+            _ => panic!("Expected ListIsFull error"),
+        }
+        match list.splice_at(9, &mut other) {
+            Err(LinkedListError::IndexOutOfRange(IndexError { index: 9, len: 5 })) => (),
+            _ => panic!("Expected IndexOutOfRange error"),
+        }
+    }
+
+    #[test]
+    fn test_insert_sorted_by() {
+        let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+
+        assert_eq!(list.insert_sorted_by(5, |a, b| a.cmp(b)).unwrap(), 0);
+        assert_eq!(list.insert_sorted_by(1, |a, b| a.cmp(b)).unwrap(), 0);
+        assert_eq!(list.insert_sorted_by(9, |a, b| a.cmp(b)).unwrap(), 2);
+        assert_eq!(list.insert_sorted_by(7, |a, b| a.cmp(b)).unwrap(), 2);
+        assert_eq!(list.insert_sorted_by(3, |a, b| a.cmp(b)).unwrap(), 1);
+
+        assert_eq!(collect(&list), vec![1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn test_insert_sorted_by_is_stable() {
+        let mut list: SizedDoubleLinkedList<(i32, char), 10> = Default::default();
+
+        for item in [(1, 'a'), (0, 'b'), (1, 'c'), (2, 'd'), (1, 'e')] {
+            assert!(list.insert_sorted_by(item, |a, b| a.0.cmp(&b.0)).is_ok());
+        }
+
+        let order: Vec<char> = (0..list.len()).map(|i| list.get(i).unwrap().1).collect();
+        assert_eq!(order, vec!['b', 'a', 'c', 'e', 'd']);
+    }
+
+    #[test]
+    fn test_insert_sorted_by_full() {
+        let mut list: SizedDoubleLinkedList<i32, 1> = Default::default();
+
+        assert!(list.insert_sorted_by(1, |a, b| a.cmp(b)).is_ok());
+        match list.insert_sorted_by(0, |a, b| a.cmp(b)) {
+            Err(error) => assert_eq!(error.into_inner(), 0),
+            Ok(_) => panic!("Expected ListIsFull error"),
+        }
+    }
+
+    #[test]
+    fn test_find_sorted_by() {
+        let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&1)), Err(0));
+
+        for value in [10, 20, 30, 40, 50, 60] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        for (i, value) in [10, 20, 30, 40, 50, 60].iter().enumerate() {
+            assert_eq!(list.find_sorted_by(|v| v.cmp(value)), Ok(i));
+        }
+
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&5)), Err(0));
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&25)), Err(2));
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&45)), Err(4));
+        assert_eq!(list.find_sorted_by(|v| v.cmp(&65)), Err(6));
+    }
+
+    #[test]
+    fn test_merge_sorted_by() {
+        let mut a: SizedDoubleLinkedList<(i32, char), 10> = Default::default();
+        let mut b: SizedDoubleLinkedList<(i32, char), 10> = Default::default();
+
+        for item in [(1, 'a'), (3, 'a'), (5, 'a')] {
+            assert!(a.insert_tail(item).is_ok());
+        }
+        for item in [(0, 'b'), (3, 'b'), (6, 'b')] {
+            assert!(b.insert_tail(item).is_ok());
+        }
+
+        assert!(a.merge_sorted_by(&mut b, |x, y| x.0.cmp(&y.0)).is_ok());
+
+        let merged: Vec<(i32, char)> = (0..a.len()).map(|i| *a.get(i).unwrap()).collect();
+        assert_eq!(
+            merged,
+            vec![(0, 'b'), (1, 'a'), (3, 'a'), (3, 'b'), (5, 'a'), (6, 'b')]
+        );
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn test_merge_sorted_by_panicking_comparator_keeps_both_lists_valid() {
+        use std::rc::Rc;
+
+        let token = Rc::new(());
+        let mut a: SizedDoubleLinkedList<(i32, Rc<()>), 8> = Default::default();
+        let mut b: SizedDoubleLinkedList<(i32, Rc<()>), 8> = Default::default();
+
+        for key in [2, 4, 6] {
+            assert!(a.insert_tail((key, Rc::clone(&token))).is_ok());
+        }
+        for key in [1, 3, 5] {
+            assert!(b.insert_tail((key, Rc::clone(&token))).is_ok());
+        }
+
+        let mut calls = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            a.merge_sorted_by(&mut b, |x, y| {
+                calls += 1;
+                if calls == 3 {
+                    panic!("comparator failure");
+                }
+                x.0.cmp(&y.0)
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(a.len() + b.len(), 6);
+        assert_eq!(a.iter().count(), a.len());
+        assert_eq!(b.iter().rev().count(), b.len());
+        assert_eq!(Rc::strong_count(&token), 7);
+
+        assert!(a.merge_sorted_by(&mut b, |x, y| x.0.cmp(&y.0)).is_ok());
+        assert_eq!(
+            a.iter().map(|v| v.0).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert!(b.is_empty());
+
+        while a.pop_front().is_some() {}
+        assert_eq!(Rc::strong_count(&token), 1);
+    }
+
+    #[test]
+    fn test_ordered_sized_list() {
+        use datastructures::DoubleLinkedList::OrderedSizedList;
+
+        let mut deadlines = OrderedSizedList::<u32, 4, _>::new(|a: &u32, b: &u32| a.cmp(b));
+
+        assert_eq!(deadlines.insert(30).unwrap(), 0);
+        assert_eq!(deadlines.insert(10).unwrap(), 0);
+        assert_eq!(deadlines.insert(20).unwrap(), 1);
+
+        assert_eq!(deadlines.first(), Some(&10));
+        assert_eq!(deadlines.last(), Some(&30));
+        assert_eq!(deadlines.find(&20), Ok(1));
+        assert!(!deadlines.contains(&15));
+
+        let mut later = OrderedSizedList::<u32, 4, _>::new(|a: &u32, b: &u32| a.cmp(b));
+        assert!(later.insert(25).is_ok());
+        assert!(deadlines.merge(&mut later).is_ok());
+        assert!(deadlines.is_full());
+        assert_eq!(*deadlines.get(2).unwrap(), 25);
+
+        assert!(deadlines.remove(0).is_ok());
+        assert_eq!(deadlines.first(), Some(&20));
+
+        let mut unsorted: SizedDoubleLinkedList<u32, 4> = Default::default();
+        for value in [3, 1, 2] {
+            assert!(unsorted.insert_tail(value).is_ok());
+        }
+        let ordered = OrderedSizedList::from_list(unsorted, |a: &u32, b: &u32| b.cmp(a));
+        assert_eq!(ordered.first(), Some(&3));
+        assert_eq!(ordered.into_inner().len(), 3);
+    }
+
+    #[test]
+    fn test_ordered_sized_list_merge_with_another_order() {
+        use datastructures::DoubleLinkedList::OrderedSizedList;
+
+        let mut ascending = OrderedSizedList::<u32, 6, _>::new(|a: &u32, b: &u32| a.cmp(b));
+        for value in [10, 30, 50] {
+            assert!(ascending.insert(value).is_ok());
+        }
+
+        let mut descending = OrderedSizedList::<u32, 6, _>::new(|a: &u32, b: &u32| b.cmp(a));
+        for value in [20, 40, 60] {
+            assert!(descending.insert(value).is_ok());
+        }
+        assert_eq!(descending.first(), Some(&60));
+
+        assert!(ascending.merge(&mut descending).is_ok());
+        assert!(descending.is_empty());
+
+        let merged: Vec<u32> = (0..ascending.len())
+            .map(|i| *ascending.get(i).unwrap())
+            .collect();
+        assert_eq!(merged, vec![10, 20, 30, 40, 50, 60]);
+
+        let mut overflow = OrderedSizedList::<u32, 6, _>::new(|a: &u32, b: &u32| a.cmp(b));
+        assert!(overflow.insert(1).is_ok());
+        assert!(ascending.merge(&mut overflow).is_err());
+        assert_eq!(overflow.len(), 1);
+        assert_eq!(ascending.len(), 6);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+        for value in 0..8 {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        list.retain(|v| v % 3 != 0);
+
+        assert_eq!(collect(&list), vec![1, 2, 4, 5, 7]);
+
+        // Freed slots are reused by later insertions.
+        for value in 10..15 {
+            assert!(list.insert_tail(value).is_ok());
+        }
+        assert!(list.is_full());
+        assert_eq!(*list.get(9).unwrap(), 14);
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+        for value in 1..=6 {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        let evens: Vec<i32> = list
+            .extract_if(|v| {
+                *v *= 10;
+                *v % 20 == 0
+            })
+            .collect();
+
+        assert_eq!(evens, vec![20, 40, 60]);
+        assert_eq!(collect(&list), vec![10, 30, 50]);
+    }
+
+    #[test]
+    fn test_extract_if_dropped_early() {
+        let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+        for value in 1..=5 {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        assert_eq!(list.extract_if(|v| *v > 1).next(), Some(2));
+        assert_eq!(collect(&list), vec![1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_remove_first_where() {
+        let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+        for value in [5, 8, 3, 8] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        assert_eq!(list.remove_first_where(|v| *v == 8), Some(8));
+        assert_eq!(list.remove_first_where(|v| *v == 7), None);
+        assert_eq!(collect(&list), vec![5, 3, 8]);
+    }
+
+    #[test]
+    fn test_position_from_back_and_count_where() {
+        let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+        assert_eq!(list.position_from_back(|_| true), None);
+
+        for value in [1, 2, 1, 3, 1, 4] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+        assert!(list.remove(0).is_ok());
+
+        assert_eq!(list.position_from_back(|v| *v == 1), Some(3));
+        assert_eq!(list.position_from_back(|v| *v == 2), Some(0));
+        assert_eq!(list.position_from_back(|v| *v == 9), None);
+        assert_eq!(list.count_where(|v| *v == 1), 2);
+        assert_eq!(list.count_where(|v| *v > 1), 3);
+    }
+
+    #[test]
+    fn test_get_value_where_mut() {
+        let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+        for value in [1, 2, 3] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+
+        *list.get_value_where_mut(|v| *v == 2).unwrap() = 20;
+
+        assert!(list.get_value_where_mut(|v| *v == 9).is_none());
+        assert_eq!(collect(&list), vec![1, 20, 3]);
+    }
+
+    #[test]
+    fn test_insert_before_len_out_of_range() {
+        let mut list: SizedDoubleLinkedList<i32, 10> = Default::default();
+
+        assert!(list.insert_before(0, 1).is_ok());
+        match list.insert_before(1, 2) {
+            Err(LinkedListError::IndexOutOfRange(IndexError { index: 1, len: 1 })) => (),
+            _ => panic!("Expected IndexOutOfRange error"),
+        }
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn test_eq_ignores_slot_layout() {
+        let mut a: SizedDoubleLinkedList<i32, 10> = Default::default();
+        let mut b: SizedDoubleLinkedList<i32, 10> = Default::default();
+
+        for value in [0, 1, 2, 3] {
+            assert!(a.insert_tail(value).is_ok());
+        }
+        assert!(a.remove(0).is_ok());
+
+        for value in [3, 2, 1] {
+            assert!(b.insert_head(value).is_ok());
+        }
+
+        assert_eq!(a, b);
+        assert!(b.insert_tail(4).is_ok());
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_ord_and_hash() {
+        use std::collections::HashSet;
+
+        let a = SizedDoubleLinkedList::<i32, 4>::from([1, 2, 3]);
+        let b = SizedDoubleLinkedList::<i32, 4>::from([1, 3]);
+        let c = SizedDoubleLinkedList::<i32, 4>::from([1, 2]);
+
+        assert!(a < b);
+        assert!(c < a);
+        assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+
+        let mut set = HashSet::new();
+        assert!(set.insert(a.clone()));
+        assert!(!set.insert(a));
+        assert!(set.insert(b));
+    }
+
+    #[test]
+    fn test_debug() {
+        let list = SizedDoubleLinkedList::<i32, 4>::from([1, 2, 3]);
+
+        assert_eq!(format!("{list:?}"), "[1, 2, 3]");
+    }
+
+    #[test]
+    fn test_index() {
+        let mut list = SizedDoubleLinkedList::<i32, 4>::from([10, 20, 30]);
+
+        list[1] += 5;
+        *list.get_mut(2).unwrap() = 33;
+
+        assert_eq!(list[0], 10);
+        assert_eq!(list[1], 25);
+        assert_eq!(list[2], 33);
+        assert!(list.get_mut(3).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_index_out_of_range() {
+        let list = SizedDoubleLinkedList::<i32, 4>::from([10]);
+
+        let _ = list[1];
+    }
+
+    #[test]
+    fn test_extend_and_iterators() {
+        let mut list: SizedDoubleLinkedList<i32, 6> = [1, 2].into_iter().collect();
+
+        list.extend([3, 4]);
+        list.extend(&[5]);
+
+        assert_eq!(
+            (&list).into_iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            list.into_iter().rev().collect::<Vec<_>>(),
+            vec![5, 4, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity")]
+    fn test_extend_full() {
+        let mut list: SizedDoubleLinkedList<i32, 2> = Default::default();
+
+        list.extend([1, 2, 3]);
+    }
 }
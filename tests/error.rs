@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::DoubleLinkedList::SizedDoubleLinkedList;
+    use datastructures::DoubleLinkedList::intrusive::IntrusiveListError;
+    use datastructures::codec::CodecError;
+    use datastructures::{CapacityError, IndexError, LinkedListError};
+    use std::error::Error;
+
+    #[test]
+    fn test_capacity_error_hands_back_value() {
+        let mut list: SizedDoubleLinkedList<String, 1> = SizedDoubleLinkedList::new();
+        list.insert_tail("kept".to_string()).unwrap();
+
+        let error = list.insert_head("rejected".to_string()).unwrap_err();
+        assert_eq!(error.value(), "rejected");
+        assert_eq!(error.clone(), error);
+        assert_eq!(error.into_inner(), "rejected");
+    }
+
+    #[test]
+    fn test_display_messages() {
+        assert_eq!(CapacityError::new(7).to_string(), "container is full");
+        assert_eq!(
+            IndexError::new(4, 2).to_string(),
+            "index 4 out of range for length 2"
+        );
+        assert_eq!(
+            LinkedListError::<u8>::from(IndexError::new(1, 0)).to_string(),
+            "index 1 out of range for length 0"
+        );
+        assert_eq!(
+            IntrusiveListError::NotLinked.to_string(),
+            "anchor value is not linked into this list"
+        );
+        assert_eq!(
+            CodecError::UnsupportedVersion(9).to_string(),
+            "unsupported snapshot format version 9"
+        );
+    }
+
+    #[test]
+    fn test_debug_does_not_require_debug_values() {
+        struct Opaque;
+
+        let error = CapacityError::new(Opaque);
+        assert_eq!(format!("{error:?}"), "CapacityError { .. }");
+
+        let error: LinkedListError<Opaque> = error.into();
+        assert_eq!(format!("{error:?}"), "ListIsFull(CapacityError { .. })");
+    }
+
+    #[test]
+    fn test_linked_list_error_into_value() {
+        let mut list: SizedDoubleLinkedList<u8, 2> = SizedDoubleLinkedList::new();
+        list.insert_tail(1).unwrap();
+        list.insert_tail(2).unwrap();
+
+        let full = list.insert_after(0, 3).unwrap_err();
+        assert_eq!(full, LinkedListError::ListIsFull(CapacityError::new(3)));
+        assert_eq!(full.into_value(), Some(3));
+
+        list.remove(0).unwrap();
+        let out_of_range = list.insert_before(3, 4).unwrap_err();
+        assert_eq!(
+            out_of_range,
+            LinkedListError::IndexOutOfRange(IndexError { index: 3, len: 1 })
+        );
+        assert_eq!(out_of_range.into_value(), None);
+    }
+
+    #[test]
+    fn test_errors_box_into_dyn_error() {
+        fn first_two(list: &SizedDoubleLinkedList<u8, 4>) -> Result<u8, Box<dyn Error>> {
+            Ok(list.get(0)? + list.get(1)?)
+        }
+
+        let list = SizedDoubleLinkedList::<u8, 4>::from([5]);
+        let error = first_two(&list).unwrap_err();
+        assert_eq!(error.to_string(), "index 1 out of range for length 1");
+
+        let error: Box<dyn Error> = Box::new(CapacityError::new(1u8));
+        assert!(error.source().is_none());
+    }
+}
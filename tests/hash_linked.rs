@@ -0,0 +1,186 @@
+#![cfg(feature = "std")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::hash::{LinkedHashMap, Order};
+
+    use std::rc::Rc;
+
+    fn keys<V, S>(map: &LinkedHashMap<&'static str, V, S>) -> Vec<&'static str> {
+        map.keys().copied().collect()
+    }
+
+    #[test]
+    fn insertion_order_is_kept() {
+        let mut map = LinkedHashMap::new();
+        assert_eq!(map.insert("b", 1), None);
+        assert_eq!(map.insert("a", 2), None);
+        assert_eq!(map.insert("c", 3), None);
+
+        assert_eq!(map.insert("b", 10), Some(1));
+        assert_eq!(map.get_mut("a").map(|v| *v += 1), Some(()));
+
+        assert_eq!(keys(&map), ["b", "a", "c"]);
+        assert_eq!(map.get("b"), Some(&10));
+        assert_eq!(map.get("a"), Some(&3));
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn access_order_moves_touched_entries_to_the_back() {
+        let mut map = LinkedHashMap::with_order(Order::Access);
+        map.extend([("a", 1), ("b", 2), ("c", 3)]);
+
+        assert_eq!(map.get("a"), Some(&1));
+        assert_eq!(keys(&map), ["a", "b", "c"]);
+
+        map.get_mut("a");
+        assert_eq!(keys(&map), ["b", "c", "a"]);
+
+        map.insert("b", 20);
+        assert_eq!(keys(&map), ["c", "a", "b"]);
+        assert_eq!(map.front(), Some((&"c", &3)));
+        assert_eq!(map.back(), Some((&"b", &20)));
+    }
+
+    #[test]
+    fn move_and_pop_at_both_ends() {
+        let mut map: LinkedHashMap<_, _> = [("a", 1), ("b", 2), ("c", 3), ("d", 4)]
+            .into_iter()
+            .collect();
+
+        assert!(map.move_to_back("a"));
+        assert!(map.move_to_front("c"));
+        assert!(map.move_to_front("c"));
+        assert!(!map.move_to_back("z"));
+        assert_eq!(keys(&map), ["c", "b", "d", "a"]);
+
+        assert_eq!(map.pop_front(), Some(("c", 3)));
+        assert_eq!(map.pop_back(), Some(("a", 1)));
+        assert!(!map.contains_key("c"));
+        assert_eq!(keys(&map), ["b", "d"]);
+    }
+
+    #[test]
+    fn remove_relinks_neighbours() {
+        let mut map: LinkedHashMap<_, _> = (0..5).map(|i| (i, i * 10)).collect();
+
+        assert_eq!(map.remove(&2), Some(20));
+        assert_eq!(map.remove(&2), None);
+        assert_eq!(map.remove_entry(&0), Some((0, 0)));
+        assert_eq!(map.remove(&4), Some(40));
+
+        assert_eq!(map.iter().collect::<Vec<_>>(), [(&1, &10), (&3, &30)]);
+        assert_eq!(map.front(), Some((&1, &10)));
+        assert_eq!(map.back(), Some((&3, &30)));
+
+        map.insert(2, 20);
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 3, 2]);
+    }
+
+    #[test]
+    fn iteration_in_both_directions() {
+        let map: LinkedHashMap<_, _> = (0..6).map(|i| (i, i)).collect();
+
+        let mut iter = map.iter();
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.next(), Some((&0, &0)));
+        assert_eq!(iter.next_back(), Some((&5, &5)));
+        assert_eq!(iter.len(), 4);
+
+        assert_eq!(
+            map.values().rev().copied().collect::<Vec<_>>(),
+            [5, 4, 3, 2, 1, 0]
+        );
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            (0..6).map(|i| (i, i)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn borrowed_keys_need_no_clone() {
+        let mut map = LinkedHashMap::new();
+        map.insert(String::from("alpha"), 1);
+        map.insert(String::from("beta"), 2);
+
+        assert_eq!(map.get("alpha"), Some(&1));
+        assert!(map.contains_key("beta"));
+        assert_eq!(map.remove_entry("alpha"), Some((String::from("alpha"), 1)));
+        assert_eq!(format!("{map:?}"), r#"{"beta": 2}"#);
+    }
+
+    #[test]
+    fn retain_clear_and_equality() {
+        let mut map: LinkedHashMap<_, _> = (0..10).map(|i| (i, i)).collect();
+        map.retain(|key, value| {
+            *value *= 2;
+            key % 3 == 0
+        });
+
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            [(&0, &0), (&3, &6), (&6, &12), (&9, &18)]
+        );
+
+        let copy = map.clone();
+        assert_eq!(copy, map);
+        map.move_to_back(&0);
+        assert_ne!(copy, map);
+
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.pop_front(), None);
+        map.insert(1, 1);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn every_value_is_dropped_once() {
+        let counter = Rc::new(());
+        {
+            let mut map = LinkedHashMap::new();
+            for i in 0..8 {
+                map.insert(i, Rc::clone(&counter));
+            }
+            map.insert(0, Rc::clone(&counter));
+            map.remove(&1);
+            map.pop_back();
+
+            let mut iter = map.into_iter();
+            iter.next();
+            assert_eq!(Rc::strong_count(&counter), 6);
+        }
+
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn least_recently_used_cache() {
+        const CAPACITY: usize = 3;
+
+        fn fetch(cache: &mut LinkedHashMap<u32, u32>, key: u32, misses: &mut u32) -> u32 {
+            if let Some(value) = cache.get_mut(&key) {
+                return *value;
+            }
+
+            *misses += 1;
+            if cache.len() == CAPACITY {
+                cache.pop_front();
+            }
+            cache.insert(key, key * key);
+            key * key
+        }
+
+        let mut cache = LinkedHashMap::with_order(Order::Access);
+        let mut misses = 0;
+
+        for key in [1, 2, 3, 1, 4, 1, 2, 5, 1] {
+            fetch(&mut cache, key, &mut misses);
+        }
+
+        assert_eq!(misses, 6);
+        assert_eq!(cache.keys().copied().collect::<Vec<_>>(), [2, 5, 1]);
+        assert_eq!(cache.order(), Order::Access);
+    }
+}
@@ -0,0 +1,259 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::hash::{BuildFnvHasher, Entry, FnvHasher, SizedHashMap};
+    use std::collections::HashMap;
+    use std::hash::{BuildHasher, Hasher};
+    use std::rc::Rc;
+
+    /// Hasher sending every key to one of four slots near the end of the table, forcing long
+    /// probe sequences that wrap around.
+    #[derive(Clone, Default)]
+    struct Colliding;
+
+    struct CollidingHasher(u64);
+
+    impl Hasher for CollidingHasher {
+        fn write(&mut self, bytes: &[u8]) {
+            for &byte in bytes {
+                self.0 = self.0.wrapping_add(byte as u64);
+            }
+        }
+
+        fn finish(&self) -> u64 {
+            28 + self.0 % 4
+        }
+    }
+
+    impl BuildHasher for Colliding {
+        type Hasher = CollidingHasher;
+
+        fn build_hasher(&self) -> CollidingHasher {
+            CollidingHasher(0)
+        }
+    }
+
+    /// Deterministic pseudo-random sequence.
+    fn lcg(seed: &mut u64) -> u64 {
+        *seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        *seed >> 33
+    }
+
+    #[test]
+    fn insert_get_and_replace() {
+        let mut map: SizedHashMap<u32, &str, 8> = SizedHashMap::new();
+
+        assert!(map.is_empty());
+        assert_eq!(map.insert(1, "one"), Ok(None));
+        assert_eq!(map.insert(2, "two"), Ok(None));
+        assert_eq!(map.insert(1, "uno"), Ok(Some("one")));
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&1), Some(&"uno"));
+        assert_eq!(map[&2], "two");
+        assert_eq!(map.get(&3), None);
+        assert!(map.contains_key(&2));
+
+        *map.get_mut(&2).unwrap() = "dos";
+        assert_eq!(map.get_key_value(&2), Some((&2, &"dos")));
+    }
+
+    #[test]
+    fn full_map_rejects_new_keys_only() {
+        let mut map: SizedHashMap<u32, u32, 4> = SizedHashMap::new();
+        for key in 0..4 {
+            map.insert(key, key).unwrap();
+        }
+
+        assert!(map.is_full());
+        assert_eq!(map.insert(9, 9).unwrap_err().into_inner(), (9, 9));
+        assert_eq!(map.insert(3, 30), Ok(Some(3)));
+        assert_eq!(map.entry(9).err().map(|e| e.into_inner()), Some(9));
+
+        assert_eq!(map.remove(&0), Some(0));
+        assert_eq!(map.insert(9, 9), Ok(None));
+        for key in 1..4 {
+            assert!(map.contains_key(&key));
+        }
+    }
+
+    #[test]
+    fn zero_capacity_map() {
+        let mut map: SizedHashMap<u32, u32, 0> = SizedHashMap::new();
+
+        assert!(map.insert(1, 1).is_err());
+        assert!(map.entry(1).is_err());
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.remove(&1), None);
+    }
+
+    #[test]
+    fn entry_api() {
+        let mut counts: SizedHashMap<&str, u32, 8> = SizedHashMap::new();
+
+        for word in ["a", "b", "a", "c", "a"] {
+            *counts.entry(word).unwrap().or_default() += 1;
+        }
+        assert_eq!(counts["a"], 3);
+        assert_eq!(counts["c"], 1);
+
+        counts
+            .entry("b")
+            .unwrap()
+            .and_modify(|n| *n += 10)
+            .or_insert(0);
+        assert_eq!(counts["b"], 11);
+
+        match counts.entry("c").unwrap() {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.key(), &"c");
+                assert_eq!(entry.insert(5), 1);
+                assert_eq!(entry.remove_entry(), ("c", 5));
+            }
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+
+        match counts.entry("d").unwrap() {
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), &"d");
+                *entry.insert(1) += 1;
+            }
+            Entry::Occupied(_) => panic!("expected a vacant entry"),
+        }
+
+        assert_eq!(counts.get("d"), Some(&2));
+        assert!(!counts.contains_key("c"));
+    }
+
+    #[test]
+    fn lookups_through_borrowed_keys() {
+        let mut map: SizedHashMap<String, u32, 4> = SizedHashMap::new();
+        map.insert(String::from("eth0"), 1).unwrap();
+
+        assert_eq!(map.get("eth0"), Some(&1));
+        assert_eq!(map.remove_entry("eth0"), Some((String::from("eth0"), 1)));
+    }
+
+    #[test]
+    fn removals_leave_no_tombstones() {
+        let mut map: SizedHashMap<u32, u32, 16, Colliding> = SizedHashMap::default();
+
+        // Repeated churn on a fully colliding table keeps every key reachable.
+        for round in 0..100u32 {
+            for key in 0..16 {
+                map.insert(round * 16 + key, key).unwrap();
+            }
+            for key in 0..16 {
+                assert_eq!(map.get(&(round * 16 + key)), Some(&key));
+            }
+            for key in 0..16 {
+                assert_eq!(map.remove(&(round * 16 + key)), Some(key));
+            }
+            assert!(map.is_empty());
+        }
+    }
+
+    #[test]
+    fn matches_std_hash_map_under_random_operations() {
+        for mut seed in [1, 42, 1_234, 99_999] {
+            let mut map: SizedHashMap<u32, u32, 32, Colliding> = SizedHashMap::default();
+            let mut model = HashMap::new();
+
+            for step in 0..20_000u32 {
+                let key = (lcg(&mut seed) % 48) as u32;
+
+                match lcg(&mut seed) % 8 {
+                    0..=3 => match map.insert(key, step) {
+                        Ok(previous) => assert_eq!(previous, model.insert(key, step)),
+                        Err(_) => assert!(model.len() == 32 && !model.contains_key(&key)),
+                    },
+                    4..=6 => assert_eq!(map.remove(&key), model.remove(&key)),
+                    _ => {
+                        map.retain(|k, _| k % 7 != step % 7);
+                        model.retain(|k, _| k % 7 != step % 7);
+                    }
+                }
+
+                assert_eq!(map.len(), model.len());
+                for (key, value) in &model {
+                    assert_eq!(map.get(key), Some(value));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn iteration_and_retain() {
+        let mut map: SizedHashMap<u32, u32, 16> = SizedHashMap::new();
+        for key in 0..10 {
+            map.insert(key, key * 10).unwrap();
+        }
+
+        let mut keys: Vec<_> = map.keys().copied().collect();
+        keys.sort_unstable();
+        assert_eq!(keys, (0..10).collect::<Vec<_>>());
+        assert_eq!(map.iter().len(), 10);
+
+        for value in map.values_mut() {
+            *value += 1;
+        }
+        for (key, value) in &mut map {
+            assert_eq!(*value, key * 10 + 1);
+        }
+
+        map.retain(|key, _| key % 2 == 0);
+        let mut values: Vec<_> = map.values().copied().collect();
+        values.sort_unstable();
+        assert_eq!(values, vec![1, 21, 41, 61, 81]);
+    }
+
+    #[test]
+    fn clone_eq_and_debug() {
+        let mut map: SizedHashMap<u32, u32, 4> = SizedHashMap::new();
+        map.insert(1, 10).unwrap();
+
+        let mut copy = map.clone();
+        assert_eq!(copy, map);
+
+        copy.insert(2, 20).unwrap();
+        assert_ne!(copy, map);
+        assert_eq!(format!("{map:?}"), "{1: 10}");
+    }
+
+    #[test]
+    fn values_are_dropped() {
+        let value = Rc::new(());
+
+        {
+            let mut map: SizedHashMap<u32, Rc<()>, 8> = SizedHashMap::new();
+            for key in 0..6 {
+                map.insert(key, Rc::clone(&value)).unwrap();
+            }
+
+            map.insert(0, Rc::clone(&value)).unwrap();
+            map.remove(&1);
+            map.retain(|key, _| *key < 4);
+            assert_eq!(Rc::strong_count(&value), 4);
+        }
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn pluggable_hashers() {
+        let mut map: SizedHashMap<u32, u32, 8, std::hash::RandomState> =
+            SizedHashMap::with_hasher(std::hash::RandomState::new());
+        map.insert(1, 1).unwrap();
+        assert_eq!(map.get(&1), Some(&1));
+
+        const TABLE: SizedHashMap<u8, u8, 4> = SizedHashMap::new();
+        assert!(TABLE.is_empty());
+
+        // FNV-1a test vector.
+        let mut hasher = FnvHasher::new();
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(BuildFnvHasher.hash_one(1u8), BuildFnvHasher.hash_one(1u8));
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::hash::SizedHashSet;
+
+    #[test]
+    fn insert_contains_and_remove() {
+        let mut set: SizedHashSet<u32, 4> = SizedHashSet::new();
+
+        assert_eq!(set.insert(1), Ok(true));
+        assert_eq!(set.insert(1), Ok(false));
+        assert_eq!(set.insert(2), Ok(true));
+
+        assert!(set.contains(&1));
+        assert_eq!(set.get(&2), Some(&2));
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(&1));
+        assert!(!set.remove(&1));
+        assert_eq!(set.take(&2), Some(2));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn full_set_hands_back_the_value() {
+        let mut set: SizedHashSet<u32, 2> = SizedHashSet::new();
+        set.insert(1).unwrap();
+        set.insert(2).unwrap();
+
+        assert!(set.is_full());
+        assert_eq!(set.insert(3).unwrap_err().into_inner(), 3);
+        assert_eq!(set.insert(2), Ok(false));
+    }
+
+    #[test]
+    fn iteration_retain_and_equality() {
+        let mut set: SizedHashSet<String, 8> = SizedHashSet::new();
+        for name in ["a", "bb", "ccc", "dddd"] {
+            set.insert(name.to_string()).unwrap();
+        }
+
+        set.retain(|name| name.len() % 2 == 0);
+        let mut names: Vec<_> = set.iter().map(String::as_str).collect();
+        names.sort_unstable();
+        assert_eq!(names, vec!["bb", "dddd"]);
+        assert!(set.contains("bb"));
+
+        let copy = set.clone();
+        assert_eq!(copy, set);
+        set.clear();
+        assert_ne!(copy, set);
+        assert_eq!(format!("{set:?}"), "{}");
+    }
+}
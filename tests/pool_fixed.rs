@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::pool::FixedBlockPool;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_alloc_and_deref() {
+        let pool: FixedBlockPool<32, 4> = FixedBlockPool::new();
+
+        let mut a = pool.alloc(10u64).unwrap();
+        let b = pool.alloc([1u8, 2, 3]).unwrap();
+
+        *a += 5;
+
+        assert_eq!(*a, 15);
+        assert_eq!(*b, [1, 2, 3]);
+        assert_eq!(pool.available(), 2);
+    }
+
+    #[test]
+    fn test_exhaustion_and_reuse() {
+        let pool: FixedBlockPool<8, 2> = FixedBlockPool::new();
+
+        let a = pool.alloc(1u32).unwrap();
+        let b = pool.alloc(2u32).unwrap();
+
+        assert!(pool.is_full());
+        assert_eq!(pool.alloc(3u32).unwrap_err().into_inner(), 3);
+
+        let freed = a.block_index();
+        drop(a);
+
+        let c = pool.alloc(4u32).unwrap();
+        assert_eq!(c.block_index(), freed);
+        assert_eq!(*b, 2);
+        assert_eq!(*c, 4);
+    }
+
+    #[test]
+    fn test_free_list_is_lifo() {
+        let pool: FixedBlockPool<8, 4> = FixedBlockPool::new();
+
+        let boxes: Vec<_> = (0..4u32).map(|i| pool.alloc(i).unwrap()).collect();
+        let indices: Vec<usize> = boxes.iter().map(|b| b.block_index()).collect();
+        drop(boxes);
+
+        let again: Vec<_> = (0..4u32).map(|i| pool.alloc(i).unwrap()).collect();
+        let reused: Vec<usize> = again.iter().map(|b| b.block_index()).collect();
+
+        let mut expected = indices.clone();
+        expected.reverse();
+        assert_eq!(reused, expected);
+    }
+
+    #[test]
+    fn test_drop_runs_value_destructor() {
+        let tracker = Rc::new(());
+        let pool: FixedBlockPool<16, 2> = FixedBlockPool::new();
+
+        let boxed = pool.alloc(tracker.clone()).unwrap();
+        assert_eq!(Rc::strong_count(&tracker), 2);
+
+        drop(boxed);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+        assert_eq!(pool.available(), 2);
+    }
+
+    #[test]
+    fn test_into_inner_releases_block() {
+        let pool: FixedBlockPool<32, 1> = FixedBlockPool::new();
+
+        let boxed = pool.alloc(String::from("frame")).unwrap();
+        let value = boxed.into_inner();
+
+        assert_eq!(value, "frame");
+        assert_eq!(pool.available(), 1);
+    }
+
+    #[test]
+    fn test_blocks_are_aligned() {
+        let pool: FixedBlockPool<16, 3> = FixedBlockPool::new();
+
+        let a = pool.alloc(1u128).unwrap();
+        let b = pool.alloc(2u128).unwrap();
+
+        assert_eq!((&*a as *const u128 as usize) % 16, 0);
+        assert_eq!((&*b as *const u128 as usize) % 16, 0);
+    }
+}
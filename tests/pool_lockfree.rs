@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::pool::LockFreeBlockPool;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn alloc_until_full_then_reuse() {
+        let pool: LockFreeBlockPool<8, 3> = LockFreeBlockPool::new();
+
+        let a = pool.alloc(1u64).unwrap();
+        let _b = pool.alloc(2u64).unwrap();
+        let _c = pool.alloc(3u64).unwrap();
+
+        assert_eq!(pool.alloc(4u64).unwrap_err().into_inner(), 4);
+        assert_eq!(pool.available(), 0);
+
+        let freed = a.block_index();
+        drop(a);
+
+        let d = pool.alloc(5u64).unwrap();
+        assert_eq!(d.block_index(), freed);
+    }
+
+    #[test]
+    fn static_pool_shared_across_threads() {
+        static POOL: LockFreeBlockPool<16, 64> = LockFreeBlockPool::new();
+
+        let handles: Vec<_> = (0..8u64)
+            .map(|t| {
+                thread::spawn(move || {
+                    for i in 0..1_000u64 {
+                        let value = t * 1_000_000 + i;
+
+                        if let Ok(mut boxed) = POOL.alloc(value) {
+                            assert_eq!(*boxed, value);
+                            *boxed += 1;
+                            assert_eq!(*boxed, value + 1);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(POOL.available(), 64);
+    }
+
+    #[test]
+    fn concurrent_allocations_never_share_a_block() {
+        let pool: Arc<LockFreeBlockPool<8, 32>> = Arc::new(LockFreeBlockPool::new());
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let pool = Arc::clone(&pool);
+                thread::spawn(move || {
+                    for _ in 0..500 {
+                        let held: Vec<_> = (0..8u64).filter_map(|i| pool.alloc(i).ok()).collect();
+                        let indices: HashSet<usize> =
+                            held.iter().map(|b| b.block_index()).collect();
+
+                        assert_eq!(indices.len(), held.len());
+                        for (i, boxed) in held.iter().enumerate() {
+                            assert_eq!(**boxed, i as u64);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(pool.available(), 32);
+    }
+}
@@ -0,0 +1,217 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::prefix::PrefixTable;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use std::rc::Rc;
+
+    /// Deterministic pseudo-random sequence.
+    fn lcg(seed: &mut u64) -> u64 {
+        *seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        *seed >> 33
+    }
+
+    fn mask(len: u8) -> u32 {
+        u32::MAX.checked_shl(32 - len as u32).unwrap_or(0)
+    }
+
+    #[test]
+    fn longest_match_picks_the_most_specific_prefix() {
+        let mut routes: PrefixTable<&str, Ipv4Addr> = PrefixTable::new();
+        assert_eq!(routes.insert(Ipv4Addr::new(10, 0, 0, 0), 8, "lan"), None);
+        assert_eq!(routes.insert(Ipv4Addr::new(10, 1, 0, 0), 16, "lab"), None);
+        assert_eq!(routes.insert(Ipv4Addr::new(10, 1, 2, 0), 24, "rack"), None);
+
+        fn lookup(
+            routes: &PrefixTable<&'static str, Ipv4Addr>,
+            addr: Ipv4Addr,
+        ) -> Option<(Ipv4Addr, u8, &'static str)> {
+            routes.longest_match(addr).map(|(p, l, v)| (p, l, *v))
+        }
+
+        assert_eq!(
+            lookup(&routes, Ipv4Addr::new(10, 1, 2, 3)),
+            Some((Ipv4Addr::new(10, 1, 2, 0), 24, "rack"))
+        );
+        assert_eq!(
+            lookup(&routes, Ipv4Addr::new(10, 1, 3, 3)),
+            Some((Ipv4Addr::new(10, 1, 0, 0), 16, "lab"))
+        );
+        assert_eq!(
+            lookup(&routes, Ipv4Addr::new(10, 200, 0, 1)),
+            Some((Ipv4Addr::new(10, 0, 0, 0), 8, "lan"))
+        );
+        assert_eq!(lookup(&routes, Ipv4Addr::new(192, 168, 0, 1)), None);
+
+        routes.insert(Ipv4Addr::UNSPECIFIED, 0, "default");
+        assert_eq!(
+            lookup(&routes, Ipv4Addr::new(192, 168, 0, 1)),
+            Some((Ipv4Addr::UNSPECIFIED, 0, "default"))
+        );
+    }
+
+    #[test]
+    fn insert_masks_host_bits_and_replaces_values() {
+        let mut table: PrefixTable<u32> = PrefixTable::new();
+
+        assert_eq!(table.insert(0x0a01_0203, 8, 1), None);
+        assert_eq!(table.insert(0x0a00_0000, 8, 2), Some(1));
+        assert_eq!(table.get(0x0aff_ffff, 8), Some(&2));
+        assert_eq!(table.get(0x0a00_0000, 16), None);
+        assert!(table.contains(0x0a00_0000, 8));
+
+        *table.get_mut(0x0a00_0000, 8).unwrap() += 1;
+        assert_eq!(table.get(0x0a00_0000, 8), Some(&3));
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn remove_keeps_more_specific_prefixes() {
+        let mut table: PrefixTable<u32> = PrefixTable::new();
+        table.insert(0x0a00_0000, 8, 8);
+        table.insert(0x0a01_0000, 16, 16);
+        table.insert(0x0a80_0000, 16, 17);
+
+        assert_eq!(table.remove(0x0a00_0000, 8), Some(8));
+        assert_eq!(table.remove(0x0a00_0000, 8), None);
+        assert_eq!(table.remove(0x0a00_0000, 12), None);
+        assert_eq!(
+            table.longest_match(0x0a01_0101),
+            Some((0x0a01_0000, 16, &16))
+        );
+        assert_eq!(table.longest_match(0x0a02_0101), None);
+
+        assert_eq!(table.remove(0x0a01_0000, 16), Some(16));
+        assert_eq!(table.remove(0x0a80_0000, 16), Some(17));
+        assert!(table.is_empty());
+        assert_eq!(table.iter().next(), None);
+    }
+
+    #[test]
+    fn iteration_in_prefix_order() {
+        let table: PrefixTable<u8, u8> = [
+            (0b1000_0000, 1, 1),
+            (0b0000_0000, 0, 0),
+            (0b1100_0000, 2, 3),
+            (0b0100_0000, 2, 2),
+            (0b1000_0000, 2, 4),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            table.iter().collect::<Vec<_>>(),
+            [
+                (0b0000_0000, 0, &0),
+                (0b0100_0000, 2, &2),
+                (0b1000_0000, 1, &1),
+                (0b1000_0000, 2, &4),
+                (0b1100_0000, 2, &3),
+            ]
+        );
+        assert_eq!(table.iter().len(), 5);
+    }
+
+    #[test]
+    fn ipv6_prefixes() {
+        let mut table: PrefixTable<&str, Ipv6Addr> = PrefixTable::new();
+        let doc: Ipv6Addr = "2001:db8::".parse().unwrap();
+        let host: Ipv6Addr = "2001:db8::1".parse().unwrap();
+
+        table.insert(doc, 32, "documentation");
+        table.insert(host, 128, "host");
+
+        assert_eq!(table.longest_match(host), Some((host, 128, &"host")));
+        assert_eq!(
+            table.longest_match("2001:db8::2".parse().unwrap()),
+            Some((doc, 32, &"documentation"))
+        );
+        assert_eq!(
+            format!("{table:?}"),
+            r#"{2001:db8::/32: "documentation", 2001:db8::1/128: "host"}"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "prefix length exceeds the address width")]
+    fn prefix_longer_than_the_address_panics() {
+        let mut table: PrefixTable<(), u8> = PrefixTable::new();
+        table.insert(0, 9, ());
+    }
+
+    #[test]
+    fn clone_clear_and_drop() {
+        let value = Rc::new(());
+
+        {
+            let mut table: PrefixTable<Rc<()>> = PrefixTable::new();
+            for len in 0..=32 {
+                table.insert(u32::MAX, len, Rc::clone(&value));
+            }
+
+            let copy = table.clone();
+            assert_eq!(Rc::strong_count(&value), 67);
+            assert_eq!(copy.len(), 33);
+
+            table.remove(u32::MAX, 16);
+            table.clear();
+            assert!(table.is_empty());
+            assert_eq!(Rc::strong_count(&value), 34);
+        }
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn matches_a_linear_scan_under_random_operations() {
+        for mut seed in [1, 42, 1_234, 99_999] {
+            let mut table: PrefixTable<u32> = PrefixTable::new();
+            let mut model: Vec<(u32, u8, u32)> = Vec::new();
+
+            for step in 0..5_000u32 {
+                // Few distinct high bits so that prefixes nest and share branches.
+                let prefix = (lcg(&mut seed) as u32 & 0xf0f0_0000) | (lcg(&mut seed) as u32 & 0xff);
+                let len = (lcg(&mut seed) % 33) as u8;
+                let bits = prefix & mask(len);
+                let position = model.iter().position(|&(b, l, _)| (b, l) == (bits, len));
+
+                match lcg(&mut seed) % 4 {
+                    0 | 1 => {
+                        let previous = table.insert(prefix, len, step);
+                        match position {
+                            Some(i) => {
+                                assert_eq!(previous, Some(model[i].2));
+                                model[i].2 = step;
+                            }
+                            None => {
+                                assert_eq!(previous, None);
+                                model.push((bits, len, step));
+                            }
+                        }
+                    }
+                    2 => {
+                        let removed = position.map(|i| model.swap_remove(i).2);
+                        assert_eq!(table.remove(prefix, len), removed);
+                    }
+                    _ => {
+                        let best = model
+                            .iter()
+                            .filter(|&&(b, l, _)| prefix & mask(l) == b)
+                            .max_by_key(|&&(_, l, _)| l)
+                            .map(|(b, l, v)| (*b, *l, v));
+                        assert_eq!(table.longest_match(prefix), best);
+                    }
+                }
+
+                assert_eq!(table.len(), model.len());
+            }
+
+            model.sort_unstable_by_key(|&(b, l, _)| (b, l));
+            let entries: Vec<_> = table.iter().map(|(b, l, v)| (b, l, *v)).collect();
+            assert_eq!(entries, model);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::prefix::SizedPrefixTable;
+    use std::net::Ipv4Addr;
+    use std::rc::Rc;
+
+    /// Deterministic pseudo-random sequence.
+    fn lcg(seed: &mut u64) -> u64 {
+        *seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        *seed >> 33
+    }
+
+    fn mask(len: u8) -> u32 {
+        u32::MAX.checked_shl(32 - len as u32).unwrap_or(0)
+    }
+
+    #[test]
+    fn longest_match_picks_the_most_specific_prefix() {
+        let mut routes: SizedPrefixTable<&str, 8, Ipv4Addr> = SizedPrefixTable::new();
+        routes.insert(Ipv4Addr::new(10, 0, 0, 0), 8, "lan").unwrap();
+        routes
+            .insert(Ipv4Addr::new(10, 1, 0, 0), 16, "lab")
+            .unwrap();
+        routes.insert(Ipv4Addr::UNSPECIFIED, 0, "default").unwrap();
+
+        assert_eq!(
+            routes.longest_match(Ipv4Addr::new(10, 1, 9, 9)),
+            Some((Ipv4Addr::new(10, 1, 0, 0), 16, &"lab"))
+        );
+        assert_eq!(
+            routes.longest_match(Ipv4Addr::new(10, 2, 9, 9)),
+            Some((Ipv4Addr::new(10, 0, 0, 0), 8, &"lan"))
+        );
+        assert_eq!(
+            routes.longest_match(Ipv4Addr::new(8, 8, 8, 8)),
+            Some((Ipv4Addr::UNSPECIFIED, 0, &"default"))
+        );
+        assert_eq!(
+            format!("{routes:?}"),
+            r#"{0.0.0.0/0: "default", 10.0.0.0/8: "lan", 10.1.0.0/16: "lab"}"#
+        );
+    }
+
+    #[test]
+    fn diverging_prefixes_take_a_joining_node() {
+        let mut table: SizedPrefixTable<u32, 3> = SizedPrefixTable::new();
+
+        table.insert(0x0a00_0000, 8, 1).unwrap();
+        assert_eq!(table.node_count(), 1);
+
+        table.insert(0x0b00_0000, 8, 2).unwrap();
+        assert_eq!(table.node_count(), 3);
+        assert_eq!(table.insert(0x0c00_0000, 8, 3).unwrap_err().into_inner(), 3);
+
+        // A prefix landing on the joining node needs no new slot.
+        assert_eq!(table.insert(0x0a00_0000, 7, 0), Ok(None));
+        assert_eq!(table.node_count(), 3);
+        assert_eq!(table.insert(0x0a00_0000, 8, 10), Ok(Some(1)));
+        assert_eq!(table.len(), 3);
+
+        assert_eq!(table.remove(0x0a00_0000, 7), Some(0));
+        assert_eq!(table.node_count(), 3);
+        assert_eq!(table.remove(0x0a00_0000, 8), Some(10));
+        assert_eq!(table.node_count(), 1);
+        assert_eq!(table.get(0x0b00_0000, 8), Some(&2));
+        assert_eq!(table.capacity(), 3);
+    }
+
+    #[test]
+    fn iteration_in_prefix_order() {
+        let mut table: SizedPrefixTable<u8, 16, u8> = SizedPrefixTable::new();
+        for (prefix, len, value) in [
+            (0b1000_0000, 1, 1),
+            (0b0000_0000, 0, 0),
+            (0b1100_0000, 2, 3),
+            (0b0100_0000, 2, 2),
+            (0b1000_0000, 2, 4),
+        ] {
+            table.insert(prefix, len, value).unwrap();
+        }
+
+        assert_eq!(
+            table.iter().collect::<Vec<_>>(),
+            [
+                (0b0000_0000, 0, &0),
+                (0b0100_0000, 2, &2),
+                (0b1000_0000, 1, &1),
+                (0b1000_0000, 2, &4),
+                (0b1100_0000, 2, &3),
+            ]
+        );
+
+        *table.get_mut(0b1100_0000, 2).unwrap() = 30;
+        assert!(table.contains(0b1100_0000, 2));
+        assert_eq!((&table).into_iter().last(), Some((0b1100_0000, 2, &30)));
+    }
+
+    #[test]
+    fn clone_clear_and_drop() {
+        let value = Rc::new(());
+
+        {
+            let mut table: SizedPrefixTable<Rc<()>, 16> = SizedPrefixTable::new();
+            for len in [0, 8, 16, 24, 32] {
+                table.insert(0x0a0b_0c0d, len, Rc::clone(&value)).unwrap();
+            }
+            table.insert(0xc000_0000, 8, Rc::clone(&value)).unwrap();
+
+            let copy = table.clone();
+            assert_eq!(Rc::strong_count(&value), 13);
+            assert_eq!(copy.iter().count(), 6);
+            assert_eq!(copy.node_count(), table.node_count());
+
+            table.remove(0x0a0b_0c0d, 16);
+            table.clear();
+            assert!(table.is_empty());
+            assert_eq!(table.node_count(), 0);
+            assert_eq!(Rc::strong_count(&value), 7);
+        }
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn matches_a_linear_scan_under_random_operations() {
+        for mut seed in [1, 42, 1_234, 99_999] {
+            let mut table: SizedPrefixTable<u32, 63> = SizedPrefixTable::new();
+            let mut model: Vec<(u32, u8, u32)> = Vec::new();
+
+            for step in 0..5_000u32 {
+                let prefix = (lcg(&mut seed) as u32 & 0xf0f0_0000) | (lcg(&mut seed) as u32 & 0xff);
+                let len = (lcg(&mut seed) % 33) as u8;
+                let bits = prefix & mask(len);
+                let position = model.iter().position(|&(b, l, _)| (b, l) == (bits, len));
+
+                match lcg(&mut seed) % 4 {
+                    0 | 1 => match (table.insert(prefix, len, step), position) {
+                        (Ok(previous), Some(i)) => {
+                            assert_eq!(previous, Some(model[i].2));
+                            model[i].2 = step;
+                        }
+                        (Ok(previous), None) => {
+                            assert_eq!(previous, None);
+                            model.push((bits, len, step));
+                        }
+                        (Err(error), None) => {
+                            assert_eq!(error.into_inner(), step);
+                            assert!(table.node_count() >= 62);
+                        }
+                        (Err(_), Some(_)) => panic!("replacing a value needs no slot"),
+                    },
+                    2 => {
+                        let removed = position.map(|i| model.swap_remove(i).2);
+                        assert_eq!(table.remove(prefix, len), removed);
+                    }
+                    _ => {
+                        let best = model
+                            .iter()
+                            .filter(|&&(b, l, _)| prefix & mask(l) == b)
+                            .max_by_key(|&&(_, l, _)| l)
+                            .map(|(b, l, v)| (*b, *l, v));
+                        assert_eq!(table.longest_match(prefix), best);
+                    }
+                }
+
+                assert_eq!(table.len(), model.len());
+                assert!(table.node_count() < 2 * model.len().max(1));
+            }
+
+            model.sort_unstable_by_key(|&(b, l, _)| (b, l));
+            let entries: Vec<_> = table.iter().map(|(b, l, v)| (b, l, *v)).collect();
+            assert_eq!(entries, model);
+        }
+    }
+}
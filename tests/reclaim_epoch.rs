@@ -0,0 +1,104 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::reclaim::epoch::{COLLECT_EVERY, Collector, SLOTS};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Tracked(Arc<AtomicUsize>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn tracked(drops: &Arc<AtomicUsize>) -> *mut Tracked {
+        Box::into_raw(Box::new(Tracked(Arc::clone(drops))))
+    }
+
+    #[test]
+    fn deferred_value_outlives_pinned_guard() {
+        let collector = Collector::new();
+        let drops = Arc::new(AtomicUsize::new(0));
+
+        let reader = collector.pin();
+        {
+            let guard = collector.pin();
+            unsafe { guard.defer_drop(tracked(&drops)) };
+        }
+
+        for _ in 0..4 {
+            collector.collect();
+        }
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+        drop(reader);
+        for _ in 0..4 {
+            collector.collect();
+        }
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn epoch_advances_only_when_every_guard_caught_up() {
+        let collector = Collector::new();
+        let start = collector.epoch();
+
+        let guard = collector.pin();
+        collector.collect();
+        assert_eq!(collector.epoch(), start + 1);
+
+        // The guard still holds the previous epoch.
+        collector.collect();
+        assert_eq!(collector.epoch(), start + 1);
+
+        drop(guard);
+        collector.collect();
+        assert_eq!(collector.epoch(), start + 2);
+    }
+
+    #[test]
+    fn deferrals_trigger_collections() {
+        let collector = Collector::new();
+        let drops = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..COLLECT_EVERY * 4 {
+            let guard = collector.pin();
+            unsafe { guard.defer_drop(tracked(&drops)) };
+        }
+
+        assert!(drops.load(Ordering::SeqCst) > 0);
+    }
+
+    #[test]
+    fn dropping_the_collector_drops_pending_values() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let collector = Collector::new();
+
+        {
+            let guard = collector.pin();
+            for _ in 0..3 {
+                unsafe { guard.defer_drop(tracked(&drops)) };
+            }
+        }
+
+        drop(collector);
+        assert_eq!(drops.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn guards_release_their_slot() {
+        static COLLECTOR: Collector = Collector::new();
+
+        let guards: Vec<_> = (0..SLOTS).map(|_| COLLECTOR.pin()).collect();
+        assert_eq!(COLLECTOR.pinned_count(), SLOTS);
+
+        drop(guards);
+        assert_eq!(COLLECTOR.pinned_count(), 0);
+
+        let _guard = COLLECTOR.pin();
+        assert_eq!(COLLECTOR.pinned_count(), 1);
+    }
+}
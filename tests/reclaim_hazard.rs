@@ -0,0 +1,115 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::reclaim::hazard::{COLLECT_EVERY, HAZARDS, HazardDomain, RECORDS};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+    struct Tracked(Arc<AtomicUsize>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn tracked(drops: &Arc<AtomicUsize>) -> *mut Tracked {
+        Box::into_raw(Box::new(Tracked(Arc::clone(drops))))
+    }
+
+    #[test]
+    fn protected_value_is_kept_until_cleared() {
+        let domain = HazardDomain::new();
+        let drops = Arc::new(AtomicUsize::new(0));
+        let shared = AtomicPtr::new(tracked(&drops));
+
+        let reader = domain.pin();
+        let protected = reader.protect(0, &shared);
+
+        {
+            let writer = domain.pin();
+            let old = shared.swap(std::ptr::null_mut(), Ordering::AcqRel);
+            assert_eq!(old, protected);
+            unsafe { writer.defer_drop(old) };
+        }
+
+        domain.collect();
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+        reader.clear(0);
+        domain.collect();
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn tagged_hazard_protects_the_value() {
+        let domain = HazardDomain::new();
+        let drops = Arc::new(AtomicUsize::new(0));
+        let value = tracked(&drops);
+        let shared = AtomicPtr::new(value.map_addr(|addr| addr | 1));
+
+        let reader = domain.pin();
+        reader.protect(1, &shared);
+
+        let guard = domain.pin();
+        unsafe { guard.defer_drop(value) };
+        guard.flush();
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+        drop(reader);
+        guard.flush();
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn unprotected_values_are_dropped_by_deferrals() {
+        let domain = HazardDomain::new();
+        let drops = Arc::new(AtomicUsize::new(0));
+
+        let guard = domain.pin();
+        for _ in 0..COLLECT_EVERY {
+            unsafe { guard.defer_drop(tracked(&drops)) };
+        }
+
+        assert_eq!(drops.load(Ordering::SeqCst), COLLECT_EVERY);
+    }
+
+    #[test]
+    fn dropping_the_domain_drops_pending_values() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let domain = HazardDomain::new();
+        let shared = AtomicPtr::new(tracked(&drops));
+
+        {
+            let guard = domain.pin();
+            let value = guard.protect(HAZARDS - 1, &shared);
+            unsafe { guard.defer_drop(value) };
+            guard.flush();
+        }
+
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+        drop(domain);
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn protect_rejects_out_of_range_slots() {
+        let domain = HazardDomain::new();
+        let shared: AtomicPtr<u32> = AtomicPtr::new(std::ptr::null_mut());
+
+        domain.pin().protect(HAZARDS, &shared);
+    }
+
+    #[test]
+    fn guards_release_their_record() {
+        static DOMAIN: HazardDomain = HazardDomain::new();
+
+        let guards: Vec<_> = (0..RECORDS).map(|_| DOMAIN.pin()).collect();
+        assert_eq!(DOMAIN.pinned_count(), RECORDS);
+
+        drop(guards);
+        assert_eq!(DOMAIN.pinned_count(), 0);
+    }
+}
@@ -0,0 +1,200 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::reclaim::{Collector, HazardDomain, Reclaim, ReclaimGuard};
+    use std::ptr;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+    use std::thread;
+
+    const THREADS: usize = 4;
+    const ROUNDS: usize = 5_000;
+
+    /// Pair whose halves always sum to zero, checked by readers to detect use after free.
+    struct Pair {
+        left: i64,
+        right: i64,
+        drops: Arc<AtomicUsize>,
+    }
+
+    impl Pair {
+        fn boxed(value: i64, drops: &Arc<AtomicUsize>) -> *mut Pair {
+            Box::into_raw(Box::new(Pair {
+                left: value,
+                right: -value,
+                drops: Arc::clone(drops),
+            }))
+        }
+    }
+
+    impl Drop for Pair {
+        fn drop(&mut self) {
+            // Poison the pair so a late reader fails its check.
+            self.left = 1;
+            self.right = 1;
+            self.drops.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Writers keep replacing a shared pair while readers dereference it.
+    fn swap_under_readers<R: Reclaim + Send + 'static>(domain: Arc<R>) {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let shared = Arc::new(AtomicPtr::new(Pair::boxed(0, &drops)));
+
+        let writers = (0..THREADS).map(|t| {
+            let (domain, shared, drops) = (domain.clone(), shared.clone(), drops.clone());
+            thread::spawn(move || {
+                for i in 0..ROUNDS {
+                    let guard = domain.pin();
+                    let fresh = Pair::boxed((t * ROUNDS + i) as i64, &drops);
+                    let old = shared.swap(fresh, Ordering::AcqRel);
+                    unsafe { guard.defer_drop(old) };
+                }
+            })
+        });
+
+        let readers = (0..THREADS).map(|_| {
+            let (domain, shared) = (domain.clone(), shared.clone());
+            thread::spawn(move || {
+                for _ in 0..ROUNDS {
+                    let guard = domain.pin();
+                    let pair = unsafe { &*guard.protect(0, &shared) };
+                    assert_eq!(pair.left + pair.right, 0);
+                }
+            })
+        });
+
+        let handles: Vec<_> = writers.chain(readers).collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let last = shared.swap(ptr::null_mut(), Ordering::AcqRel);
+        drop(unsafe { Box::from_raw(last) });
+        domain.collect();
+
+        // Dropping the domain drops the values still pending.
+        drop(Arc::into_inner(domain).unwrap());
+        assert_eq!(drops.load(Ordering::SeqCst), THREADS * ROUNDS + 1);
+    }
+
+    struct Node {
+        value: usize,
+        next: *mut Node,
+    }
+
+    unsafe impl Send for Node {}
+
+    /// Treiber stack written against the `Reclaim` trait.
+    struct Stack<R> {
+        head: AtomicPtr<Node>,
+        domain: R,
+    }
+
+    impl<R: Reclaim> Stack<R> {
+        fn push(&self, value: usize) {
+            let node = Box::into_raw(Box::new(Node {
+                value,
+                next: ptr::null_mut(),
+            }));
+            let mut head = self.head.load(Ordering::Relaxed);
+
+            loop {
+                unsafe { (*node).next = head };
+                match self.head.compare_exchange_weak(
+                    head,
+                    node,
+                    Ordering::Release,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return,
+                    Err(current) => head = current,
+                }
+            }
+        }
+
+        fn pop(&self) -> Option<usize> {
+            let guard = self.domain.pin();
+
+            loop {
+                let head = guard.protect(0, &self.head);
+                if head.is_null() {
+                    return None;
+                }
+
+                let next = unsafe { (*head).next };
+                if self
+                    .head
+                    .compare_exchange(head, next, Ordering::AcqRel, Ordering::Relaxed)
+                    .is_ok()
+                {
+                    let value = unsafe { (*head).value };
+                    unsafe { guard.defer_drop(head) };
+                    return Some(value);
+                }
+            }
+        }
+    }
+
+    /// Threads push and pop concurrently; every value comes out exactly once.
+    fn stack_round_trip<R: Reclaim + Send + 'static>(domain: R) {
+        let stack = Arc::new(Stack {
+            head: AtomicPtr::new(ptr::null_mut()),
+            domain,
+        });
+        let popped = Arc::new(
+            (0..THREADS * ROUNDS)
+                .map(|_| AtomicUsize::new(0))
+                .collect::<Vec<_>>(),
+        );
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let (stack, popped) = (stack.clone(), popped.clone());
+                thread::spawn(move || {
+                    for i in 0..ROUNDS {
+                        stack.push(t * ROUNDS + i);
+                        if let Some(value) = stack.pop() {
+                            popped[value].fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        while let Some(value) = stack.pop() {
+            popped[value].fetch_add(1, Ordering::Relaxed);
+        }
+
+        assert!(
+            popped
+                .iter()
+                .all(|count| count.load(Ordering::Relaxed) == 1)
+        );
+    }
+
+    #[test]
+    fn epoch_swap_under_readers() {
+        swap_under_readers(Arc::new(Collector::new()));
+    }
+
+    #[test]
+    fn hazard_swap_under_readers() {
+        swap_under_readers(Arc::new(HazardDomain::new()));
+    }
+
+    #[test]
+    fn epoch_stack_round_trip() {
+        stack_round_trip(Collector::new());
+    }
+
+    #[test]
+    fn hazard_stack_round_trip() {
+        stack_round_trip(HazardDomain::new());
+    }
+}
@@ -0,0 +1,383 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::DoubleLinkedList::SizedDoubleLinkedList;
+    use datastructures::buffer::{OverflowPolicy, SizedCircularBuffer};
+    use datastructures::hash::{SizedHashMap, SizedHashSet};
+    use datastructures::prefix::SizedPrefixTable;
+    use datastructures::slab::SizedSlab;
+    use datastructures::sorted_map::SizedSortedMap;
+    use datastructures::workstealing::SizedWorkStealingPool;
+
+    #[test]
+    fn test_sized_list_round_trip() {
+        let mut list: SizedDoubleLinkedList<i32, 5> = Default::default();
+        for value in [0, 1, 2, 3] {
+            assert!(list.insert_tail(value).is_ok());
+        }
+        assert!(list.remove(0).is_ok());
+        assert!(list.insert_head(9).is_ok());
+
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[9,1,2,3]");
+
+        let decoded: SizedDoubleLinkedList<i32, 5> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, list);
+    }
+
+    #[test]
+    fn test_sized_list_rejects_too_many_items() {
+        let result = serde_json::from_str::<SizedDoubleLinkedList<i32, 3>>("[1,2,3,4]");
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("at most 3 elements"), "{error}");
+
+        let exact: SizedDoubleLinkedList<i32, 3> = serde_json::from_str("[1,2,3]").unwrap();
+        assert!(exact.is_full());
+    }
+
+    #[test]
+    fn test_sized_list_of_structs() {
+        let list = SizedDoubleLinkedList::<(String, u8), 2>::from([("a".to_string(), 1)]);
+
+        let json = serde_json::to_string(&list).unwrap();
+        let decoded: SizedDoubleLinkedList<(String, u8), 2> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, r#"[["a",1]]"#);
+        assert_eq!(decoded, list);
+    }
+
+    #[test]
+    fn test_circular_buffer_round_trip() {
+        let mut buffer: SizedCircularBuffer<u32, 3> =
+            SizedCircularBuffer::new(OverflowPolicy::Overwrite);
+        for value in 1..=5 {
+            assert!(buffer.push_back(value).is_ok());
+        }
+
+        let json = serde_json::to_string(&buffer).unwrap();
+        assert_eq!(json, "[3,4,5]");
+
+        let decoded: SizedCircularBuffer<u32, 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(decoded.policy(), OverflowPolicy::Reject);
+
+        assert!(serde_json::from_str::<SizedCircularBuffer<u32, 2>>(&json).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dynamic_list_round_trip() {
+        use datastructures::DoubleLinkedList::DoubleLinkedList;
+
+        let list: DoubleLinkedList<String> =
+            ["x", "y", "z"].iter().map(|s| s.to_string()).collect();
+
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, r#"["x","y","z"]"#);
+
+        let decoded: DoubleLinkedList<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, list);
+
+        let empty: DoubleLinkedList<String> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_chunked_buffer_round_trip() {
+        use datastructures::buffer::ChunkedBuffer;
+
+        let mut buffer: ChunkedBuffer<4> = ChunkedBuffer::new();
+        buffer.append(b"..hello world");
+        buffer.consume(2);
+
+        let json = serde_json::to_string(&buffer).unwrap();
+        let decoded: ChunkedBuffer<4> = serde_json::from_str(&json).unwrap();
+
+        let mut bytes = [0u8; 11];
+        assert_eq!(decoded.read_at(0, &mut bytes), 11);
+        assert_eq!(&bytes, b"hello world");
+        assert_eq!(decoded.chunk_count(), 3);
+    }
+
+    #[test]
+    fn test_sized_slab_round_trip_keeps_keys() {
+        let mut slab: SizedSlab<String, 8> = SizedSlab::new();
+        let a = slab.insert("a".to_string()).unwrap();
+        let b = slab.insert("b".to_string()).unwrap();
+        let c = slab.insert("c".to_string()).unwrap();
+        assert_eq!(slab.remove(b), Some("b".to_string()));
+
+        let json = serde_json::to_string(&slab).unwrap();
+        assert_eq!(json, r#"[[0,"a"],[1,null],[0,"c"]]"#);
+
+        let mut decoded: SizedSlab<String, 8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded.get(a).map(String::as_str), Some("a"));
+        assert_eq!(decoded.get(c).map(String::as_str), Some("c"));
+        assert!(!decoded.contains(b));
+
+        // The vacant slot is reused with its bumped generation.
+        let d = decoded.insert("d".to_string()).unwrap();
+        assert_eq!((d.index(), d.generation()), (1, 1));
+        assert!(!decoded.contains(b));
+
+        let empty: SizedSlab<String, 8> = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_sized_slab_rejects_too_many_slots() {
+        let error = serde_json::from_str::<SizedSlab<u8, 2>>("[[0,1],[0,null],[0,3]]")
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("at most 2 slots"), "{error}");
+    }
+
+    #[test]
+    fn test_work_stealing_pool_round_trip() {
+        let mut pool: SizedWorkStealingPool<u32, 4> = SizedWorkStealingPool::new();
+        for value in [1, 2, 3] {
+            assert!(pool.insert(value).is_ok());
+        }
+        assert_eq!(pool.take(), Some(1));
+
+        let json = serde_json::to_string(&pool).unwrap();
+        assert_eq!(json, "[2,3]");
+
+        let mut decoded: SizedWorkStealingPool<u32, 4> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.steal(), Some(3));
+        assert_eq!(decoded.take(), Some(2));
+        assert_eq!(decoded.take(), None);
+
+        let error = serde_json::from_str::<SizedWorkStealingPool<u32, 2>>("[1,2,3]")
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("at most 2 elements"), "{error}");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dynamic_slab_round_trip_keeps_keys() {
+        use datastructures::slab::Slab;
+
+        let mut slab: Slab<u32> = Slab::new();
+        let keys: Vec<_> = (0..4).map(|value| slab.insert(value * 10)).collect();
+        assert_eq!(slab.remove(keys[3]), Some(30));
+        assert_eq!(slab.remove(keys[1]), Some(10));
+
+        let json = serde_json::to_string(&slab).unwrap();
+        assert_eq!(json, "[[0,0],[1,null],[0,20],[1,null]]");
+
+        let mut decoded: Slab<u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded.vacant(), 2);
+        assert_eq!(decoded.get(keys[0]), Some(&0));
+        assert_eq!(decoded.get(keys[2]), Some(&20));
+        assert!(!decoded.contains(keys[1]));
+
+        // Vacant slots are reused lowest first, then the slab grows.
+        let reused = [decoded.insert(1), decoded.insert(3), decoded.insert(5)];
+        assert_eq!(
+            reused.map(|key| (key.index(), key.generation())),
+            [(1, 1), (3, 1), (4, 0)]
+        );
+        assert!(!decoded.contains(keys[3]));
+    }
+
+    #[test]
+    fn test_sized_hash_map_round_trip() {
+        let mut map: SizedHashMap<String, u16, 4> = SizedHashMap::new();
+        map.insert("http".to_string(), 80).unwrap();
+        map.insert("https".to_string(), 443).unwrap();
+
+        let json = serde_json::to_string(&map).unwrap();
+        let decoded: SizedHashMap<String, u16, 4> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, map);
+
+        let duplicated: SizedHashMap<String, u16, 1> =
+            serde_json::from_str(r#"{"a":1,"a":2}"#).unwrap();
+        assert_eq!(duplicated.get("a"), Some(&2));
+
+        let error = serde_json::from_str::<SizedHashMap<String, u16, 2>>(r#"{"a":1,"b":2,"c":3}"#)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("at most 2 entries"), "{error}");
+    }
+
+    #[test]
+    fn test_sized_hash_set_round_trip() {
+        let mut set: SizedHashSet<u32, 8> = SizedHashSet::new();
+        for value in [3, 1, 4, 1, 5] {
+            set.insert(value).unwrap();
+        }
+
+        let json = serde_json::to_string(&set).unwrap();
+        let decoded: SizedHashSet<u32, 8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, set);
+        assert_eq!(decoded.len(), 4);
+
+        let merged: SizedHashSet<u32, 2> = serde_json::from_str("[7,7,8]").unwrap();
+        assert_eq!(merged.len(), 2);
+
+        let error = serde_json::from_str::<SizedHashSet<u32, 2>>("[1,2,3]")
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("at most 2 distinct elements"), "{error}");
+    }
+
+    #[test]
+    fn test_sized_sorted_map_round_trip() {
+        let mut map: SizedSortedMap<u32, &str, 4> = SizedSortedMap::new();
+        for (key, value) in [(30, "c"), (10, "a"), (20, "b")] {
+            map.insert(key, value).unwrap();
+        }
+
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"10":"a","20":"b","30":"c"}"#);
+
+        let decoded: SizedSortedMap<u32, &str, 4> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, map);
+
+        let unordered: SizedSortedMap<u32, &str, 2> =
+            serde_json::from_str(r#"{"2":"x","1":"y","2":"z"}"#).unwrap();
+        assert_eq!(
+            unordered.iter().collect::<Vec<_>>(),
+            [(&1, &"y"), (&2, &"z")]
+        );
+
+        let error = serde_json::from_str::<SizedSortedMap<u32, &str, 2>>(&json)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("at most 2 entries"), "{error}");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_linked_hash_map_round_trip_keeps_order() {
+        use datastructures::hash::{LinkedHashMap, Order};
+
+        let mut config = LinkedHashMap::with_order(Order::Access);
+        for (key, value) in [("name", 1), ("version", 2), ("edition", 3), ("authors", 4)] {
+            config.insert(key.to_string(), value);
+        }
+        config.get_mut("name");
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"version":2,"edition":3,"authors":4,"name":1}"#);
+
+        let decoded: LinkedHashMap<String, u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            decoded.iter().collect::<Vec<_>>(),
+            config.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(decoded.order(), Order::Insertion);
+
+        // A repeated key keeps its first position and its last value.
+        let repeated: LinkedHashMap<String, u32> =
+            serde_json::from_str(r#"{"b":1,"a":2,"b":3}"#).unwrap();
+        assert_eq!(
+            repeated
+                .iter()
+                .map(|(k, v)| (k.as_str(), *v))
+                .collect::<Vec<_>>(),
+            vec![("b", 3), ("a", 2)]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sorted_map_round_trip() {
+        use datastructures::sorted_map::SortedMap;
+
+        let map: SortedMap<u32, u32> = (0..100).rev().map(|key| (key, key * key)).collect();
+
+        let json = serde_json::to_string(&map).unwrap();
+        assert!(json.starts_with(r#"{"0":0,"1":1,"2":4,"#), "{json}");
+
+        let decoded: SortedMap<u32, u32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, map);
+        assert_eq!(decoded.len(), 100);
+
+        let empty: SortedMap<u32, u32> = serde_json::from_str("{}").unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_sized_prefix_table_round_trip() {
+        use std::net::Ipv4Addr;
+
+        let mut routes: SizedPrefixTable<&str, 8, Ipv4Addr> = SizedPrefixTable::new();
+        routes
+            .insert(Ipv4Addr::new(10, 1, 0, 0), 16, "lab")
+            .unwrap();
+        routes.insert(Ipv4Addr::new(10, 0, 0, 0), 8, "lan").unwrap();
+
+        let json = serde_json::to_string(&routes).unwrap();
+        assert_eq!(json, r#"[["10.0.0.0",8,"lan"],["10.1.0.0",16,"lab"]]"#);
+
+        let decoded: SizedPrefixTable<&str, 8, Ipv4Addr> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            decoded.iter().collect::<Vec<_>>(),
+            routes.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            decoded.longest_match(Ipv4Addr::new(10, 1, 2, 3)),
+            Some((Ipv4Addr::new(10, 1, 0, 0), 16, &"lab"))
+        );
+
+        // Two diverging prefixes need a third node joining them.
+        let error =
+            serde_json::from_str::<SizedPrefixTable<u8, 2>>("[[167772160,8,1],[184549376,8,2]]")
+                .err()
+                .unwrap()
+                .to_string();
+        assert!(error.contains("capacity of 2 nodes"), "{error}");
+
+        let error = serde_json::from_str::<SizedPrefixTable<u8, 2>>("[[0,33,1]]")
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("within the address width"), "{error}");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_prefix_table_round_trip() {
+        use datastructures::prefix::PrefixTable;
+        use std::net::Ipv6Addr;
+
+        let table: PrefixTable<u32, Ipv6Addr> = [
+            ("2001:db8::".parse().unwrap(), 32, 1),
+            ("2001:db8::1".parse().unwrap(), 128, 2),
+            (Ipv6Addr::UNSPECIFIED, 0, 0),
+        ]
+        .into_iter()
+        .collect();
+
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(
+            json,
+            r#"[["::",0,0],["2001:db8::",32,1],["2001:db8::1",128,2]]"#
+        );
+
+        let decoded: PrefixTable<u32, Ipv6Addr> = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            decoded.iter().collect::<Vec<_>>(),
+            table.iter().collect::<Vec<_>>()
+        );
+
+        let error = serde_json::from_str::<PrefixTable<u32, Ipv6Addr>>(r#"[["::",129,0]]"#)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("within the address width"), "{error}");
+    }
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::slab::Slab;
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut slab: Slab<&str> = Slab::new();
+
+        let a = slab.insert("a");
+        let b = slab.insert("b");
+
+        assert_eq!(slab.len(), 2);
+        assert_eq!(slab.get(a), Some(&"a"));
+        assert_eq!(slab.get(b), Some(&"b"));
+    }
+
+    #[test]
+    fn test_grows_past_initial_capacity() {
+        let mut slab: Slab<usize> = Slab::with_capacity(2);
+
+        let keys: Vec<_> = (0..100).map(|i| slab.insert(i)).collect();
+
+        assert_eq!(slab.len(), 100);
+        for (i, key) in keys.into_iter().enumerate() {
+            assert_eq!(slab.get(key), Some(&i));
+        }
+    }
+
+    #[test]
+    fn test_remove_and_stale_key() {
+        let mut slab: Slab<i32> = Slab::new();
+
+        let old = slab.insert(1);
+        assert_eq!(slab.remove(old), Some(1));
+        assert_eq!(slab.remove(old), None);
+
+        let new = slab.insert(2);
+        assert_eq!(old.index(), new.index());
+        assert_eq!(slab.get(old), None);
+        assert_eq!(slab.get_mut(old), None);
+        assert_eq!(slab.get(new), Some(&2));
+    }
+
+    #[test]
+    fn test_vacancy_count() {
+        let mut slab: Slab<i32> = Slab::new();
+
+        let a = slab.insert(1);
+        let b = slab.insert(2);
+        slab.insert(3);
+        assert_eq!(slab.vacant(), 0);
+
+        slab.remove(a);
+        slab.remove(b);
+        assert_eq!(slab.vacant(), 2);
+
+        slab.insert(4);
+        assert_eq!(slab.vacant(), 1);
+    }
+
+    #[test]
+    fn test_iter_and_clear() {
+        let mut slab: Slab<i32> = Slab::new();
+
+        let a = slab.insert(1);
+        let b = slab.insert(2);
+        let c = slab.insert(3);
+        slab.remove(b);
+
+        let items: Vec<_> = slab.iter().collect();
+        assert_eq!(items, vec![(a, &1), (c, &3)]);
+
+        for (_, value) in slab.iter_mut() {
+            *value += 1;
+        }
+        assert_eq!(slab.get(c), Some(&4));
+
+        slab.clear();
+        assert!(slab.is_empty());
+        assert!(!slab.contains(a));
+        assert_eq!(slab.vacant(), 3);
+    }
+}
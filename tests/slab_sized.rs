@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::CapacityError;
+    use datastructures::slab::SizedSlab;
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut slab: SizedSlab<i32, 4> = SizedSlab::new();
+
+        let a = slab.insert(10).unwrap();
+        let b = slab.insert(20).unwrap();
+
+        assert_eq!(slab.len(), 2);
+        assert_eq!(slab.get(a), Some(&10));
+        assert_eq!(slab.get(b), Some(&20));
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut slab: SizedSlab<i32, 4> = SizedSlab::new();
+
+        let key = slab.insert(1).unwrap();
+        *slab.get_mut(key).unwrap() += 41;
+
+        assert_eq!(slab.get(key), Some(&42));
+    }
+
+    #[test]
+    fn test_remove_invalidates_key() {
+        let mut slab: SizedSlab<i32, 4> = SizedSlab::new();
+
+        let key = slab.insert(5).unwrap();
+
+        assert!(slab.contains(key));
+        assert_eq!(slab.remove(key), Some(5));
+        assert!(!slab.contains(key));
+        assert_eq!(slab.get(key), None);
+        assert_eq!(slab.remove(key), None);
+    }
+
+    #[test]
+    fn test_stale_key_after_slot_reuse() {
+        let mut slab: SizedSlab<i32, 4> = SizedSlab::new();
+
+        let old = slab.insert(1).unwrap();
+        slab.remove(old);
+        let new = slab.insert(2).unwrap();
+
+        assert_eq!(old.index(), new.index());
+        assert_ne!(old.generation(), new.generation());
+        assert_eq!(slab.get(old), None);
+        assert_eq!(slab.get(new), Some(&2));
+    }
+
+    #[test]
+    fn test_full_and_vacancy() {
+        let mut slab: SizedSlab<u8, 2> = SizedSlab::new();
+
+        assert_eq!(slab.vacant(), 2);
+        let key = slab.insert(1).unwrap();
+        slab.insert(2).unwrap();
+
+        assert!(slab.is_full());
+        assert_eq!(slab.vacant(), 0);
+        assert_eq!(slab.insert(3), Err(CapacityError::new(3)));
+
+        slab.remove(key);
+        assert_eq!(slab.vacant(), 1);
+        assert!(slab.insert(3).is_ok());
+    }
+
+    #[test]
+    fn test_iter_in_slot_order() {
+        let mut slab: SizedSlab<i32, 8> = SizedSlab::new();
+
+        let a = slab.insert(1).unwrap();
+        let b = slab.insert(2).unwrap();
+        let c = slab.insert(3).unwrap();
+        slab.remove(b);
+
+        let items: Vec<_> = slab.iter().collect();
+        assert_eq!(items, vec![(a, &1), (c, &3)]);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut slab: SizedSlab<i32, 8> = SizedSlab::new();
+
+        slab.insert(1).unwrap();
+        slab.insert(2).unwrap();
+
+        for (_, value) in slab.iter_mut() {
+            *value *= 10;
+        }
+
+        let values: Vec<_> = slab.iter().map(|(_, v)| *v).collect();
+        assert_eq!(values, vec![10, 20]);
+    }
+
+    #[test]
+    fn test_clear_drops_values() {
+        use std::rc::Rc;
+
+        let tracker = Rc::new(());
+        let mut slab: SizedSlab<Rc<()>, 4> = SizedSlab::new();
+
+        let key = slab.insert(tracker.clone()).unwrap();
+        slab.insert(tracker.clone()).unwrap();
+        assert_eq!(Rc::strong_count(&tracker), 3);
+
+        slab.clear();
+        assert_eq!(Rc::strong_count(&tracker), 1);
+        assert!(slab.is_empty());
+        assert!(!slab.contains(key));
+
+        slab.insert(tracker.clone()).unwrap();
+        drop(slab);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+}
@@ -0,0 +1,320 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::reclaim::HazardDomain;
+    use datastructures::sorted_list::LockFreeSortedList;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    /// Value counting its creations and drops, ordered by key only.
+    #[derive(Debug)]
+    struct Tracked(u32, Arc<Counters>);
+
+    #[derive(Debug, Default)]
+    struct Counters {
+        created: AtomicUsize,
+        dropped: AtomicUsize,
+    }
+
+    impl Tracked {
+        fn new(key: u32, counters: &Arc<Counters>) -> Self {
+            counters.created.fetch_add(1, Ordering::SeqCst);
+            Self(key, Arc::clone(counters))
+        }
+    }
+
+    impl Clone for Tracked {
+        fn clone(&self) -> Self {
+            Self::new(self.0, &self.1)
+        }
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.1.dropped.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl PartialEq for Tracked {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Tracked {}
+
+    impl PartialOrd for Tracked {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tracked {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    impl std::borrow::Borrow<u32> for Tracked {
+        fn borrow(&self) -> &u32 {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn insert_keeps_values_sorted_and_unique() {
+        let list = LockFreeSortedList::new();
+
+        assert!(list.is_empty());
+        for value in [5, 1, 4, 2, 3] {
+            assert!(list.insert(value));
+        }
+        assert!(!list.insert(3));
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.snapshot(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn remove_and_contains() {
+        let list = LockFreeSortedList::new();
+        for value in 0..10 {
+            list.insert(value);
+        }
+
+        assert!(list.remove(&0));
+        assert!(list.remove(&5));
+        assert!(list.remove(&9));
+        assert!(!list.remove(&5));
+        assert!(!list.remove(&42));
+
+        assert!(!list.contains(&5));
+        assert!(list.contains(&6));
+        assert_eq!(list.len(), 7);
+        assert_eq!(list.snapshot(), vec![1, 2, 3, 4, 6, 7, 8]);
+
+        assert!(list.insert(5));
+        assert!(list.contains(&5));
+    }
+
+    #[test]
+    fn lookups_through_borrowed_keys() {
+        let list = LockFreeSortedList::new();
+        list.insert(String::from("orders"));
+        list.insert(String::from("alerts"));
+
+        assert!(list.contains("orders"));
+        assert!(!list.contains("billing"));
+
+        let guard = list.pin();
+        assert_eq!(
+            list.get("alerts", &guard).map(String::as_str),
+            Some("alerts")
+        );
+
+        assert!(list.remove("alerts"));
+        assert_eq!(format!("{list:?}"), "{\"orders\"}");
+    }
+
+    #[test]
+    fn iteration_borrows_a_guard() {
+        let list = LockFreeSortedList::new();
+        for value in [3, 1, 2] {
+            list.insert(value);
+        }
+
+        let guard = list.pin();
+        let mut iter = list.iter(&guard);
+        assert_eq!(iter.next(), Some(&1));
+
+        list.remove(&2);
+        list.insert(4);
+
+        // Elements removed ahead of the iterator are skipped.
+        assert_eq!(iter.collect::<Vec<_>>(), vec![&3, &4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "guard pins the collector of another list")]
+    fn guard_of_another_list_is_rejected() {
+        let first: LockFreeSortedList<u32> = LockFreeSortedList::new();
+        let second: LockFreeSortedList<u32> = LockFreeSortedList::new();
+
+        let guard = first.pin();
+        second.iter(&guard);
+    }
+
+    #[test]
+    fn every_value_is_dropped_once() {
+        let counters = Arc::new(Counters::default());
+
+        {
+            let list = LockFreeSortedList::new();
+            for value in 0..100 {
+                list.insert(Tracked::new(value, &counters));
+            }
+
+            // The rejected duplicate is dropped right away.
+            assert!(!list.insert(Tracked::new(7, &counters)));
+            assert_eq!(counters.dropped.load(Ordering::SeqCst), 1);
+
+            for value in (0..100).step_by(2) {
+                assert!(list.remove(&value));
+            }
+        }
+
+        assert_eq!(counters.created.load(Ordering::SeqCst), 101);
+        assert_eq!(counters.dropped.load(Ordering::SeqCst), 101);
+    }
+
+    #[test]
+    fn static_registry() {
+        static REGISTRY: LockFreeSortedList<(u32, u64)> = LockFreeSortedList::new();
+
+        let handles: Vec<_> = (0..4u32)
+            .map(|topic| {
+                thread::spawn(move || {
+                    for client in 0..100u64 {
+                        REGISTRY.insert((topic, client));
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(REGISTRY.len(), 400);
+        assert!(REGISTRY.contains(&(3, 99)));
+    }
+
+    #[test]
+    fn concurrent_inserts_are_all_kept_in_order() {
+        let list = Arc::new(LockFreeSortedList::new());
+
+        let handles: Vec<_> = (0..8u32)
+            .map(|t| {
+                let list = Arc::clone(&list);
+                thread::spawn(move || {
+                    // Interleaved ranges make threads insert next to each other.
+                    for i in 0..500u32 {
+                        assert!(list.insert(i * 8 + t));
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(list.len(), 4_000);
+        assert_eq!(list.snapshot(), (0..4_000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn concurrent_churn_with_readers() {
+        let list = Arc::new(LockFreeSortedList::new());
+        let counters = Arc::new(Counters::default());
+
+        // Even keys stay in the list for the whole test, odd keys are churned.
+        for key in (0..256).step_by(2) {
+            list.insert(Tracked::new(key, &counters));
+        }
+
+        let writers: Vec<_> = (0..4u32)
+            .map(|t| {
+                let list = Arc::clone(&list);
+                let counters = Arc::clone(&counters);
+                thread::spawn(move || {
+                    for round in 0..2_000u32 {
+                        let key = ((round * 4 + t) % 128) * 2 + 1;
+
+                        list.insert(Tracked::new(key, &counters));
+                        list.remove(&key);
+                    }
+                })
+            })
+            .collect();
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let list = Arc::clone(&list);
+                thread::spawn(move || {
+                    for _ in 0..200 {
+                        let guard = list.pin();
+                        let keys: Vec<u32> = list.iter(&guard).map(|t| t.0).collect();
+
+                        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+                        assert_eq!(keys.iter().filter(|k| *k % 2 == 0).count(), 128);
+                        assert!(list.contains(&42));
+                    }
+                })
+            })
+            .collect();
+
+        for handle in writers.into_iter().chain(readers) {
+            handle.join().unwrap();
+        }
+
+        assert!(list.len() >= 128);
+        drop(list);
+
+        let created = counters.created.load(Ordering::SeqCst);
+        assert_eq!(counters.dropped.load(Ordering::SeqCst), created);
+    }
+
+    #[test]
+    fn concurrent_churn_with_hazard_pointers() {
+        let list: Arc<LockFreeSortedList<Tracked, HazardDomain>> =
+            Arc::new(LockFreeSortedList::with_reclaim(HazardDomain::new()));
+        let counters = Arc::new(Counters::default());
+
+        for key in (0..256).step_by(2) {
+            list.insert(Tracked::new(key, &counters));
+        }
+
+        let writers: Vec<_> = (0..4u32)
+            .map(|t| {
+                let list = Arc::clone(&list);
+                let counters = Arc::clone(&counters);
+                thread::spawn(move || {
+                    for round in 0..2_000u32 {
+                        let key = ((round * 4 + t) % 128) * 2 + 1;
+
+                        list.insert(Tracked::new(key, &counters));
+                        list.remove(&key);
+                    }
+                })
+            })
+            .collect();
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let list = Arc::clone(&list);
+                thread::spawn(move || {
+                    for _ in 0..200 {
+                        let keys: Vec<u32> = list.snapshot().iter().map(|t| t.0).collect();
+
+                        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+                        assert_eq!(keys.iter().filter(|k| *k % 2 == 0).count(), 128);
+                        assert!(list.contains(&42));
+                    }
+                })
+            })
+            .collect();
+
+        for handle in writers.into_iter().chain(readers) {
+            handle.join().unwrap();
+        }
+
+        assert!(list.len() >= 128);
+        drop(list);
+
+        let created = counters.created.load(Ordering::SeqCst);
+        assert_eq!(counters.dropped.load(Ordering::SeqCst), created);
+    }
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::sorted_map::SortedMap;
+    use std::collections::BTreeMap;
+    use std::ops::Bound;
+    use std::rc::Rc;
+
+    /// Deterministic pseudo-random sequence.
+    fn lcg(seed: &mut u64) -> u64 {
+        *seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        *seed >> 33
+    }
+
+    fn entries(map: &SortedMap<u32, u32>) -> Vec<(u32, u32)> {
+        map.iter().map(|(k, v)| (*k, *v)).collect()
+    }
+
+    #[test]
+    fn insert_get_and_remove_across_leaves() {
+        let mut map = SortedMap::new();
+        for key in (0..500).rev() {
+            assert_eq!(map.insert(key, key * 2), None);
+        }
+
+        assert_eq!(map.len(), 500);
+        assert_eq!(map.insert(250, 0), Some(500));
+        assert_eq!(map[&250], 0);
+        assert_eq!(map.get(&500), None);
+        assert!(map.contains_key(&499));
+
+        for key in (0..500).step_by(2) {
+            assert_eq!(map.remove(&key), Some(if key == 250 { 0 } else { key * 2 }));
+        }
+
+        assert_eq!(map.len(), 250);
+        assert!(map.keys().copied().eq((1..500).step_by(2)));
+        assert_eq!(map.first(), Some((&1, &2)));
+        assert_eq!(map.last(), Some((&499, &998)));
+    }
+
+    #[test]
+    fn pop_drains_in_order() {
+        let mut map: SortedMap<u32, u32> = (0..100).map(|key| (key, key)).collect();
+
+        for key in 0..50 {
+            assert_eq!(map.pop_first(), Some((key, key)));
+            assert_eq!(map.pop_last(), Some((99 - key, 99 - key)));
+        }
+
+        assert!(map.is_empty());
+        assert_eq!(map.pop_first(), None);
+        assert_eq!(map.first(), None);
+    }
+
+    #[test]
+    fn floor_ceiling_and_range() {
+        let map: SortedMap<u32, u32> = (0..200).map(|key| (key * 3, key)).collect();
+
+        assert_eq!(map.floor(&1), Some((&0, &0)));
+        assert_eq!(map.floor(&301), Some((&300, &100)));
+        assert_eq!(map.ceiling(&301), Some((&303, &101)));
+        assert_eq!(map.ceiling(&598), None);
+
+        let keys: Vec<_> = map.range(90..=120).map(|(k, _)| *k).collect();
+        assert_eq!(keys, (30..=40).map(|k| k * 3).collect::<Vec<_>>());
+        assert_eq!(map.range(10..500).len(), 163);
+        assert_eq!(map.range(10..500).next_back(), Some((&498, &166)));
+        assert_eq!(map.range(..).len(), 200);
+        assert_eq!(map.range(600..).len(), 0);
+        assert_eq!(
+            map.range((Bound::Included(50), Bound::Excluded(40))).len(),
+            0
+        );
+    }
+
+    #[test]
+    fn split_at_key_and_merge_back() {
+        let mut map: SortedMap<u32, u32> = (0..300).map(|key| (key, key)).collect();
+        let original = map.clone();
+
+        let upper = map.split_at_key(&123);
+        assert_eq!(map.len(), 123);
+        assert_eq!(upper.len(), 177);
+        assert_eq!(map.last(), Some((&122, &122)));
+        assert_eq!(upper.first(), Some((&123, &123)));
+
+        let mut upper = upper;
+        let mut lower = map;
+        upper.merge(&mut lower);
+        assert!(lower.is_empty());
+        assert_eq!(upper, original);
+
+        let mut interleaved: SortedMap<u32, u32> = (0..100).map(|key| (key * 2, 0)).collect();
+        let mut odd: SortedMap<u32, u32> = (0..100).map(|key| (key * 2 + 1, 1)).collect();
+        odd.insert(10, 1);
+        interleaved.merge(&mut odd);
+        assert_eq!(interleaved.len(), 200);
+        assert_eq!(interleaved[&10], 1);
+        assert!(interleaved.keys().copied().eq(0..200));
+    }
+
+    #[test]
+    fn retain_and_into_iter() {
+        let mut map: SortedMap<u32, u32> = (0..256).map(|key| (key, key)).collect();
+        map.retain(|key, value| {
+            *value += 1;
+            key % 5 == 0
+        });
+
+        assert_eq!(map.len(), 52);
+        assert_eq!(
+            entries(&map),
+            (0..256).step_by(5).map(|k| (k, k + 1)).collect::<Vec<_>>()
+        );
+
+        let mut iter = map.into_iter();
+        assert_eq!(iter.len(), 52);
+        assert_eq!(iter.next(), Some((0, 1)));
+        assert_eq!(iter.next_back(), Some((255, 256)));
+        assert_eq!(iter.len(), 50);
+    }
+
+    #[test]
+    fn clone_eq_and_debug() {
+        let map: SortedMap<u32, u32> = [(2, 20), (1, 10)].into_iter().collect();
+
+        let mut copy = map.clone();
+        assert_eq!(copy, map);
+        copy.insert(3, 30);
+        assert_ne!(copy, map);
+        assert_eq!(format!("{map:?}"), "{1: 10, 2: 20}");
+    }
+
+    #[test]
+    fn values_are_dropped() {
+        let value = Rc::new(());
+
+        {
+            let mut map = SortedMap::new();
+            for key in 0..100 {
+                map.insert(key, Rc::clone(&value));
+            }
+
+            map.insert(0, Rc::clone(&value));
+            map.remove(&1);
+            map.retain(|key, _| *key < 60);
+            assert_eq!(Rc::strong_count(&value), 60);
+
+            let tail = map.split_at_key(&30);
+            let mut iter = tail.into_iter();
+            iter.next();
+            assert_eq!(Rc::strong_count(&value), 59);
+        }
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn matches_std_btree_map_under_random_operations() {
+        for mut seed in [1, 42, 1_234, 99_999] {
+            let mut map = SortedMap::new();
+            let mut model = BTreeMap::new();
+
+            for step in 0..20_000u32 {
+                let key = (lcg(&mut seed) % 512) as u32;
+
+                match lcg(&mut seed) % 16 {
+                    0..=7 => assert_eq!(map.insert(key, step), model.insert(key, step)),
+                    8..=12 => assert_eq!(map.remove(&key), model.remove(&key)),
+                    13 => assert_eq!(map.pop_first(), model.pop_first()),
+                    14 => {
+                        let bound = Bound::Excluded(key);
+                        assert!(
+                            map.range((bound, Bound::Unbounded))
+                                .eq(model.range((bound, Bound::Unbounded)))
+                        );
+                        assert_eq!(map.floor(&key), model.range(..=key).next_back());
+                        assert_eq!(map.ceiling(&key), model.range(key..).next());
+                    }
+                    _ => {
+                        let mut upper = map.split_at_key(&key);
+                        assert!(upper.iter().eq(model.range(key..)));
+                        map.merge(&mut upper);
+                    }
+                }
+
+                assert_eq!(map.len(), model.len());
+            }
+
+            assert!(map.iter().eq(model.iter()));
+        }
+    }
+}
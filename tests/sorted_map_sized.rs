@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::sorted_map::SizedSortedMap;
+    use std::ops::Bound;
+    use std::rc::Rc;
+
+    fn filled<const N: usize>(keys: &[u32]) -> SizedSortedMap<u32, u32, N> {
+        let mut map = SizedSortedMap::new();
+        for &key in keys {
+            map.insert(key, key * 10).unwrap();
+        }
+        map
+    }
+
+    #[test]
+    fn insert_keeps_keys_sorted() {
+        let mut map: SizedSortedMap<u32, &str, 4> = SizedSortedMap::new();
+
+        assert_eq!(map.insert(30, "c"), Ok(None));
+        assert_eq!(map.insert(10, "a"), Ok(None));
+        assert_eq!(map.insert(20, "b"), Ok(None));
+        assert_eq!(map.insert(20, "B"), Ok(Some("b")));
+
+        assert_eq!(map.keys(), [10, 20, 30]);
+        assert_eq!(map.values(), ["a", "B", "c"]);
+        assert_eq!(map[&30], "c");
+        assert_eq!(map.get(&40), None);
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn full_map_hands_back_the_entry() {
+        let mut map: SizedSortedMap<u32, u32, 2> = filled(&[1, 2]);
+
+        assert!(map.is_full());
+        assert_eq!(map.insert(3, 0).unwrap_err().into_inner(), (3, 0));
+        assert_eq!(map.insert(2, 0), Ok(Some(20)));
+        assert_eq!(map.capacity(), 2);
+    }
+
+    #[test]
+    fn remove_and_pop_at_both_ends() {
+        let mut map: SizedSortedMap<u32, u32, 8> = filled(&[5, 1, 4, 2, 3]);
+
+        assert_eq!(map.remove(&3), Some(30));
+        assert_eq!(map.remove(&3), None);
+        assert_eq!(map.remove_entry(&4), Some((4, 40)));
+        assert_eq!(map.pop_first(), Some((1, 10)));
+        assert_eq!(map.pop_last(), Some((5, 50)));
+        assert_eq!(map.first(), Some((&2, &20)));
+        assert_eq!(map.last(), Some((&2, &20)));
+        assert_eq!(map.pop_last(), Some((2, 20)));
+        assert_eq!(map.pop_first(), None);
+    }
+
+    #[test]
+    fn floor_and_ceiling() {
+        let map: SizedSortedMap<u32, u32, 8> = filled(&[10, 20, 30]);
+
+        assert_eq!(map.floor(&5), None);
+        assert_eq!(map.floor(&10), Some((&10, &100)));
+        assert_eq!(map.floor(&25), Some((&20, &200)));
+        assert_eq!(map.floor(&99), Some((&30, &300)));
+
+        assert_eq!(map.ceiling(&5), Some((&10, &100)));
+        assert_eq!(map.ceiling(&20), Some((&20, &200)));
+        assert_eq!(map.ceiling(&21), Some((&30, &300)));
+        assert_eq!(map.ceiling(&31), None);
+    }
+
+    #[test]
+    fn range_queries() {
+        let map: SizedSortedMap<u32, u32, 16> = filled(&[1, 3, 5, 7, 9, 11]);
+        fn keys<'a>(iter: impl Iterator<Item = (&'a u32, &'a u32)>) -> Vec<u32> {
+            iter.map(|(key, _)| *key).collect()
+        }
+
+        assert_eq!(keys(map.range(3..9)), [3, 5, 7]);
+        assert_eq!(keys(map.range(3..=9)), [3, 5, 7, 9]);
+        assert_eq!(keys(map.range(4..)), [5, 7, 9, 11]);
+        assert_eq!(keys(map.range(..5)), [1, 3]);
+        assert_eq!(keys(map.range(..)), [1, 3, 5, 7, 9, 11]);
+        assert_eq!(keys(map.range(12..)), []);
+        assert_eq!(
+            keys(map.range((Bound::Included(8), Bound::Excluded(4)))),
+            []
+        );
+        assert_eq!(keys(map.range(3..9).rev()), [7, 5, 3]);
+        assert_eq!(map.range(2..10).len(), 4);
+    }
+
+    #[test]
+    fn split_at_key_moves_the_upper_half() {
+        let mut map: SizedSortedMap<u32, u32, 8> = filled(&[1, 2, 3, 4, 5]);
+
+        let upper = map.split_at_key(&3);
+        assert_eq!(map.keys(), [1, 2]);
+        assert_eq!(upper.keys(), [3, 4, 5]);
+        assert_eq!(upper.values(), [30, 40, 50]);
+
+        let mut lower = map.clone();
+        assert!(lower.split_at_key(&0).keys() == [1, 2] && lower.is_empty());
+        assert!(map.split_at_key(&9).is_empty());
+    }
+
+    #[test]
+    fn merge_replaces_values_of_shared_keys() {
+        let mut map: SizedSortedMap<u32, u32, 6> = filled(&[1, 3, 5]);
+        let mut other: SizedSortedMap<u32, u32, 6> = SizedSortedMap::new();
+        for key in [2, 3, 6] {
+            other.insert(key, key).unwrap();
+        }
+
+        map.merge(&mut other).unwrap();
+        assert_eq!(map.keys(), [1, 2, 3, 5, 6]);
+        assert_eq!(map.values(), [10, 2, 3, 50, 6]);
+        assert!(other.is_empty());
+
+        let mut overflow: SizedSortedMap<u32, u32, 6> = filled(&[7, 8]);
+        assert!(map.merge(&mut overflow).is_err());
+        assert_eq!(map.len(), 5);
+        assert_eq!(overflow.keys(), [7, 8]);
+    }
+
+    #[test]
+    fn merge_keep_lowest_spills_the_highest_keys() {
+        let mut map: SizedSortedMap<u32, u32, 4> = filled(&[1, 4, 6, 8]);
+        let mut other: SizedSortedMap<u32, u32, 4> = SizedSortedMap::new();
+        for key in [2, 4, 5, 9] {
+            other.insert(key, 0).unwrap();
+        }
+
+        map.merge_keep_lowest(&mut other);
+
+        assert_eq!(map.keys(), [1, 2, 4, 5]);
+        assert_eq!(map.values(), [10, 0, 40, 0]);
+        assert_eq!(other.keys(), [6, 8, 9]);
+    }
+
+    #[test]
+    fn iteration_and_retain() {
+        let mut map: SizedSortedMap<u32, u32, 16> = filled(&[9, 3, 7, 1, 5]);
+
+        assert_eq!(
+            map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+            [(1, 10), (3, 30), (5, 50), (7, 70), (9, 90)]
+        );
+
+        for (key, value) in &mut map {
+            *value += key;
+        }
+        map.retain(|key, _| *key != 5);
+
+        assert_eq!(map.values(), [11, 33, 77, 99]);
+        assert_eq!(
+            map.into_iter().rev().collect::<Vec<_>>(),
+            [(9, 99), (7, 77), (3, 33), (1, 11)]
+        );
+    }
+
+    #[test]
+    fn clone_eq_and_debug() {
+        let map: SizedSortedMap<u32, u32, 4> = filled(&[2, 1]);
+
+        let mut copy = map.clone();
+        assert_eq!(copy, map);
+        copy.insert(3, 30).unwrap();
+        assert_ne!(copy, map);
+        assert_eq!(format!("{map:?}"), "{1: 10, 2: 20}");
+    }
+
+    #[test]
+    fn values_are_dropped() {
+        let value = Rc::new(());
+
+        {
+            let mut map: SizedSortedMap<u32, Rc<()>, 6> = SizedSortedMap::new();
+            let mut other: SizedSortedMap<u32, Rc<()>, 6> = SizedSortedMap::new();
+            for key in 0..6 {
+                map.insert(key, Rc::clone(&value)).unwrap();
+                other.insert(key + 4, Rc::clone(&value)).unwrap();
+            }
+
+            map.insert(0, Rc::clone(&value)).unwrap();
+            map.remove(&1);
+            map.retain(|key, _| *key != 2);
+            assert_eq!(Rc::strong_count(&value), 11);
+
+            // Keys 4 and 5 are shared: the entries of `other` are dropped.
+            map.merge_keep_lowest(&mut other);
+            assert_eq!(Rc::strong_count(&value), 9);
+            assert_eq!(map.len() + other.len(), 8);
+
+            let mut iter = other.into_iter();
+            iter.next();
+            assert_eq!(Rc::strong_count(&value), 8);
+        }
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "alloc")]
+
+#[cfg(test)]
+mod tests {
+    use datastructures::reclaim::{Collector, HazardDomain, Reclaim};
+    use datastructures::stack::LockFreeStack;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    struct Tracked(Arc<AtomicUsize>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn push_and_pop_in_lifo_order() {
+        let stack = LockFreeStack::new();
+
+        assert!(stack.is_empty());
+        for value in 1..=3 {
+            stack.push(value);
+        }
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+    }
+
+    #[test]
+    fn pop_all_with_hazard_pointers() {
+        let stack: LockFreeStack<u32, HazardDomain> =
+            LockFreeStack::with_reclaim(HazardDomain::new());
+
+        for value in 0..5 {
+            stack.push(value);
+        }
+
+        let mut batch = stack.pop_all();
+        assert_eq!(stack.len(), 0);
+        assert_eq!(batch.next(), Some(4));
+        stack.push(10);
+        assert_eq!(stack.len(), 1);
+
+        assert_eq!(batch.collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+        assert_eq!(stack.pop(), Some(10));
+        assert_eq!(stack.len(), 0);
+    }
+
+    #[test]
+    fn every_value_is_dropped_once() {
+        let drops = Arc::new(AtomicUsize::new(0));
+
+        {
+            let stack = LockFreeStack::new();
+            for _ in 0..10 {
+                stack.push(Tracked(Arc::clone(&drops)));
+            }
+
+            drop(stack.pop());
+            assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+            // A partially consumed batch drops the rest.
+            let mut batch = stack.pop_all();
+            drop(batch.next());
+            stack.push(Tracked(Arc::clone(&drops)));
+            drop(batch);
+            assert_eq!(drops.load(Ordering::SeqCst), 10);
+        }
+
+        assert_eq!(drops.load(Ordering::SeqCst), 11);
+    }
+
+    #[test]
+    fn static_stack() {
+        static STACK: LockFreeStack<u32> = LockFreeStack::new();
+
+        let handles: Vec<_> = (0..4)
+            .map(|t| thread::spawn(move || (0..100).for_each(|i| STACK.push(t * 100 + i))))
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut values: Vec<_> = STACK.pop_all().collect();
+        values.sort_unstable();
+        assert_eq!(values, (0..400).collect::<Vec<_>>());
+    }
+
+    fn values_come_out_exactly_once<R: Reclaim + Send + 'static>(reclaim: R) {
+        const THREADS: usize = 4;
+        const ROUNDS: usize = 10_000;
+
+        let stack = Arc::new(LockFreeStack::with_reclaim(reclaim));
+        let seen: Arc<Vec<AtomicUsize>> =
+            Arc::new((0..THREADS * ROUNDS).map(|_| AtomicUsize::new(0)).collect());
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let (stack, seen) = (Arc::clone(&stack), Arc::clone(&seen));
+                thread::spawn(move || {
+                    for i in 0..ROUNDS {
+                        stack.push(t * ROUNDS + i);
+
+                        if i % 64 == 0 {
+                            for popped in stack.pop_all() {
+                                seen[popped].fetch_add(1, Ordering::Relaxed);
+                            }
+                        } else if let Some(popped) = stack.pop() {
+                            seen[popped].fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        for popped in stack.pop_all() {
+            seen[popped].fetch_add(1, Ordering::Relaxed);
+        }
+
+        assert!(seen.iter().all(|count| count.load(Ordering::Relaxed) == 1));
+    }
+
+    #[test]
+    fn epoch_values_come_out_exactly_once() {
+        values_come_out_exactly_once(Collector::new());
+    }
+
+    #[test]
+    fn hazard_values_come_out_exactly_once() {
+        values_come_out_exactly_once(HazardDomain::new());
+    }
+}
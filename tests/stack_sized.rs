@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::stack::SizedLockFreeStack;
+    use std::rc::Rc;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    #[test]
+    fn push_and_pop_in_lifo_order() {
+        let stack: SizedLockFreeStack<u32, 4> = SizedLockFreeStack::new();
+
+        assert!(stack.is_empty());
+        for value in 1..=4 {
+            stack.push(value).unwrap();
+        }
+
+        assert_eq!(stack.len(), 4);
+        assert_eq!(stack.push(5).unwrap_err().into_inner(), 5);
+
+        assert_eq!(stack.pop(), Some(4));
+        stack.push(6).unwrap();
+        assert_eq!(stack.pop(), Some(6));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn pop_all_detaches_every_value() {
+        let stack: SizedLockFreeStack<u32, 8> = SizedLockFreeStack::new();
+        for value in 0..5 {
+            stack.push(value).unwrap();
+        }
+
+        let mut batch = stack.pop_all();
+        assert_eq!(stack.len(), 0);
+        assert_eq!(batch.next(), Some(4));
+
+        // The stack is usable again while the batch is consumed.
+        stack.push(10).unwrap();
+        assert_eq!(stack.len(), 1);
+
+        assert_eq!(batch.collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+        assert_eq!(stack.pop_all().collect::<Vec<_>>(), vec![10]);
+        assert_eq!(stack.len(), 0);
+
+        // Every slot was returned to the free stack.
+        for value in 0..8 {
+            stack.push(value).unwrap();
+        }
+    }
+
+    #[test]
+    fn dropping_releases_values() {
+        let value = Rc::new(());
+
+        {
+            let stack: SizedLockFreeStack<Rc<()>, 4> = SizedLockFreeStack::new();
+            for _ in 0..4 {
+                stack.push(Rc::clone(&value)).unwrap();
+            }
+
+            // A partially consumed batch drops the rest.
+            let mut batch = stack.pop_all();
+            batch.next();
+            drop(batch);
+            assert_eq!(Rc::strong_count(&value), 1);
+
+            stack.push(Rc::clone(&value)).unwrap();
+        }
+
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn static_stack_shared_across_threads() {
+        static STACK: SizedLockFreeStack<u64, 32> = SizedLockFreeStack::new();
+
+        let handles: Vec<_> = (0..4u64)
+            .map(|t| {
+                thread::spawn(move || {
+                    for i in 0..1_000u64 {
+                        if STACK.push(t * 1_000 + i).is_ok() {
+                            STACK.pop().unwrap();
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(STACK.is_empty());
+    }
+
+    #[test]
+    fn concurrent_values_come_out_exactly_once() {
+        const THREADS: usize = 4;
+        const ROUNDS: usize = 10_000;
+
+        let stack: Arc<SizedLockFreeStack<usize, 16>> = Arc::new(SizedLockFreeStack::new());
+        let seen: Arc<Vec<AtomicUsize>> =
+            Arc::new((0..THREADS * ROUNDS).map(|_| AtomicUsize::new(0)).collect());
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let (stack, seen) = (Arc::clone(&stack), Arc::clone(&seen));
+                thread::spawn(move || {
+                    for i in 0..ROUNDS {
+                        let mut value = t * ROUNDS + i;
+
+                        // Make room when the stack is full.
+                        while let Err(error) = stack.push(value) {
+                            value = error.into_inner();
+                            if let Some(popped) = stack.pop() {
+                                seen[popped].fetch_add(1, Ordering::Relaxed);
+                            }
+                        }
+
+                        if i % 64 == 0 {
+                            for popped in stack.pop_all() {
+                                seen[popped].fetch_add(1, Ordering::Relaxed);
+                            }
+                        } else if let Some(popped) = stack.pop() {
+                            seen[popped].fetch_add(1, Ordering::Relaxed);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        for popped in stack.pop_all() {
+            seen[popped].fetch_add(1, Ordering::Relaxed);
+        }
+
+        assert!(seen.iter().all(|count| count.load(Ordering::Relaxed) == 1));
+        assert_eq!(stack.len(), 0);
+    }
+}
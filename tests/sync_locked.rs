@@ -0,0 +1,264 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::DoubleLinkedList::{Deque, SizedDoubleLinkedList};
+    use datastructures::sync::{CriticalSection, Locked, RawCriticalSection, RawLock, TicketLock};
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    /// Critical section emulated with a global flag, standing in for masked interrupts.
+    struct GlobalSection;
+
+    static IN_SECTION: AtomicBool = AtomicBool::new(false);
+    static ENTERED: AtomicUsize = AtomicUsize::new(0);
+
+    unsafe impl CriticalSection for GlobalSection {
+        type State = usize;
+
+        fn acquire() -> usize {
+            while IN_SECTION
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                std::hint::spin_loop();
+            }
+
+            ENTERED.fetch_add(1, Ordering::Relaxed)
+        }
+
+        unsafe fn release(_: usize) {
+            IN_SECTION.store(false, Ordering::Release);
+        }
+    }
+
+    type Queue = SizedDoubleLinkedList<u32, 63>;
+
+    /// Runs producers pushing distinct values while consumers pop them, then checks that every
+    /// value was received exactly once.
+    fn check_producers_consumers<L>(
+        queue: Arc<L>,
+        push: fn(&L, u32) -> bool,
+        pop: fn(&L) -> Option<u32>,
+    ) where
+        L: Send + Sync + 'static,
+    {
+        const PRODUCERS: u32 = 4;
+        const PER_PRODUCER: u32 = 2_000;
+
+        let done = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(PRODUCERS as usize * 2));
+
+        let producers: Vec<_> = (0..PRODUCERS)
+            .map(|p| {
+                let (queue, done, barrier) = (queue.clone(), done.clone(), barrier.clone());
+                thread::spawn(move || {
+                    barrier.wait();
+                    for i in 0..PER_PRODUCER {
+                        while !push(&queue, p * PER_PRODUCER + i) {
+                            thread::yield_now();
+                        }
+                    }
+                    done.fetch_add(1, Ordering::Release);
+                })
+            })
+            .collect();
+
+        let consumers: Vec<_> = (0..PRODUCERS)
+            .map(|_| {
+                let (queue, done, barrier) = (queue.clone(), done.clone(), barrier.clone());
+                thread::spawn(move || {
+                    barrier.wait();
+                    let mut received = Vec::new();
+                    loop {
+                        match pop(&queue) {
+                            Some(value) => received.push(value),
+                            None if done.load(Ordering::Acquire) == PRODUCERS as usize => {
+                                match pop(&queue) {
+                                    Some(value) => received.push(value),
+                                    None => break,
+                                }
+                            }
+                            None => thread::yield_now(),
+                        }
+                    }
+                    received
+                })
+            })
+            .collect();
+
+        for producer in producers {
+            producer.join().unwrap();
+        }
+
+        let mut seen = HashSet::new();
+        for consumer in consumers {
+            for value in consumer.join().unwrap() {
+                assert!(seen.insert(value), "value {value} received twice");
+            }
+        }
+
+        assert_eq!(seen.len(), (PRODUCERS * PER_PRODUCER) as usize);
+    }
+
+    #[test]
+    fn test_ticket_lock_producers_consumers() {
+        check_producers_consumers(
+            Arc::new(Locked::<Queue>::new(SizedDoubleLinkedList::new())),
+            |queue, value| queue.push_back(value).is_ok(),
+            |queue| queue.pop_front(),
+        );
+    }
+
+    #[test]
+    fn test_critical_section_producers_consumers() {
+        check_producers_consumers(
+            Arc::new(Locked::<Queue, RawCriticalSection<GlobalSection>>::new(
+                SizedDoubleLinkedList::new(),
+            )),
+            |queue, value| queue.push_back(value).is_ok(),
+            |queue| queue.pop_front(),
+        );
+
+        assert!(ENTERED.load(Ordering::Relaxed) > 0);
+        assert!(!IN_SECTION.load(Ordering::Relaxed));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_mutex_producers_consumers() {
+        use datastructures::sync::MutexLocked;
+
+        check_producers_consumers(
+            Arc::new(MutexLocked::<Queue>::new(SizedDoubleLinkedList::new())),
+            |queue, value| queue.push_back(value).is_ok(),
+            |queue| queue.pop_front(),
+        );
+    }
+
+    #[test]
+    fn test_push_back_hands_back_value_when_full() {
+        static SMALL: Locked<SizedDoubleLinkedList<&str, 1>> =
+            Locked::new(SizedDoubleLinkedList::new());
+
+        assert!(SMALL.push_back("first").is_ok());
+        assert_eq!(
+            SMALL.push_back("second").unwrap_err().into_inner(),
+            "second"
+        );
+        assert_eq!(SMALL.pop_front(), Some("first"));
+        assert_eq!(SMALL.pop_front(), None);
+    }
+
+    #[test]
+    fn test_guard_gives_full_access() {
+        let queue: Locked<Queue> = Locked::default();
+
+        {
+            let mut guard = queue.lock();
+            guard.push_back(1).unwrap();
+            guard.push_front(0).unwrap();
+            assert!(queue.is_locked());
+            assert!(queue.try_lock().is_none());
+        }
+
+        assert!(!queue.is_locked());
+        assert_eq!(queue.with(|list| list.len()), 2);
+        assert_eq!(format!("{queue:?}"), "Locked { value: [0, 1] }");
+        assert_eq!(queue.into_inner().back(), Some(&1));
+    }
+
+    #[test]
+    #[should_panic(expected = "acquired twice")]
+    fn test_critical_section_rejects_reentrant_lock() {
+        struct Nested;
+
+        unsafe impl CriticalSection for Nested {
+            type State = ();
+
+            fn acquire() {}
+
+            unsafe fn release(_: ()) {}
+        }
+
+        let queue: Locked<Queue, RawCriticalSection<Nested>> = Locked::default();
+        let _outer = queue.lock();
+        assert!(queue.try_lock().is_none());
+        let _inner = queue.lock();
+    }
+
+    #[test]
+    fn test_critical_section_guards_dropped_out_of_order() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static ENABLED: Cell<bool> = const { Cell::new(true) };
+        }
+
+        struct Interrupts;
+
+        unsafe impl CriticalSection for Interrupts {
+            type State = bool;
+
+            fn acquire() -> bool {
+                ENABLED.replace(false)
+            }
+
+            unsafe fn release(enabled: bool) {
+                if enabled {
+                    ENABLED.set(true);
+                }
+            }
+        }
+
+        let first: Locked<Queue, RawCriticalSection<Interrupts>> = Locked::default();
+        let second: Locked<Queue, RawCriticalSection<Interrupts>> = Locked::default();
+
+        let outer = first.lock();
+        let inner = second.lock();
+        drop(outer);
+
+        // The outer state is restored while the inner lock is still held and exclusive.
+        assert!(ENABLED.get());
+        assert!(second.try_lock().is_none());
+        assert!(ENABLED.get());
+
+        drop(inner);
+        assert!(ENABLED.get());
+        assert!(second.try_lock().is_some());
+    }
+
+    #[test]
+    fn test_ticket_lock_raw_api() {
+        let lock = TicketLock::INIT;
+
+        lock.lock();
+        assert!(lock.is_locked());
+        assert!(!lock.try_lock());
+
+        unsafe { lock.unlock() };
+        assert!(!lock.is_locked());
+        assert!(lock.try_lock());
+        unsafe { lock.unlock() };
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_mutex_ignores_poisoning() {
+        use datastructures::sync::MutexLocked;
+
+        let queue = Arc::new(MutexLocked::<Queue>::new(SizedDoubleLinkedList::new()));
+        queue.push_back(5).unwrap();
+
+        let poisoner = queue.clone();
+        let result = thread::spawn(move || {
+            let _guard = poisoner.lock();
+            panic!("poison the mutex");
+        })
+        .join();
+
+        assert!(result.is_err());
+        assert_eq!(queue.pop_front(), Some(5));
+        assert!(queue.try_lock().is_some());
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use datastructures::DoubleLinkedList::{OrderedSizedList, SizedDoubleLinkedList};
+    use datastructures::buffer::{OverflowPolicy, SizedCircularBuffer};
+    use datastructures::slab::SizedSlab;
+    use datastructures::sync::SpinLock;
+    use datastructures::workstealing::SizedWorkStealingPool;
+    use std::cmp::Ordering;
+    use std::thread;
+
+    type Timers = OrderedSizedList<u32, 8, fn(&u32, &u32) -> Ordering>;
+
+    fn by_value(a: &u32, b: &u32) -> Ordering {
+        a.cmp(b)
+    }
+
+    static LIST: SpinLock<SizedDoubleLinkedList<u32, 8>> =
+        SpinLock::new(SizedDoubleLinkedList::new());
+    static ORDERED: SpinLock<Timers> = SpinLock::new(OrderedSizedList::new(by_value));
+    static SLAB: SpinLock<SizedSlab<u32, 8>> = SpinLock::new(SizedSlab::new());
+    static POOL: SpinLock<SizedWorkStealingPool<u32, 8>> =
+        SpinLock::new(SizedWorkStealingPool::new());
+    static WINDOW: SpinLock<SizedCircularBuffer<u32, 4>> =
+        SpinLock::new(SizedCircularBuffer::new(OverflowPolicy::Overwrite));
+
+    #[test]
+    fn test_statics_start_empty() {
+        assert!(LIST.lock().is_empty());
+        assert!(ORDERED.lock().is_empty());
+        assert!(SLAB.lock().is_empty());
+        assert_eq!(POOL.lock().take(), None);
+        assert!(WINDOW.lock().is_empty());
+    }
+
+    #[test]
+    fn test_const_new_matches_default() {
+        const EMPTY: SizedDoubleLinkedList<u8, 4> = SizedDoubleLinkedList::new();
+
+        let mut list = EMPTY;
+        assert!(list.insert_tail(1).is_ok());
+        assert_eq!(list.len(), 1);
+        assert_eq!(EMPTY, SizedDoubleLinkedList::default());
+    }
+
+    #[test]
+    fn test_try_lock_fails_while_held() {
+        let lock = SpinLock::new(0u32);
+
+        let guard = lock.lock();
+        assert!(lock.is_locked());
+        assert!(lock.try_lock().is_none());
+        assert_eq!(format!("{lock:?}"), "Locked { value: <locked> }");
+
+        drop(guard);
+        assert!(!lock.is_locked());
+        assert_eq!(*lock.try_lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_with_get_mut_and_into_inner() {
+        let mut lock = SpinLock::new(SizedDoubleLinkedList::<u32, 4>::new());
+
+        assert!(lock.with(|list| list.insert_tail(7)).is_ok());
+        assert!(lock.get_mut().insert_head(3).is_ok());
+
+        let list = lock.into_inner();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 7]);
+    }
+
+    #[test]
+    fn test_static_list_shared_across_threads() {
+        static SHARED: SpinLock<SizedDoubleLinkedList<u32, 63>> =
+            SpinLock::new(SizedDoubleLinkedList::new());
+
+        let handles: Vec<_> = (0..4u32)
+            .map(|t| {
+                thread::spawn(move || {
+                    for i in 0..10_000u32 {
+                        let mut list = SHARED.lock();
+
+                        if list.is_full() || i % 3 == 0 {
+                            let _ = list.remove(0);
+                        } else {
+                            list.insert_tail(t * 100_000 + i).unwrap();
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let list = SHARED.lock();
+        assert!(list.len() <= 63);
+        assert_eq!(list.iter().count(), list.len());
+    }
+
+    #[test]
+    fn test_static_counter_is_exact() {
+        static COUNTER: SpinLock<u64> = SpinLock::new(0);
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                thread::spawn(|| {
+                    for _ in 0..10_000 {
+                        *COUNTER.lock() += 1;
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(*COUNTER.lock(), 80_000);
+    }
+}
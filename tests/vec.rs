@@ -1,4 +1,4 @@
-#![cfg(not(feature = "no-std"))]
+#![cfg(feature = "alloc")]
 
 #[cfg(test)]
 mod tests {
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
+    use datastructures::CapacityError;
     use datastructures::workstealing::SizedWorkStealingPool;
-    use datastructures::workstealing::sized::SizedWorkStealingPoolError;
 
     #[test]
     fn insert_take_fifo_for_owner() {
@@ -40,10 +40,7 @@ mod tests {
 
         assert!(pool.insert(1).is_ok());
         assert!(pool.insert(2).is_ok());
-        assert!(matches!(
-            pool.insert(3),
-            Err(SizedWorkStealingPoolError::IsFull)
-        ));
+        assert_eq!(pool.insert(3), Err(CapacityError::new(3)));
 
         assert!(pool.take().is_some());
         assert!(pool.take().is_some());